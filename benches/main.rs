@@ -33,7 +33,19 @@ fn ints_json(bench: &mut Bencher) {
         let validator = build_schema_validator(py, c"{'type': 'int'}");
 
         let result = validator
-            .validate_json(py, &json(py, "123"), None, None, None, None, false.into(), None, None)
+            .validate_json(
+                py,
+                &json(py, "123"),
+                None,
+                None,
+                None,
+                None,
+                false.into(),
+                None,
+                None,
+                false,
+                None,
+            )
             .unwrap();
         let result_int: i64 = result.extract(py).unwrap();
         assert_eq!(result_int, 123);
@@ -41,7 +53,19 @@ fn ints_json(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_json(py, &json(py, "123"), None, None, None, None, false.into(), None, None)
+                    .validate_json(
+                        py,
+                        &json(py, "123"),
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        None,
+                        None,
+                        false,
+                        None,
+                    )
                     .unwrap(),
             )
         })
@@ -55,7 +79,7 @@ fn ints_python(bench: &mut Bencher) {
 
         let Ok(input) = 123_i64.into_pyobject(py);
         let result = validator
-            .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+            .validate_python(py, &input, None, None, None, None, None, false.into(), None, None, None)
             .unwrap();
         let result_int: i64 = result.extract(py).unwrap();
         assert_eq!(result_int, 123);
@@ -64,7 +88,7 @@ fn ints_python(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None, None)
                     .unwrap(),
             )
         })
@@ -83,7 +107,19 @@ fn list_int_json(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_json(py, &json(py, &code), None, None, None, None, false.into(), None, None)
+                    .validate_json(
+                        py,
+                        &json(py, &code),
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        None,
+                        None,
+                        false,
+                        None,
+                    )
                     .unwrap(),
             )
         })
@@ -109,7 +145,7 @@ fn list_int_python(bench: &mut Bencher) {
         let input = black_box(input.bind(py));
         bench.iter(|| {
             let v = validator
-                .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                .validate_python(py, &input, None, None, None, None, None, false.into(), None, None, None)
                 .unwrap();
             black_box(v)
         })
@@ -147,7 +183,19 @@ fn list_error_json(bench: &mut Bencher) {
                 .join(", ")
         );
 
-        match validator.validate_json(py, &json(py, &code), None, None, None, None, false.into(), None, None) {
+        match validator.validate_json(
+            py,
+            &json(py, &code),
+            None,
+            None,
+            None,
+            None,
+            false.into(),
+            None,
+            None,
+            false,
+            None,
+        ) {
             Ok(_) => panic!("unexpectedly valid"),
             Err(e) => {
                 let v = e.value(py);
@@ -159,7 +207,19 @@ fn list_error_json(bench: &mut Bencher) {
         };
 
         bench.iter(|| {
-            match validator.validate_json(py, &json(py, &code), None, None, None, None, false.into(), None, None) {
+            match validator.validate_json(
+                py,
+                &json(py, &code),
+                None,
+                None,
+                None,
+                None,
+                false.into(),
+                None,
+                None,
+                false,
+                None,
+            ) {
                 Ok(_) => panic!("unexpectedly valid"),
                 Err(e) => black_box(e),
             }
@@ -180,7 +240,7 @@ fn list_error_python_input(py: Python<'_>) -> (SchemaValidator, PyObject) {
 
     let input = py.eval(&code, None, None).unwrap().extract().unwrap();
 
-    match validator.validate_python(py, &input, None, None, None, None, None, false.into(), None, None) {
+    match validator.validate_python(py, &input, None, None, None, None, None, false.into(), None, None, None) {
         Ok(_) => panic!("unexpectedly valid"),
         Err(e) => {
             let v = e.value(py);
@@ -200,7 +260,8 @@ fn list_error_python(bench: &mut Bencher) {
 
         let input = black_box(input.bind(py));
         bench.iter(|| {
-            let result = validator.validate_python(py, &input, None, None, None, None, None, false.into(), None, None);
+            let result =
+                validator.validate_python(py, &input, None, None, None, None, None, false.into(), None, None, None);
 
             match result {
                 Ok(_) => panic!("unexpectedly valid"),
@@ -242,7 +303,19 @@ fn list_any_json(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_json(py, &json(py, &code), None, None, None, None, false.into(), None, None)
+                    .validate_json(
+                        py,
+                        &json(py, &code),
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        None,
+                        None,
+                        false,
+                        None,
+                    )
                     .unwrap(),
             )
         })
@@ -262,7 +335,7 @@ fn list_any_python(bench: &mut Bencher) {
         let input = black_box(input);
         bench.iter(|| {
             let v = validator
-                .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                .validate_python(py, &input, None, None, None, None, None, false.into(), None, None, None)
                 .unwrap();
             black_box(v)
         })
@@ -296,7 +369,19 @@ fn dict_json(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_json(py, &json(py, &code), None, None, None, None, false.into(), None, None)
+                    .validate_json(
+                        py,
+                        &json(py, &code),
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        None,
+                        None,
+                        false,
+                        None,
+                    )
                     .unwrap(),
             )
         })
@@ -323,7 +408,7 @@ fn dict_python(bench: &mut Bencher) {
         let input = black_box(input);
         bench.iter(|| {
             let v = validator
-                .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                .validate_python(py, &input, None, None, None, None, None, false.into(), None, None, None)
                 .unwrap();
             black_box(v)
         })
@@ -353,7 +438,7 @@ fn dict_value_error(bench: &mut Bencher) {
 
         let input = py.eval(&code, None, None).unwrap();
 
-        match validator.validate_python(py, &input, None, None, None, None, None, false.into(), None, None) {
+        match validator.validate_python(py, &input, None, None, None, None, None, false.into(), None, None, None) {
             Ok(_) => panic!("unexpectedly valid"),
             Err(e) => {
                 let v = e.value(py);
@@ -366,7 +451,8 @@ fn dict_value_error(bench: &mut Bencher) {
 
         let input = black_box(input);
         bench.iter(|| {
-            let result = validator.validate_python(py, &input, None, None, None, None, None, false.into(), None, None);
+            let result =
+                validator.validate_python(py, &input, None, None, None, None, None, false.into(), None, None, None);
 
             match result {
                 Ok(_) => panic!("unexpectedly valid"),
@@ -404,7 +490,19 @@ fn typed_dict_json(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_json(py, &json(py, code), None, None, None, None, false.into(), None, None)
+                    .validate_json(
+                        py,
+                        &json(py, code),
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        None,
+                        None,
+                        false,
+                        None,
+                    )
                     .unwrap(),
             )
         })
@@ -439,7 +537,7 @@ fn typed_dict_python(bench: &mut Bencher) {
         let input = black_box(input);
         bench.iter(|| {
             let v = validator
-                .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                .validate_python(py, &input, None, None, None, None, None, false.into(), None, None, None)
                 .unwrap();
             black_box(v)
         })
@@ -480,7 +578,7 @@ fn typed_dict_deep_error(bench: &mut Bencher) {
         let input = py.eval(code, None, None).unwrap();
         let input = black_box(input);
 
-        match validator.validate_python(py, &input, None, None, None, None, None, false.into(), None, None) {
+        match validator.validate_python(py, &input, None, None, None, None, None, false.into(), None, None, None) {
             Ok(_) => panic!("unexpectedly valid"),
             Err(e) => {
                 let v = e.value(py);
@@ -492,7 +590,8 @@ fn typed_dict_deep_error(bench: &mut Bencher) {
         };
 
         bench.iter(|| {
-            let result = validator.validate_python(py, &input, None, None, None, None, None, false.into(), None, None);
+            let result =
+                validator.validate_python(py, &input, None, None, None, None, None, false.into(), None, None, None);
 
             match result {
                 Ok(_) => panic!("unexpectedly valid"),
@@ -518,7 +617,7 @@ fn complete_model(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None, None)
                     .unwrap(),
             );
         })
@@ -539,13 +638,13 @@ fn nested_model_using_definitions(bench: &mut Bencher) {
         let input = black_box(input);
 
         validator
-            .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+            .validate_python(py, &input, None, None, None, None, None, false.into(), None, None, None)
             .unwrap();
 
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None, None)
                     .unwrap(),
             );
         })
@@ -566,13 +665,13 @@ fn nested_model_inlined(bench: &mut Bencher) {
         let input = black_box(input);
 
         validator
-            .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+            .validate_python(py, &input, None, None, None, None, None, false.into(), None, None, None)
             .unwrap();
 
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None, None)
                     .unwrap(),
             );
         })
@@ -586,7 +685,7 @@ fn literal_ints_few_python(bench: &mut Bencher) {
 
         let Ok(input) = 4_i64.into_pyobject(py);
         let result = validator
-            .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+            .validate_python(py, &input, None, None, None, None, None, false.into(), None, None, None)
             .unwrap();
         let result_int: i64 = result.extract(py).unwrap();
         assert_eq!(result_int, 4);
@@ -595,7 +694,7 @@ fn literal_ints_few_python(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None, None)
                     .unwrap(),
             )
         })
@@ -610,7 +709,7 @@ fn literal_strings_few_small_python(bench: &mut Bencher) {
         let input = py.eval(c"'4'", None, None).unwrap();
         let input_str: String = input.extract().unwrap();
         let result = validator
-            .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+            .validate_python(py, &input, None, None, None, None, None, false.into(), None, None, None)
             .unwrap();
         let result_str: String = result.extract(py).unwrap();
         assert_eq!(result_str, input_str);
@@ -619,7 +718,7 @@ fn literal_strings_few_small_python(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None, None)
                     .unwrap(),
             )
         })
@@ -637,7 +736,7 @@ fn literal_strings_few_large_python(bench: &mut Bencher) {
         let input = py.eval(c"'a' * 25 + '4'", None, None).unwrap();
         let input_str: String = input.extract().unwrap();
         let result = validator
-            .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+            .validate_python(py, &input, None, None, None, None, None, false.into(), None, None, None)
             .unwrap();
         let result_str: String = result.extract(py).unwrap();
         assert_eq!(result_str, input_str);
@@ -646,7 +745,7 @@ fn literal_strings_few_large_python(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None, None)
                     .unwrap(),
             )
         })
@@ -680,7 +779,7 @@ class Foo(Enum):
 
         let input = py.eval(c"Foo.v4", Some(&globals), None).unwrap();
         let result = validator
-            .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+            .validate_python(py, &input, None, None, None, None, None, false.into(), None, None, None)
             .unwrap();
         assert!(input.eq(result).unwrap());
 
@@ -688,7 +787,7 @@ class Foo(Enum):
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None, None)
                     .unwrap(),
             )
         })
@@ -702,7 +801,7 @@ fn literal_ints_many_python(bench: &mut Bencher) {
 
         let Ok(input) = 99_i64.into_pyobject(py);
         let result = validator
-            .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+            .validate_python(py, &input, None, None, None, None, None, false.into(), None, None, None)
             .unwrap();
         let result_int: i64 = result.extract(py).unwrap();
         assert_eq!(result_int, 99);
@@ -711,7 +810,7 @@ fn literal_ints_many_python(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None, None)
                     .unwrap(),
             )
         })
@@ -727,7 +826,7 @@ fn literal_strings_many_small_python(bench: &mut Bencher) {
         let input = py.eval(c"'99'", None, None).unwrap();
         let input_str: String = input.extract().unwrap();
         let result = validator
-            .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+            .validate_python(py, &input, None, None, None, None, None, false.into(), None, None, None)
             .unwrap();
         let result_str: String = result.extract(py).unwrap();
         assert_eq!(result_str, input_str);
@@ -736,7 +835,7 @@ fn literal_strings_many_small_python(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None, None)
                     .unwrap(),
             )
         })
@@ -754,7 +853,7 @@ fn literal_strings_many_large_python(bench: &mut Bencher) {
         let input = py.eval(c"'a' * 25 + '99'", None, None).unwrap();
         let input_str: String = input.extract().unwrap();
         let result = validator
-            .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+            .validate_python(py, &input, None, None, None, None, None, false.into(), None, None, None)
             .unwrap();
         let result_str: String = result.extract(py).unwrap();
         assert_eq!(result_str, input_str);
@@ -763,7 +862,7 @@ fn literal_strings_many_large_python(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                    .validate_python(py, &input, None, None, None, None, None, false.into(), None, None, None)
                     .unwrap(),
             )
         })
@@ -777,7 +876,19 @@ fn literal_ints_many_json(bench: &mut Bencher) {
 
         let input_json = py.eval(c"'99'", None, None).unwrap();
         let result = validator
-            .validate_json(py, &input_json, None, None, None, None, false.into(), None, None)
+            .validate_json(
+                py,
+                &input_json,
+                None,
+                None,
+                None,
+                None,
+                false.into(),
+                None,
+                None,
+                false,
+                None,
+            )
             .unwrap();
         let result_int: i64 = result.extract(py).unwrap();
         assert_eq!(result_int, 99);
@@ -786,7 +897,19 @@ fn literal_ints_many_json(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_json(py, &input_json, None, None, None, None, false.into(), None, None)
+                    .validate_json(
+                        py,
+                        &input_json,
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        None,
+                        None,
+                        false,
+                        None,
+                    )
                     .unwrap(),
             )
         })
@@ -805,7 +928,19 @@ fn literal_strings_many_large_json(bench: &mut Bencher) {
         let input_json = py.eval(c"'\"' + 'a' * 25 + '99' + '\"'", None, None).unwrap();
         let input_str: String = input.extract().unwrap();
         let result = validator
-            .validate_json(py, &input_json, None, None, None, None, false.into(), None, None)
+            .validate_json(
+                py,
+                &input_json,
+                None,
+                None,
+                None,
+                None,
+                false.into(),
+                None,
+                None,
+                false,
+                None,
+            )
             .unwrap();
         let result_str: String = result.extract(py).unwrap();
         assert_eq!(result_str, input_str);
@@ -814,7 +949,19 @@ fn literal_strings_many_large_json(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_json(py, &input_json, None, None, None, None, false.into(), None, None)
+                    .validate_json(
+                        py,
+                        &input_json,
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        None,
+                        None,
+                        false,
+                        None,
+                    )
                     .unwrap(),
             )
         })
@@ -850,7 +997,7 @@ class Foo(Enum):
             let input = py.eval(c"'null'", None, None).unwrap();
             let input_str: String = input.extract().unwrap();
             let result = validator
-                .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                .validate_python(py, &input, None, None, None, None, None, false.into(), None, None, None)
                 .unwrap();
             let result_str: String = result.extract(py).unwrap();
             assert_eq!(result_str, input_str);
@@ -859,7 +1006,7 @@ class Foo(Enum):
             bench.iter(|| {
                 black_box(
                     validator
-                        .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                        .validate_python(py, &input, None, None, None, None, None, false.into(), None, None, None)
                         .unwrap(),
                 )
             })
@@ -870,7 +1017,7 @@ class Foo(Enum):
             let input = py.eval(c"-1", None, None).unwrap();
             let input_int: i64 = input.extract().unwrap();
             let result = validator
-                .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                .validate_python(py, &input, None, None, None, None, None, false.into(), None, None, None)
                 .unwrap();
             let result_int: i64 = result.extract(py).unwrap();
             assert_eq!(result_int, input_int);
@@ -879,7 +1026,7 @@ class Foo(Enum):
             bench.iter(|| {
                 black_box(
                     validator
-                        .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                        .validate_python(py, &input, None, None, None, None, None, false.into(), None, None, None)
                         .unwrap(),
                 )
             })
@@ -889,7 +1036,7 @@ class Foo(Enum):
         {
             let input = py.eval(c"None", None, None).unwrap();
             let result = validator
-                .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                .validate_python(py, &input, None, None, None, None, None, false.into(), None, None, None)
                 .unwrap();
             assert!(input.eq(result).unwrap());
 
@@ -897,7 +1044,7 @@ class Foo(Enum):
             bench.iter(|| {
                 black_box(
                     validator
-                        .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                        .validate_python(py, &input, None, None, None, None, None, false.into(), None, None, None)
                         .unwrap(),
                 )
             })
@@ -907,7 +1054,7 @@ class Foo(Enum):
         {
             let input = py.eval(c"Foo.v4", Some(&globals), None).unwrap();
             let result = validator
-                .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                .validate_python(py, &input, None, None, None, None, None, false.into(), None, None, None)
                 .unwrap();
             assert!(input.eq(result).unwrap());
 
@@ -915,7 +1062,7 @@ class Foo(Enum):
             bench.iter(|| {
                 black_box(
                     validator
-                        .validate_python(py, &input, None, None, None, None, None, false.into(), None, None)
+                        .validate_python(py, &input, None, None, None, None, None, false.into(), None, None, None)
                         .unwrap(),
                 )
             })
@@ -25,6 +25,7 @@ from ._pydantic_core import (
     ValidationError,
     __version__,
     from_json,
+    register_error_messages,
     to_json,
     to_jsonable_python,
 )
@@ -56,6 +57,8 @@ __all__ = [
     'PydanticUndefinedType',
     'SchemaError',
     'ErrorDetails',
+    'UnionErrorDetails',
+    'UnionMemberErrorDetails',
     'InitErrorDetails',
     'ValidationError',
     'PydanticCustomError',
@@ -68,6 +71,7 @@ __all__ = [
     'to_json',
     'from_json',
     'to_jsonable_python',
+    'register_error_messages',
 ]
 
 
@@ -80,7 +84,19 @@ class ErrorDetails(_TypedDict):
     `type` is unique for each error message, and can hence be used as an identifier to build custom error messages.
     """
     loc: tuple[int | str, ...]
-    """Tuple of strings and ints identifying where in the schema the error occurred."""
+    """
+    Tuple of strings and ints identifying where in the schema the error occurred, or a string if a `loc_format`
+    other than `'tuple'` is passed to [`errors()`][pydantic_core.ValidationError.errors].
+    """
+    line: _NotRequired[int]
+    """
+    The line, starting at 1, of the value at `loc` in the JSON input, only set by
+    [`validate_json(..., track_positions=True)`][pydantic_core.SchemaValidator.validate_json].
+    """
+    column: _NotRequired[int]
+    """The column, in characters starting at 1, of the value at `loc` in the JSON input."""
+    offset: _NotRequired[int]
+    """The byte offset of the value at `loc` in the JSON input."""
     msg: str
     """A human readable error message."""
     input: _Any
@@ -97,6 +113,27 @@ class ErrorDetails(_TypedDict):
     """
 
 
+class UnionMemberErrorDetails(_TypedDict):
+    member: int | str
+    """The label of the union member, as used in `loc` when errors aren't grouped."""
+    closest: bool
+    """
+    Whether this member came closest to validating successfully: members which accepted the input and only failed
+    on values inside it come first, then the member which raised the fewest errors.
+    """
+    errors: list[ErrorDetails | UnionErrorDetails]
+    """The errors raised by this member, with `loc` relative to the union."""
+
+
+class UnionErrorDetails(_TypedDict):
+    loc: tuple[int | str, ...]
+    """Tuple of strings and ints identifying where in the schema the union which failed to match is."""
+    msg: str
+    """A human readable error message."""
+    members: list[UnionMemberErrorDetails]
+    """The errors raised by each member of the union, in the order of the union's choices."""
+
+
 class InitErrorDetails(_TypedDict):
     type: str | PydanticCustomError
     """The type of error that occurred, this should be a "slug" identifier that changes rarely or never."""
@@ -1,11 +1,11 @@
 import datetime
-from collections.abc import Mapping
-from typing import Any, Callable, Generic, Literal, TypeVar, final
+from collections.abc import Iterable, Iterator, Mapping
+from typing import Any, Callable, Generic, Literal, TypeVar, final, overload
 
-from _typeshed import SupportsAllComparisons
+from _typeshed import SupportsAllComparisons, SupportsRead, SupportsWrite
 from typing_extensions import LiteralString, Self, TypeAlias
 
-from pydantic_core import ErrorDetails, ErrorTypeInfo, InitErrorDetails, MultiHostHost
+from pydantic_core import ErrorDetails, ErrorTypeInfo, InitErrorDetails, MultiHostHost, UnionErrorDetails
 from pydantic_core.core_schema import CoreConfig, CoreSchema, ErrorType, ExtraBehavior
 
 __all__ = [
@@ -99,6 +99,7 @@ class SchemaValidator:
         allow_partial: bool | Literal['off', 'on', 'trailing-strings'] = False,
         by_alias: bool | None = None,
         by_name: bool | None = None,
+        max_errors: int | None = None,
     ) -> Any:
         """
         Validate a Python object against the schema and return the validated object.
@@ -120,6 +121,9 @@ class SchemaValidator:
                 `'trailing-strings'` means any final unfinished JSON string is included in the result.
             by_alias: Whether to use the field's alias when validating against the provided input data.
             by_name: Whether to use the field's name when validating against the provided input data.
+            max_errors: Stop validating once more than this many errors have been found, the first `max_errors`
+                errors are then raised followed by a `too_many_errors` error. Must be at least 1.
+                If `None`, all errors are collected.
 
         Raises:
             ValidationError: If validation fails.
@@ -160,6 +164,8 @@ class SchemaValidator:
         allow_partial: bool | Literal['off', 'on', 'trailing-strings'] = False,
         by_alias: bool | None = None,
         by_name: bool | None = None,
+        track_positions: bool = False,
+        max_errors: int | None = None,
     ) -> Any:
         """
         Validate JSON data directly against the schema and return the validated Python object.
@@ -184,6 +190,10 @@ class SchemaValidator:
                 `'trailing-strings'` means any final unfinished JSON string is included in the result.
             by_alias: Whether to use the field's alias when validating against the provided input data.
             by_name: Whether to use the field's name when validating against the provided input data.
+            track_positions: Whether to add the `line`, `column` and `offset` in `input` of the value which
+                caused each error, or of the syntax error if the JSON is invalid, to the error details.
+            max_errors: Stop validating once more than this many errors have been found, see
+                [`validate_python()`][pydantic_core.SchemaValidator.validate_python].
 
         Raises:
             ValidationError: If validation fails or if the JSON data is invalid.
@@ -192,6 +202,210 @@ class SchemaValidator:
         Returns:
             The validated Python object.
         """
+    def validate_json_stream(
+        self,
+        reader: SupportsRead[str | bytes] | Iterable[str | bytes | bytearray] | str | bytes | bytearray,
+        *,
+        strict: bool | None = None,
+        extra: ExtraBehavior | None = None,
+        context: Any | None = None,
+        self_instance: Any | None = None,
+        by_alias: bool | None = None,
+        by_name: bool | None = None,
+        chunk_size: int = 65536,
+    ) -> Any:
+        """
+        Validate JSON data read incrementally from a file-like object or an iterator of chunks.
+
+        If the schema is a list schema and the data is a JSON array, each item is validated as soon as it has been
+        read, so the whole document is never held in memory; otherwise the data is read in full and validated as
+        with [`validate_json`][pydantic_core.SchemaValidator.validate_json].
+
+        Errors are the same as from `validate_json`, except that a `too_long` error cannot report the actual
+        length of the array.
+
+        Arguments:
+            reader: A file-like object with a `read(size)` method, an iterator of `bytes`, `bytearray` or `str`
+                chunks, or the complete JSON data.
+            strict: Whether to validate the object in strict mode.
+                If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+            extra: Whether to ignore, allow, or forbid extra data during model validation.
+                If `None`, the value of [`CoreConfig.extra_fields_behavior`][pydantic_core.core_schema.CoreConfig] is used.
+            context: The context to use for validation, this is passed to functional validators as
+                [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+            self_instance: An instance of a model set attributes on from validation.
+            by_alias: Whether to use the field's alias when validating against the provided input data.
+            by_name: Whether to use the field's name when validating against the provided input data.
+            chunk_size: The number of bytes or characters requested from `reader.read()` at a time.
+
+        Raises:
+            ValidationError: If validation fails or if the JSON data is invalid.
+            TypeError: If `reader` is not a supported source, or an iterator yields an unsupported chunk.
+            Exception: Other error types maybe raised if internal errors occur, or by the reader.
+
+        Returns:
+            The validated Python object.
+        """
+    def validate_json_lines(
+        self,
+        input: str | bytes | bytearray,
+        *,
+        strict: bool | None = None,
+        extra: ExtraBehavior | None = None,
+        context: Any | None = None,
+        by_alias: bool | None = None,
+        by_name: bool | None = None,
+    ) -> list[Any]:
+        """
+        Validate newline-delimited JSON ([JSON Lines](https://jsonlines.org/)) data, each non-blank line is
+        validated against the schema as a separate JSON document.
+
+        Errors from all lines are collected into a single `ValidationError`, the first element of each error's
+        `loc` is the line number, starting at 1.
+
+        Arguments:
+            input: The JSON Lines data to validate.
+            strict: Whether to validate the object in strict mode.
+                If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+            extra: Whether to ignore, allow, or forbid extra data during model validation.
+                If `None`, the value of [`CoreConfig.extra_fields_behavior`][pydantic_core.core_schema.CoreConfig] is used.
+            context: The context to use for validation, this is passed to functional validators as
+                [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+            by_alias: Whether to use the field's alias when validating against the provided input data.
+            by_name: Whether to use the field's name when validating against the provided input data.
+
+        Raises:
+            ValidationError: If any line fails validation or contains invalid JSON.
+            Exception: Other error types maybe raised if internal errors occur.
+
+        Returns:
+            A list of the validated Python objects, one per non-blank line.
+        """
+    def validate_msgpack(
+        self,
+        input: bytes | bytearray,
+        *,
+        strict: bool | None = None,
+        extra: ExtraBehavior | None = None,
+        context: Any | None = None,
+        self_instance: Any | None = None,
+        by_alias: bool | None = None,
+        by_name: bool | None = None,
+    ) -> Any:
+        """
+        Validate [MessagePack](https://msgpack.org/) data against the schema.
+
+        MessagePack values are validated directly, as with JSON, but `bin` values are valid `bytes`,
+        unsigned integers up to `2**64 - 1` are supported and the timestamp extension type is a valid `datetime`.
+        Other extension types are converted to a `(code, data)` tuple where a Python object is required.
+
+        Arguments:
+            input: The MessagePack data to validate.
+            strict: Whether to validate the object in strict mode.
+                If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+            extra: Whether to ignore, allow, or forbid extra data during model validation.
+                If `None`, the value of [`CoreConfig.extra_fields_behavior`][pydantic_core.core_schema.CoreConfig] is used.
+            context: The context to use for validation, this is passed to functional validators as
+                [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+            self_instance: An instance of a model set attributes on from validation.
+            by_alias: Whether to use the field's alias when validating against the provided input data.
+            by_name: Whether to use the field's name when validating against the provided input data.
+
+        Raises:
+            ValidationError: If validation fails or if the MessagePack data is invalid.
+            Exception: Other error types maybe raised if internal errors occur.
+
+        Returns:
+            The validated Python object.
+        """
+    def validate_cbor(
+        self,
+        input: bytes | bytearray,
+        *,
+        strict: bool | None = None,
+        extra: ExtraBehavior | None = None,
+        context: Any | None = None,
+        self_instance: Any | None = None,
+        by_alias: bool | None = None,
+        by_name: bool | None = None,
+    ) -> Any:
+        """
+        Validate [CBOR](https://www.rfc-editor.org/rfc/rfc8949) data against the schema.
+
+        CBOR values are validated directly, as with JSON, but byte strings are valid `bytes` and the standard tags
+        are understood: date/time strings and epoch date/times (tags 0 and 1) are exact `datetime` inputs,
+        bignums (tags 2 and 3) are exact `int` inputs and decimal fractions (tag 4) are exact `Decimal` inputs.
+        In strict mode a tagged value is only valid for the type its tag describes. Other tags are ignored.
+
+        Arguments:
+            input: The CBOR data to validate.
+            strict: Whether to validate the object in strict mode.
+                If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+            extra: Whether to ignore, allow, or forbid extra data during model validation.
+                If `None`, the value of [`CoreConfig.extra_fields_behavior`][pydantic_core.core_schema.CoreConfig] is used.
+            context: The context to use for validation, this is passed to functional validators as
+                [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+            self_instance: An instance of a model set attributes on from validation.
+            by_alias: Whether to use the field's alias when validating against the provided input data.
+            by_name: Whether to use the field's name when validating against the provided input data.
+
+        Raises:
+            ValidationError: If validation fails or if the CBOR data is invalid.
+            Exception: Other error types maybe raised if internal errors occur.
+
+        Returns:
+            The validated Python object.
+        """
+    def iter_validate_json(
+        self,
+        data: SupportsRead[str | bytes] | Iterable[str | bytes | bytearray] | str | bytes | bytearray,
+        *,
+        strict: bool | None = None,
+        extra: ExtraBehavior | None = None,
+        context: Any | None = None,
+        by_alias: bool | None = None,
+        by_name: bool | None = None,
+        on_error: Literal['raise', 'skip', 'collect'] = 'raise',
+        chunk_size: int = 65536,
+    ) -> Iterator[Any]:
+        """
+        Lazily validate the items of a top-level JSON array, yielding each validated item as it is read.
+
+        The schema must be a list schema. Only the item currently being validated is held in memory, so this can
+        be used for arrays which are too large to validate with
+        [`validate_json`][pydantic_core.SchemaValidator.validate_json].
+
+        Errors for an item have the item's index as the first element of their `loc`. `max_length` is checked as
+        items are read and `min_length` once the array ends, against the number of valid items. Invalid JSON always
+        raises a `ValidationError` as soon as it is reached.
+
+        Arguments:
+            data: The JSON data, a file-like object with a `read(size)` method, or an iterator of chunks,
+                see [`validate_json_stream`][pydantic_core.SchemaValidator.validate_json_stream].
+            strict: Whether to validate the items in strict mode.
+                If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+            extra: Whether to ignore, allow, or forbid extra data during model validation.
+                If `None`, the value of [`CoreConfig.extra_fields_behavior`][pydantic_core.core_schema.CoreConfig] is used.
+            context: The context to use for validation, this is passed to functional validators as
+                [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+            by_alias: Whether to use the field's alias when validating against the provided input data.
+            by_name: Whether to use the field's name when validating against the provided input data.
+            on_error: What to do when an item fails validation:
+
+                * `'raise'`: raise a `ValidationError` for the item and stop iterating
+                * `'skip'`: skip the item
+                * `'collect'`: skip the item, and once the array has been read, raise a `ValidationError`
+                  with the errors of every skipped item
+            chunk_size: The number of bytes or characters requested from `data.read()` at a time.
+
+        Raises:
+            TypeError: If the schema is not a list schema.
+            ValidationError: While iterating, if an item fails validation (depending on `on_error`), the array
+                is too long or too short, or the JSON data is invalid.
+
+        Returns:
+            An iterator of validated items.
+        """
     def validate_strings(
         self,
         input: _StringInput,
@@ -202,6 +416,9 @@ class SchemaValidator:
         allow_partial: bool | Literal['off', 'on', 'trailing-strings'] = False,
         by_alias: bool | None = None,
         by_name: bool | None = None,
+        nested_delimiter: str | None = None,
+        case_sensitive: bool = True,
+        max_errors: int | None = None,
     ) -> Any:
         """
         Validate a string against the schema and return the validated Python object.
@@ -222,6 +439,14 @@ class SchemaValidator:
                 `'trailing-strings'` means any final unfinished JSON string is included in the result.
             by_alias: Whether to use the field's alias when validating against the provided input data.
             by_name: Whether to use the field's name when validating against the provided input data.
+            nested_delimiter: If set, `input` must be a flat mapping of strings, e.g. environment variables,
+                and keys are split on the delimiter into nested mappings before validation,
+                so `{'DB__PORT': '5432'}` is validated as `{'DB': {'PORT': '5432'}}` with `nested_delimiter='__'`.
+                Error locations refer to the original flat keys.
+            case_sensitive: If `False`, keys of the flat mapping are matched to field names and aliases ignoring case,
+                keys which differ only by case are reported as `ambiguous_key` errors.
+            max_errors: Stop validating once more than this many errors have been found, see
+                [`validate_python()`][pydantic_core.SchemaValidator.validate_python].
 
         Raises:
             ValidationError: If validation fails or if the JSON data is invalid.
@@ -323,6 +548,8 @@ class SchemaSerializer:
         fallback: Callable[[Any], Any] | None = None,
         serialize_as_any: bool = False,
         context: Any | None = None,
+        sort_keys: bool | Literal['recursive', 'top-level'] | None = None,
+        groups: set[str] | None = None,
     ) -> Any:
         """
         Serialize/marshal a Python object to a Python object including transforming and filtering data.
@@ -347,6 +574,11 @@ class SchemaSerializer:
             serialize_as_any: Whether to serialize fields with duck-typing serialization behavior.
             context: The context to use for serialization, this is passed to functional serializers as
                 [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
+            sort_keys: Whether to sort the keys of dicts, `True`/`'recursive'` sorts every dict,
+                `'top-level'` only sorts the outermost dict. If `None`, `ser_json_sort_keys` from the config is used
+                in JSON mode. Keys which can't be compared with each other are ordered by type name, then `str()`.
+            groups: Only serialize fields whose `serialization_groups` include one of these groups,
+                fields without `serialization_groups` are always serialized. If `None`, groups aren't considered.
 
         Raises:
             PydanticSerializationError: If serialization fails and no `fallback` function is provided.
@@ -354,6 +586,7 @@ class SchemaSerializer:
         Returns:
             The serialized Python object.
         """
+    @overload
     def to_json(
         self,
         value: Any,
@@ -372,7 +605,43 @@ class SchemaSerializer:
         fallback: Callable[[Any], Any] | None = None,
         serialize_as_any: bool = False,
         context: Any | None = None,
-    ) -> bytes:
+        fp: None = None,
+        buffer_size: int = 65536,
+        canonical: bool = False,
+        sort_keys: bool | Literal['recursive', 'top-level'] | None = None,
+        separators: tuple[str, str] | None = None,
+        trailing_newline: bool = False,
+        inline_scalar_arrays: bool = False,
+        groups: set[str] | None = None,
+    ) -> bytes: ...
+    @overload
+    def to_json(
+        self,
+        value: Any,
+        *,
+        indent: int | None = None,
+        ensure_ascii: bool = False,
+        include: _IncEx | None = None,
+        exclude: _IncEx | None = None,
+        by_alias: bool | None = None,
+        exclude_unset: bool = False,
+        exclude_defaults: bool = False,
+        exclude_none: bool = False,
+        exclude_computed_fields: bool = False,
+        round_trip: bool = False,
+        warnings: bool | Literal['none', 'warn', 'error'] = True,
+        fallback: Callable[[Any], Any] | None = None,
+        serialize_as_any: bool = False,
+        context: Any | None = None,
+        fp: SupportsWrite[bytes] | SupportsWrite[str],
+        buffer_size: int = 65536,
+        canonical: bool = False,
+        sort_keys: bool | Literal['recursive', 'top-level'] | None = None,
+        separators: tuple[str, str] | None = None,
+        trailing_newline: bool = False,
+        inline_scalar_arrays: bool = False,
+        groups: set[str] | None = None,
+    ) -> None:
         """
         Serialize a Python object to JSON including transforming and filtering data.
 
@@ -397,14 +666,242 @@ class SchemaSerializer:
             serialize_as_any: Whether to serialize fields with duck-typing serialization behavior.
             context: The context to use for serialization, this is passed to functional serializers as
                 [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
+            fp: A writable file-like object to write the JSON to in chunks instead of returning it,
+                text files (`io.TextIOBase` instances) are written `str`, other objects are written `bytes`.
+            buffer_size: The number of bytes to buffer between calls to `fp.write()`, only used with `fp`.
+            canonical: Whether to write [RFC 8785](https://www.rfc-editor.org/rfc/rfc8785) canonical JSON,
+                with sorted keys, no whitespace and numbers formatted as in JavaScript, for signing and hashing.
+                Can't be combined with `indent` or `ensure_ascii`, and sorts keys regardless of `sort_keys`.
+            sort_keys: Whether to sort the keys of objects, `True`/`'recursive'` sorts every object,
+                `'top-level'` only sorts the outermost object. If `None`, `ser_json_sort_keys` from the config is used.
+            separators: `(item_separator, key_separator)` to use, defaults to `(', ', ': ')` with `indent`
+                (trailing whitespace is dropped at line ends) and `(',', ':')` without.
+                Can't be combined with `canonical`.
+            trailing_newline: Whether to end the output with a newline.
+            inline_scalar_arrays: With `indent`, whether to write arrays which only contain scalars on a single line.
+            groups: Only serialize fields whose `serialization_groups` include one of these groups,
+                fields without `serialization_groups` are always serialized. If `None`, groups aren't considered.
+
+        Raises:
+            PydanticSerializationError: If serialization fails and no `fallback` function is provided.
+
+        Returns:
+           JSON bytes, or `None` if `fp` is provided.
+        """
+    def to_json_lines(
+        self,
+        values: Iterable[Any],
+        *,
+        ensure_ascii: bool = False,
+        include: _IncEx | None = None,
+        exclude: _IncEx | None = None,
+        by_alias: bool | None = None,
+        exclude_unset: bool = False,
+        exclude_defaults: bool = False,
+        exclude_none: bool = False,
+        exclude_computed_fields: bool = False,
+        round_trip: bool = False,
+        warnings: bool | Literal['none', 'warn', 'error'] = True,
+        fallback: Callable[[Any], Any] | None = None,
+        serialize_as_any: bool = False,
+        context: Any | None = None,
+    ) -> bytes:
+        """
+        Serialize each item of an iterable to compact JSON on its own line, i.e. as
+        [JSON Lines](https://jsonlines.org/), each item is serialized with this serializer's schema.
+
+        Arguments:
+            values: The Python objects to serialize.
+            ensure_ascii: If `True`, the output is guaranteed to have all incoming non-ASCII characters escaped.
+                If `False` (the default), these characters will be output as-is.
+            include: A set of fields to include in each item, if `None` all fields are included.
+            exclude: A set of fields to exclude from each item, if `None` no fields are excluded.
+            by_alias: Whether to use the alias names of fields.
+            exclude_unset: Whether to exclude fields that are not set,
+                e.g. are not included in `__pydantic_fields_set__`.
+            exclude_defaults: Whether to exclude fields that are equal to their default value.
+            exclude_none: Whether to exclude fields that have a value of `None`.
+            exclude_computed_fields: Whether to exclude computed fields.
+            round_trip: Whether to enable serialization and validation round-trip support.
+            warnings: How to handle invalid fields. False/"none" ignores them, True/"warn" logs errors,
+                "error" raises a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError].
+            fallback: A function to call when an unknown value is encountered,
+                if `None` a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError] error is raised.
+            serialize_as_any: Whether to serialize fields with duck-typing serialization behavior.
+            context: The context to use for serialization, this is passed to functional serializers as
+                [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
+
+        Raises:
+            PydanticSerializationError: If serialization fails and no `fallback` function is provided.
+
+        Returns:
+           JSON Lines bytes, every line including the last ends with `\\n`.
+        """
+    def to_msgpack(
+        self,
+        value: Any,
+        *,
+        include: _IncEx | None = None,
+        exclude: _IncEx | None = None,
+        by_alias: bool | None = None,
+        exclude_unset: bool = False,
+        exclude_defaults: bool = False,
+        exclude_none: bool = False,
+        exclude_computed_fields: bool = False,
+        round_trip: bool = False,
+        warnings: bool | Literal['none', 'warn', 'error'] = True,
+        fallback: Callable[[Any], Any] | None = None,
+        serialize_as_any: bool = False,
+        context: Any | None = None,
+    ) -> bytes:
+        """
+        Serialize a Python object to [MessagePack](https://msgpack.org/).
+
+        Values are serialized as they would be by `to_json`, except that `bytes` are written as native
+        MessagePack `bin` values and integers may be up to `2**64 - 1`.
+
+        Arguments:
+            value: The Python object to serialize.
+            include: A set of fields to include, if `None` all fields are included.
+            exclude: A set of fields to exclude, if `None` no fields are excluded.
+            by_alias: Whether to use the alias names of fields.
+            exclude_unset: Whether to exclude fields that are not set,
+                e.g. are not included in `__pydantic_fields_set__`.
+            exclude_defaults: Whether to exclude fields that are equal to their default value.
+            exclude_none: Whether to exclude fields that have a value of `None`.
+            exclude_computed_fields: Whether to exclude computed fields.
+            round_trip: Whether to enable serialization and validation round-trip support.
+            warnings: How to handle invalid fields. False/"none" ignores them, True/"warn" logs errors,
+                "error" raises a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError].
+            fallback: A function to call when an unknown value is encountered,
+                if `None` a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError] error is raised.
+            serialize_as_any: Whether to serialize fields with duck-typing serialization behavior.
+            context: The context to use for serialization, this is passed to functional serializers as
+                [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
+
+        Raises:
+            PydanticSerializationError: If serialization fails and no `fallback` function is provided.
+
+        Returns:
+           MessagePack bytes.
+        """
+    def to_cbor(
+        self,
+        value: Any,
+        *,
+        include: _IncEx | None = None,
+        exclude: _IncEx | None = None,
+        by_alias: bool | None = None,
+        exclude_unset: bool = False,
+        exclude_defaults: bool = False,
+        exclude_none: bool = False,
+        exclude_computed_fields: bool = False,
+        round_trip: bool = False,
+        warnings: bool | Literal['none', 'warn', 'error'] = True,
+        fallback: Callable[[Any], Any] | None = None,
+        serialize_as_any: bool = False,
+        context: Any | None = None,
+    ) -> bytes:
+        """
+        Serialize a Python object to [CBOR](https://www.rfc-editor.org/rfc/rfc8949).
+
+        Values are serialized as they would be by `to_json`, except that `bytes` are written as native
+        CBOR byte strings and integers outside the 64 bit range are written as bignums (tags 2 and 3).
+
+        Arguments:
+            value: The Python object to serialize.
+            include: A set of fields to include, if `None` all fields are included.
+            exclude: A set of fields to exclude, if `None` no fields are excluded.
+            by_alias: Whether to use the alias names of fields.
+            exclude_unset: Whether to exclude fields that are not set,
+                e.g. are not included in `__pydantic_fields_set__`.
+            exclude_defaults: Whether to exclude fields that are equal to their default value.
+            exclude_none: Whether to exclude fields that have a value of `None`.
+            exclude_computed_fields: Whether to exclude computed fields.
+            round_trip: Whether to enable serialization and validation round-trip support.
+            warnings: How to handle invalid fields. False/"none" ignores them, True/"warn" logs errors,
+                "error" raises a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError].
+            fallback: A function to call when an unknown value is encountered,
+                if `None` a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError] error is raised.
+            serialize_as_any: Whether to serialize fields with duck-typing serialization behavior.
+            context: The context to use for serialization, this is passed to functional serializers as
+                [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
 
         Raises:
             PydanticSerializationError: If serialization fails and no `fallback` function is provided.
 
         Returns:
-           JSON bytes.
+           CBOR bytes.
+        """
+
+    def check_roundtrip(
+        self,
+        value: Any,
+        validator: SchemaValidator,
+        *,
+        by_alias: bool | None = None,
+        round_trip: bool = True,
+        serialize_as_any: bool = False,
+        context: Any | None = None,
+        strict: bool | None = None,
+    ) -> tuple[int | str, ...] | None:
+        """
+        Check that `value` survives a round trip through JSON: it's serialized with `to_json`, re-validated with
+        `validator.validate_json` and the result is compared with `value` using `==`.
+
+        Dicts, lists, tuples and objects with a `__dict__` (e.g. models and dataclasses) are compared item by item
+        to find where the values diverge.
+
+        Arguments:
+            value: The Python object to check.
+            validator: The validator to re-validate the JSON with, usually built from the same schema.
+            by_alias: Whether to use the alias names of fields, used both to serialize and to validate.
+            round_trip: Whether to enable serialization and validation round-trip support.
+            serialize_as_any: Whether to serialize fields with duck-typing serialization behavior.
+            context: The context to use for both serialization and validation.
+            strict: Whether to validate the JSON strictly.
+
+        Raises:
+            PydanticSerializationError: If serialization fails or emits a warning.
+            ValidationError: If the serialized JSON doesn't validate.
+
+        Returns:
+            `None` if the re-validated value is equal to `value`, otherwise the location of the first difference,
+                in the same form as the `loc` of validation errors, `()` if the values themselves differ.
         """
 
+@overload
+def to_json(
+    value: Any,
+    *,
+    indent: int | None = None,
+    ensure_ascii: bool = False,
+    include: _IncEx | None = None,
+    exclude: _IncEx | None = None,
+    # Note: In Pydantic 2.11, the default value of `by_alias` on `SchemaSerializer` was changed from `True` to `None`,
+    # to be consistent with the Pydantic "dump" methods. However, the default of `True` was kept here for
+    # backwards compatibility. In Pydantic V3, `by_alias` is expected to default to `True` everywhere:
+    by_alias: bool = True,
+    exclude_none: bool = False,
+    round_trip: bool = False,
+    timedelta_mode: Literal['iso8601', 'float'] = 'iso8601',
+    temporal_mode: Literal['iso8601', 'seconds', 'milliseconds'] = 'iso8601',
+    bytes_mode: Literal['utf8', 'base64', 'hex'] = 'utf8',
+    inf_nan_mode: Literal['null', 'constants', 'strings'] = 'constants',
+    int_mode: Literal['number', 'string', 'safe'] = 'number',
+    serialize_unknown: bool = False,
+    fallback: Callable[[Any], Any] | None = None,
+    serialize_as_any: bool = False,
+    context: Any | None = None,
+    fp: None = None,
+    buffer_size: int = 65536,
+    canonical: bool = False,
+    sort_keys: bool | Literal['recursive', 'top-level'] = False,
+    separators: tuple[str, str] | None = None,
+    trailing_newline: bool = False,
+    inline_scalar_arrays: bool = False,
+) -> bytes: ...
+@overload
 def to_json(
     value: Any,
     *,
@@ -422,11 +919,19 @@ def to_json(
     temporal_mode: Literal['iso8601', 'seconds', 'milliseconds'] = 'iso8601',
     bytes_mode: Literal['utf8', 'base64', 'hex'] = 'utf8',
     inf_nan_mode: Literal['null', 'constants', 'strings'] = 'constants',
+    int_mode: Literal['number', 'string', 'safe'] = 'number',
     serialize_unknown: bool = False,
     fallback: Callable[[Any], Any] | None = None,
     serialize_as_any: bool = False,
     context: Any | None = None,
-) -> bytes:
+    fp: SupportsWrite[bytes] | SupportsWrite[str],
+    buffer_size: int = 65536,
+    canonical: bool = False,
+    sort_keys: bool | Literal['recursive', 'top-level'] = False,
+    separators: tuple[str, str] | None = None,
+    trailing_newline: bool = False,
+    inline_scalar_arrays: bool = False,
+) -> None:
     """
     Serialize a Python object to JSON including transforming and filtering data.
 
@@ -448,6 +953,8 @@ def to_json(
 
         bytes_mode: How to serialize `bytes` objects, either `'utf8'`, `'base64'`, or `'hex'`.
         inf_nan_mode: How to serialize `Infinity`, `-Infinity` and `NaN` values, either `'null'`, `'constants'`, or `'strings'`.
+        int_mode: How to serialize `int` values, `'number'` writes JSON numbers, `'string'` JSON strings and `'safe'`
+            writes ints outside JavaScript's safe integer range (±(2**53 - 1)) as strings.
         serialize_unknown: Attempt to serialize unknown types, `str(value)` will be used, if that fails
             `"<Unserializable {value_type} object>"` will be used.
         fallback: A function to call when an unknown value is encountered,
@@ -455,12 +962,25 @@ def to_json(
         serialize_as_any: Whether to serialize fields with duck-typing serialization behavior.
         context: The context to use for serialization, this is passed to functional serializers as
             [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
+        fp: A writable file-like object to write the JSON to in chunks instead of returning it,
+            text files (`io.TextIOBase` instances) are written `str`, other objects are written `bytes`.
+        buffer_size: The number of bytes to buffer between calls to `fp.write()`, only used with `fp`.
+        canonical: Whether to write [RFC 8785](https://www.rfc-editor.org/rfc/rfc8785) canonical JSON,
+            with sorted keys, no whitespace and numbers formatted as in JavaScript, for signing and hashing.
+            Can't be combined with `indent` or `ensure_ascii`, and sorts keys regardless of `sort_keys`.
+        sort_keys: Whether to sort the keys of objects, `True`/`'recursive'` sorts every object,
+            `'top-level'` only sorts the outermost object.
+        separators: `(item_separator, key_separator)` to use, defaults to `(', ', ': ')` with `indent`
+            (trailing whitespace is dropped at line ends) and `(',', ':')` without.
+            Can't be combined with `canonical`.
+        trailing_newline: Whether to end the output with a newline.
+        inline_scalar_arrays: With `indent`, whether to write arrays which only contain scalars on a single line.
 
     Raises:
         PydanticSerializationError: If serialization fails and no `fallback` function is provided.
 
     Returns:
-       JSON bytes.
+       JSON bytes, or `None` if `fp` is provided.
     """
 
 def from_json(
@@ -507,10 +1027,12 @@ def to_jsonable_python(
     temporal_mode: Literal['iso8601', 'seconds', 'milliseconds'] = 'iso8601',
     bytes_mode: Literal['utf8', 'base64', 'hex'] = 'utf8',
     inf_nan_mode: Literal['null', 'constants', 'strings'] = 'constants',
+    int_mode: Literal['number', 'string', 'safe'] = 'number',
     serialize_unknown: bool = False,
     fallback: Callable[[Any], Any] | None = None,
     serialize_as_any: bool = False,
     context: Any | None = None,
+    sort_keys: bool | Literal['recursive', 'top-level'] = False,
 ) -> Any:
     """
     Serialize/marshal a Python object to a JSON-serializable Python object including transforming and filtering data.
@@ -531,6 +1053,8 @@ def to_jsonable_python(
 
         bytes_mode: How to serialize `bytes` objects, either `'utf8'`, `'base64'`, or `'hex'`.
         inf_nan_mode: How to serialize `Infinity`, `-Infinity` and `NaN` values, either `'null'`, `'constants'`, or `'strings'`.
+        int_mode: How to serialize `int` values, `'number'` writes JSON numbers, `'string'` JSON strings and `'safe'`
+            writes ints outside JavaScript's safe integer range (±(2**53 - 1)) as strings.
         serialize_unknown: Attempt to serialize unknown types, `str(value)` will be used, if that fails
             `"<Unserializable {value_type} object>"` will be used.
         fallback: A function to call when an unknown value is encountered,
@@ -538,6 +1062,8 @@ def to_jsonable_python(
         serialize_as_any: Whether to serialize fields with duck-typing serialization behavior.
         context: The context to use for serialization, this is passed to functional serializers as
             [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
+        sort_keys: Whether to sort the keys of dicts, `True`/`'recursive'` sorts every dict,
+            `'top-level'` only sorts the outermost dict.
 
     Raises:
         PydanticSerializationError: If serialization fails and no `fallback` function is provided.
@@ -680,9 +1206,28 @@ class ValidationError(ValueError):
         Returns:
             The number of errors in the validation error.
         """
+    @overload
+    def errors(
+        self,
+        *,
+        include_url: bool = True,
+        include_context: bool = True,
+        include_input: bool = True,
+        group_by_union_member: Literal[False] = False,
+        locale: str | None = None,
+        loc_format: Literal['tuple', 'json_pointer', 'jsonpath', 'dotted'] = 'tuple',
+    ) -> list[ErrorDetails]: ...
+    @overload
     def errors(
-        self, *, include_url: bool = True, include_context: bool = True, include_input: bool = True
-    ) -> list[ErrorDetails]:
+        self,
+        *,
+        include_url: bool = True,
+        include_context: bool = True,
+        include_input: bool = True,
+        group_by_union_member: Literal[True],
+        locale: str | None = None,
+        loc_format: Literal['tuple', 'json_pointer', 'jsonpath', 'dotted'] = 'tuple',
+    ) -> list[ErrorDetails | UnionErrorDetails]:
         """
         Details about each error in the validation error.
 
@@ -690,6 +1235,20 @@ class ValidationError(ValueError):
             include_url: Whether to include a URL to documentation on the error each error.
             include_context: Whether to include the context of each error.
             include_input: Whether to include the input value of each error.
+            group_by_union_member: Whether to replace the errors from each union which failed to match with
+                a single [`UnionErrorDetails`][pydantic_core.UnionErrorDetails], nesting the errors raised by each
+                member under its label and marking the member which came closest to matching.
+            locale: Render messages with the templates registered for this locale with
+                [`register_error_messages`][pydantic_core.register_error_messages], errors without a template
+                for the locale use the English message.
+            loc_format: How to render each error's `loc`:
+
+                * `'tuple'` - a tuple of strings and ints, e.g. `('items', 3, 'price')`
+                * `'json_pointer'` - an [RFC 6901](https://www.rfc-editor.org/rfc/rfc6901) JSON Pointer string,
+                  e.g. `'/items/3/price'`, with `~` and `/` in keys escaped as `~0` and `~1`
+                * `'jsonpath'` - a JSONPath string, e.g. `'$.items[3].price'`, keys which aren't identifiers
+                  are bracket-quoted, e.g. `"$['a.b']"`
+                * `'dotted'` - a string of items joined by `.` as in `str(validation_error)`, e.g. `'items.3.price'`
 
         Returns:
             A list of [`ErrorDetails`][pydantic_core.ErrorDetails] for each error in the validation error.
@@ -701,6 +1260,8 @@ class ValidationError(ValueError):
         include_url: bool = True,
         include_context: bool = True,
         include_input: bool = True,
+        locale: str | None = None,
+        loc_format: Literal['tuple', 'json_pointer', 'jsonpath', 'dotted'] = 'tuple',
     ) -> str:
         """
         Same as [`errors()`][pydantic_core.ValidationError.errors] but returns a JSON string.
@@ -710,11 +1271,35 @@ class ValidationError(ValueError):
             include_url: Whether to include a URL to documentation on the error each error.
             include_context: Whether to include the context of each error.
             include_input: Whether to include the input value of each error.
+            locale: Render messages with the templates registered for this locale, see
+                [`errors()`][pydantic_core.ValidationError.errors].
+            loc_format: How to render each error's `loc`, see [`errors()`][pydantic_core.ValidationError.errors].
 
         Returns:
             a JSON string.
         """
 
+    def to_problem_details(
+        self, *, type_uri_prefix: str | None = None, status: int = 422, instance: str | None = None
+    ) -> dict[str, Any]:
+        """
+        Convert the validation error to an [RFC 9457](https://www.rfc-editor.org/rfc/rfc9457) (formerly RFC 7807)
+        "Problem Details" object, ready to be returned as `application/problem+json`.
+
+        The `errors` extension member lists each error with its `type`, `pointer` (the JSON Pointer of its
+        location), `msg`, `ctx` and `url`; all values can be dumped to JSON.
+
+        Args:
+            type_uri_prefix: Prefix of the problem `type` URI, `{type_uri_prefix}validation_error`, and of each
+                error's `url`. If `None`, the problem `type` is `'about:blank'` and error URLs point to the
+                pydantic documentation.
+            status: The HTTP status code.
+            instance: A URI reference identifying this occurrence of the problem, omitted if `None`.
+
+        Returns:
+            The problem details as a dict.
+        """
+
     def __repr__(self) -> str:
         """
         A string representation of the validation error.
@@ -1006,6 +1591,27 @@ def list_all_errors() -> list[ErrorTypeInfo]:
     Returns:
         A list of `ErrorTypeInfo` typed dicts.
     """
+
+def register_error_messages(locale: str, messages: dict[str, str]) -> None:
+    """
+    Register translated message templates for a locale, used by
+    [`ValidationError.errors(locale=...)`][pydantic_core.ValidationError.errors] and
+    [`ValidationError.json(locale=...)`][pydantic_core.ValidationError.json].
+
+    Templates use the same `{ctx}` placeholders as the English templates, see
+    [`list_all_errors`][pydantic_core.list_all_errors], and are used for both Python and JSON input.
+    Calling this again for the same locale adds to or replaces the templates already registered.
+
+    ```py
+    from pydantic_core import register_error_messages
+
+    register_error_messages('fr', {'missing': 'Champ obligatoire', 'greater_than': 'Doit être supérieur à {gt}'})
+    ```
+
+    Arguments:
+        locale: The locale the templates are for, e.g. `'fr'`.
+        messages: A dict mapping error types, including custom error types, to message templates.
+    """
 @final
 class TzInfo(datetime.tzinfo):
     """An `pydantic-core` implementation of the abstract [`datetime.tzinfo`][] class."""
@@ -68,6 +68,19 @@ class CoreConfig(TypedDict, total=False):
         ser_json_bytes: The serialization option for `bytes` values. Default is 'utf8'.
         ser_json_inf_nan: The serialization option for infinity and NaN values
             in float fields. Default is 'null'.
+        ser_json_sort_keys: Whether to sort the keys of dicts, models, dataclasses and typed dicts when serializing
+            to JSON, `True` or `'recursive'` sorts every object, `'top-level'` only the outermost. Default is `False`.
+        ser_json_int: The serialization option for `int` values, `'number'` writes JSON numbers, `'string'` JSON
+            strings and `'safe'` only writes ints outside JavaScript's safe integer range (±(2**53 - 1)) as strings.
+            Default is 'number'.
+        ser_json_decimal: The serialization option for `Decimal` values, `'str'` writes a JSON string and
+            `'number'` a JSON number with the exact digits of the decimal, `to_python(mode='json')` returns a string
+            either way. Default is 'str'.
+        ser_json_float_format: How floats and decimals are written when serializing to JSON, `'shortest'` writes the
+            shortest representation which round-trips, `'fixed'` a fixed number of decimal places and `'scientific'`
+            exponent notation. Default is `'shortest'`.
+        ser_json_float_precision: The number of digits after the decimal point for `ser_json_float_format`,
+            `'shortest'` rounds to this many places. Default is `None`, `'fixed'` and `'scientific'` then use 6.
         val_json_bytes: The validation option for `bytes` values, complementing ser_json_bytes. Default is 'utf8'.
         hide_input_in_errors: Whether to hide input data from `ValidationError` representation.
         validation_error_cause: Whether to add user-python excs to the __cause__ of a ValidationError.
@@ -80,6 +93,9 @@ class CoreConfig(TypedDict, total=False):
         validate_by_name: Whether to use the field's name when validating against the provided input data. Default is `False`. Replacement for `populate_by_name`.
         serialize_by_alias: Whether to serialize by alias. Default is `False`, expected to change to `True` in V3.
         url_preserve_empty_path: Whether to preserve empty URL paths when validating values for a URL type. Defaults to `False`.
+        str_collection_parsing: How strings are parsed into lists, sets and dicts with `validate_strings`,
+            `'json'` expects JSON arrays and objects, `'csv'` expects comma separated items with `key=value` items
+            for dicts. Default is `'off'`.
     """
 
     title: str
@@ -110,17 +126,23 @@ class CoreConfig(TypedDict, total=False):
     ser_json_temporal: Literal['iso8601', 'seconds', 'milliseconds']  # default: 'iso8601'
     ser_json_bytes: Literal['utf8', 'base64', 'hex']  # default: 'utf8'
     ser_json_inf_nan: Literal['null', 'constants', 'strings']  # default: 'null'
+    ser_json_sort_keys: Union[bool, Literal['recursive', 'top-level']]  # default: False
+    ser_json_int: Literal['number', 'string', 'safe']  # default: 'number'
+    ser_json_decimal: Literal['str', 'number']  # default: 'str'
+    ser_json_float_format: Literal['shortest', 'fixed', 'scientific']  # default: 'shortest'
+    ser_json_float_precision: int
     val_json_bytes: Literal['utf8', 'base64', 'hex']  # default: 'utf8'
     # used to hide input data from ValidationError repr
     hide_input_in_errors: bool
     validation_error_cause: bool  # default: False
     coerce_numbers_to_str: bool  # default: False
-    regex_engine: Literal['rust-regex', 'python-re']  # default: 'rust-regex'
+    regex_engine: Literal['rust-regex', 'fancy-regex', 'python-re']  # default: 'rust-regex'
     cache_strings: Union[bool, Literal['all', 'keys', 'none']]  # default: 'True'
     validate_by_alias: bool  # default: True
     validate_by_name: bool  # default: False
     serialize_by_alias: bool  # default: False
     url_preserve_empty_path: bool  # default: False
+    str_collection_parsing: Literal['json', 'csv', 'off']  # default: 'off'
 
 
 IncExCall: TypeAlias = 'set[int | str] | dict[int | str, IncExCall] | None'
@@ -216,7 +238,7 @@ class ValidationInfo(Protocol[ContextT]):
         ...
 
     @property
-    def mode(self) -> Literal['python', 'json']:
+    def mode(self) -> Literal['python', 'json', 'msgpack', 'cbor']:
         """The type of input data we are currently validating."""
         ...
 
@@ -684,6 +706,8 @@ class FloatSchema(TypedDict, total=False):
     strict: bool
     ref: str
     metadata: dict[str, Any]
+    float_format: Literal['shortest', 'fixed', 'scientific']
+    float_precision: int
     serialization: SerSchema
 
 
@@ -698,6 +722,8 @@ def float_schema(
     strict: bool | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    float_format: Literal['shortest', 'fixed', 'scientific'] | None = None,
+    float_precision: int | None = None,
     serialization: SerSchema | None = None,
 ) -> FloatSchema:
     """
@@ -721,6 +747,9 @@ def float_schema(
         strict: Whether the value should be a float or a value that can be converted to a float
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        float_format: How to write the value when serializing to JSON, overrides `ser_json_float_format`
+        float_precision: The number of digits after the decimal point when serializing to JSON,
+            overrides `ser_json_float_precision`
         serialization: Custom serialization schema
     """
     return _dict_not_none(
@@ -734,6 +763,8 @@ def float_schema(
         strict=strict,
         ref=ref,
         metadata=metadata,
+        float_format=float_format,
+        float_precision=float_precision,
         serialization=serialization,
     )
 
@@ -751,6 +782,8 @@ class DecimalSchema(TypedDict, total=False):
     strict: bool
     ref: str
     metadata: dict[str, Any]
+    float_format: Literal['shortest', 'fixed', 'scientific']
+    float_precision: int
     serialization: SerSchema
 
 
@@ -767,6 +800,8 @@ def decimal_schema(
     strict: bool | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
+    float_format: Literal['shortest', 'fixed', 'scientific'] | None = None,
+    float_precision: int | None = None,
     serialization: SerSchema | None = None,
 ) -> DecimalSchema:
     """
@@ -793,6 +828,9 @@ def decimal_schema(
         strict: Whether the value should be a float or a value that can be converted to a float
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
+        float_format: How to write the value when serializing to JSON, overrides `ser_json_float_format`
+        float_precision: The number of digits after the decimal point when serializing to JSON,
+            overrides `ser_json_float_precision`
         serialization: Custom serialization schema
     """
     return _dict_not_none(
@@ -808,6 +846,8 @@ def decimal_schema(
         strict=strict,
         ref=ref,
         metadata=metadata,
+        float_format=float_format,
+        float_precision=float_precision,
         serialization=serialization,
     )
 
@@ -862,7 +902,7 @@ class StringSchema(TypedDict, total=False):
     strip_whitespace: bool
     to_lower: bool
     to_upper: bool
-    regex_engine: Literal['rust-regex', 'python-re']  # default: 'rust-regex'
+    regex_engine: Literal['rust-regex', 'fancy-regex', 'python-re']  # default: 'rust-regex'
     strict: bool
     coerce_numbers_to_str: bool
     ref: str
@@ -878,7 +918,7 @@ def str_schema(
     strip_whitespace: bool | None = None,
     to_lower: bool | None = None,
     to_upper: bool | None = None,
-    regex_engine: Literal['rust-regex', 'python-re'] | None = None,
+    regex_engine: Literal['rust-regex', 'fancy-regex', 'python-re'] | None = None,
     strict: bool | None = None,
     coerce_numbers_to_str: bool | None = None,
     ref: str | None = None,
@@ -907,6 +947,8 @@ def str_schema(
             - `rust-regex` uses the [`regex`](https://docs.rs/regex) Rust
               crate, which is non-backtracking and therefore more DDoS
               resistant, but does not support all regex features.
+            - `fancy-regex` uses the [`fancy-regex`](https://docs.rs/fancy-regex) Rust
+              crate, which adds backtracking features such as lookaround and backreferences.
             - `python-re` use the [`re`](https://docs.python.org/3/library/re.html) module,
               which supports all regex features, but may be slower.
         strict: Whether the value should be a string or a value that can be converted to a string
@@ -1935,6 +1977,26 @@ def filter_dict_schema(*, include: IncExDict | None = None, exclude: IncExDict |
 IncExDictOrElseSerSchema = Union[IncExDictSerSchema, SerSchema]
 
 
+class ExcludeIfEquals(TypedDict):
+    equals: Any
+
+
+ExcludeIfIn = TypedDict('ExcludeIfIn', {'in': list[Any]})
+
+ExcludeIfPredicate = Union[Literal['none', 'empty', 'falsy'], ExcludeIfEquals, ExcludeIfIn]
+"""
+A built-in predicate used to exclude values when serializing, evaluated without calling Python code:
+
+* `'none'` - the value is `None`
+* `'empty'` - the value has a length of zero, e.g. `''`, `[]` or `{}`
+* `'falsy'` - the value is falsy
+* `{'equals': value}` - the value is equal to `value`
+* `{'in': [values]}` - the value is equal to one of `values`
+"""
+
+ExcludeIf = Union[Callable[[Any], bool], ExcludeIfPredicate]
+
+
 class DictSchema(TypedDict, total=False):
     type: Required[Literal['dict']]
     keys_schema: CoreSchema  # default: AnySchema
@@ -1943,6 +2005,7 @@ class DictSchema(TypedDict, total=False):
     max_length: int
     fail_fast: bool
     strict: bool
+    values_exclude_if: ExcludeIfPredicate
     ref: str
     metadata: dict[str, Any]
     serialization: IncExDictOrElseSerSchema
@@ -1956,6 +2019,7 @@ def dict_schema(
     max_length: int | None = None,
     fail_fast: bool | None = None,
     strict: bool | None = None,
+    values_exclude_if: ExcludeIfPredicate | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
     serialization: SerSchema | None = None,
@@ -1980,6 +2044,7 @@ def dict_schema(
         max_length: The value must be a dict with at most this many items
         fail_fast: Stop validation on the first error
         strict: Whether the keys and values should be validated with strict mode
+        values_exclude_if: A built-in predicate, items whose value matches it are omitted when serializing
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1992,6 +2057,7 @@ def dict_schema(
         max_length=max_length,
         fail_fast=fail_fast,
         strict=strict,
+        values_exclude_if=values_exclude_if,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -2919,6 +2985,46 @@ def json_or_python_schema(
     )
 
 
+FieldConstraintType = Literal['lt', 'le', 'gt', 'ge', 'eq', 'ne', 'one_of', 'requires', 'mutually_exclusive']
+
+
+class FieldConstraint(TypedDict):
+    type: Required[FieldConstraintType]
+    fields: Required[list[str]]
+
+
+def field_constraint(type: FieldConstraintType, fields: list[str]) -> FieldConstraint:
+    """
+    Returns a constraint between fields of a typed dict or model, checked once all fields have validated, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.typed_dict_schema(
+        {
+            'start': core_schema.typed_dict_field(core_schema.int_schema()),
+            'end': core_schema.typed_dict_field(core_schema.int_schema()),
+        },
+        field_constraints=[core_schema.field_constraint('lt', ['start', 'end'])],
+    )
+    v = SchemaValidator(schema)
+    assert v.validate_python({'start': 1, 'end': 2}) == {'start': 1, 'end': 2}
+    ```
+
+    A field counts as provided if it's present after validation and isn't `None`.
+
+    Args:
+        type: The kind of constraint:
+            - `lt`, `le`, `gt`, `ge`, `eq`, `ne`: compare the first of two fields to the second,
+              only checked when both are provided
+            - `one_of`: exactly one of the fields must be provided
+            - `requires`: if the first field is provided, all the other fields must be provided too
+            - `mutually_exclusive`: at most one of the fields may be provided
+        fields: The names of the fields the constraint applies to
+    """
+    return FieldConstraint(type=type, fields=fields)
+
+
 class TypedDictField(TypedDict, total=False):
     type: Required[Literal['typed-dict-field']]
     schema: Required[CoreSchema]
@@ -2927,7 +3033,8 @@ class TypedDictField(TypedDict, total=False):
     serialization_alias: str
     serialization_exclude: bool  # default: False
     metadata: dict[str, Any]
-    serialization_exclude_if: Callable[[Any], bool]  # default None
+    serialization_exclude_if: ExcludeIf  # default None
+    serialization_groups: list[str]  # default: None
 
 
 def typed_dict_field(
@@ -2938,7 +3045,8 @@ def typed_dict_field(
     serialization_alias: str | None = None,
     serialization_exclude: bool | None = None,
     metadata: dict[str, Any] | None = None,
-    serialization_exclude_if: Callable[[Any], bool] | None = None,
+    serialization_exclude_if: ExcludeIf | None = None,
+    serialization_groups: list[str] | None = None,
 ) -> TypedDictField:
     """
     Returns a schema that matches a typed dict field, e.g.:
@@ -2955,7 +3063,10 @@ def typed_dict_field(
         validation_alias: The alias(es) to use to find the field in the validation data
         serialization_alias: The alias to use as a key when serializing
         serialization_exclude: Whether to exclude the field when serializing
-        serialization_exclude_if: A callable that determines whether to exclude the field when serializing based on its value.
+        serialization_exclude_if: A callable that determines whether to exclude the field when serializing based on its value,
+            or a built-in predicate, see `ExcludeIfPredicate`.
+        serialization_groups: The groups the field belongs to, when `groups` is passed to `to_python` or `to_json`
+            the field is only serialized if it's in one of them, fields without groups are always serialized
         metadata: Any other information you want to include with the schema, not used by pydantic-core
     """
     return _dict_not_none(
@@ -2966,6 +3077,7 @@ def typed_dict_field(
         serialization_alias=serialization_alias,
         serialization_exclude=serialization_exclude,
         serialization_exclude_if=serialization_exclude_if,
+        serialization_groups=serialization_groups,
         metadata=metadata,
     )
 
@@ -2976,8 +3088,10 @@ class TypedDictSchema(TypedDict, total=False):
     cls: type[Any]
     cls_name: str
     computed_fields: list[ComputedField]
+    field_constraints: list[FieldConstraint]
     strict: bool
     extras_schema: CoreSchema
+    extras_exclude_if: ExcludeIf
     # all these values can be set via config, equivalent fields have `typed_dict_` prefix
     extra_behavior: ExtraBehavior
     total: bool  # default: True
@@ -2993,8 +3107,10 @@ def typed_dict_schema(
     cls: type[Any] | None = None,
     cls_name: str | None = None,
     computed_fields: list[ComputedField] | None = None,
+    field_constraints: list[FieldConstraint] | None = None,
     strict: bool | None = None,
     extras_schema: CoreSchema | None = None,
+    extras_exclude_if: ExcludeIf | None = None,
     extra_behavior: ExtraBehavior | None = None,
     total: bool | None = None,
     ref: str | None = None,
@@ -3026,8 +3142,11 @@ def typed_dict_schema(
         cls_name: The name to use in error locations. Falls back to `cls.__name__`, or the validator name if no class
             is provided.
         computed_fields: Computed fields to use when serializing the model, only applies when directly inside a model
+        field_constraints: Constraints between fields, checked once all fields have validated, see `field_constraint`
         strict: Whether the typed dict is strict
         extras_schema: The extra validator to use for the typed dict
+        extras_exclude_if: A callable or built-in predicate, extra items whose value matches it are omitted
+            when serializing
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         extra_behavior: The extra behavior to use for the typed dict
@@ -3040,8 +3159,10 @@ def typed_dict_schema(
         cls=cls,
         cls_name=cls_name,
         computed_fields=computed_fields,
+        field_constraints=field_constraints,
         strict=strict,
         extras_schema=extras_schema,
+        extras_exclude_if=extras_exclude_if,
         extra_behavior=extra_behavior,
         total=total,
         ref=ref,
@@ -3057,7 +3178,8 @@ class ModelField(TypedDict, total=False):
     validation_alias: Union[str, list[Union[str, int]], list[list[Union[str, int]]]]
     serialization_alias: str
     serialization_exclude: bool  # default: False
-    serialization_exclude_if: Callable[[Any], bool]  # default: None
+    serialization_exclude_if: ExcludeIf  # default: None
+    serialization_groups: list[str]  # default: None
     frozen: bool
     metadata: dict[str, Any]
 
@@ -3068,7 +3190,8 @@ def model_field(
     validation_alias: str | list[str | int] | list[list[str | int]] | None = None,
     serialization_alias: str | None = None,
     serialization_exclude: bool | None = None,
-    serialization_exclude_if: Callable[[Any], bool] | None = None,
+    serialization_exclude_if: ExcludeIf | None = None,
+    serialization_groups: list[str] | None = None,
     frozen: bool | None = None,
     metadata: dict[str, Any] | None = None,
 ) -> ModelField:
@@ -3086,7 +3209,10 @@ def model_field(
         validation_alias: The alias(es) to use to find the field in the validation data
         serialization_alias: The alias to use as a key when serializing
         serialization_exclude: Whether to exclude the field when serializing
-        serialization_exclude_if: A Callable that determines whether to exclude a field during serialization based on its value.
+        serialization_exclude_if: A Callable that determines whether to exclude a field during serialization based on its value,
+            or a built-in predicate, see `ExcludeIfPredicate`.
+        serialization_groups: The groups the field belongs to, when `groups` is passed to `to_python` or `to_json`
+            the field is only serialized if it's in one of them, fields without groups are always serialized
         frozen: Whether the field is frozen
         metadata: Any other information you want to include with the schema, not used by pydantic-core
     """
@@ -3097,6 +3223,7 @@ def model_field(
         serialization_alias=serialization_alias,
         serialization_exclude=serialization_exclude,
         serialization_exclude_if=serialization_exclude_if,
+        serialization_groups=serialization_groups,
         frozen=frozen,
         metadata=metadata,
     )
@@ -3107,9 +3234,11 @@ class ModelFieldsSchema(TypedDict, total=False):
     fields: Required[dict[str, ModelField]]
     model_name: str
     computed_fields: list[ComputedField]
+    field_constraints: list[FieldConstraint]
     strict: bool
     extras_schema: CoreSchema
     extras_keys_schema: CoreSchema
+    extras_exclude_if: ExcludeIf
     extra_behavior: ExtraBehavior
     from_attributes: bool
     ref: str
@@ -3122,9 +3251,11 @@ def model_fields_schema(
     *,
     model_name: str | None = None,
     computed_fields: list[ComputedField] | None = None,
+    field_constraints: list[FieldConstraint] | None = None,
     strict: bool | None = None,
     extras_schema: CoreSchema | None = None,
     extras_keys_schema: CoreSchema | None = None,
+    extras_exclude_if: ExcludeIf | None = None,
     extra_behavior: ExtraBehavior | None = None,
     from_attributes: bool | None = None,
     ref: str | None = None,
@@ -3149,9 +3280,12 @@ def model_fields_schema(
         fields: The fields of the model
         model_name: The name of the model, used for error messages, defaults to "Model"
         computed_fields: Computed fields to use when serializing the model, only applies when directly inside a model
+        field_constraints: Constraints between fields, checked once all fields have validated, see `field_constraint`
         strict: Whether the model is strict
         extras_schema: The schema to use when validating extra input data
         extras_keys_schema: The schema to use when validating the keys of extra input data
+        extras_exclude_if: A callable or built-in predicate, extra items whose value matches it are omitted
+            when serializing
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         extra_behavior: The extra behavior to use for the model fields
@@ -3163,9 +3297,11 @@ def model_fields_schema(
         fields=fields,
         model_name=model_name,
         computed_fields=computed_fields,
+        field_constraints=field_constraints,
         strict=strict,
         extras_schema=extras_schema,
         extras_keys_schema=extras_keys_schema,
+        extras_exclude_if=extras_exclude_if,
         extra_behavior=extra_behavior,
         from_attributes=from_attributes,
         ref=ref,
@@ -3289,7 +3425,8 @@ class DataclassField(TypedDict, total=False):
     serialization_alias: str
     serialization_exclude: bool  # default: False
     metadata: dict[str, Any]
-    serialization_exclude_if: Callable[[Any], bool]  # default: None
+    serialization_exclude_if: ExcludeIf  # default: None
+    serialization_groups: list[str]  # default: None
 
 
 def dataclass_field(
@@ -3303,7 +3440,8 @@ def dataclass_field(
     serialization_alias: str | None = None,
     serialization_exclude: bool | None = None,
     metadata: dict[str, Any] | None = None,
-    serialization_exclude_if: Callable[[Any], bool] | None = None,
+    serialization_exclude_if: ExcludeIf | None = None,
+    serialization_groups: list[str] | None = None,
     frozen: bool | None = None,
 ) -> DataclassField:
     """
@@ -3329,7 +3467,10 @@ def dataclass_field(
         validation_alias: The alias(es) to use to find the field in the validation data
         serialization_alias: The alias to use as a key when serializing
         serialization_exclude: Whether to exclude the field when serializing
-        serialization_exclude_if: A callable that determines whether to exclude the field when serializing based on its value.
+        serialization_exclude_if: A callable that determines whether to exclude the field when serializing based on its value,
+            or a built-in predicate, see `ExcludeIfPredicate`.
+        serialization_groups: The groups the field belongs to, when `groups` is passed to `to_python` or `to_json`
+            the field is only serialized if it's in one of them, fields without groups are always serialized
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         frozen: Whether the field is frozen
     """
@@ -3344,6 +3485,7 @@ def dataclass_field(
         serialization_alias=serialization_alias,
         serialization_exclude=serialization_exclude,
         serialization_exclude_if=serialization_exclude_if,
+        serialization_groups=serialization_groups,
         metadata=metadata,
         frozen=frozen,
     )
@@ -4227,7 +4369,13 @@ ErrorType = Literal[
     'json_invalid',
     'json_type',
     'needs_python_object',
+    'msgpack_invalid',
+    'msgpack_type',
+    'cbor_invalid',
+    'cbor_type',
     'recursion_loop',
+    'too_many_errors',
+    'ambiguous_key',
     'missing',
     'frozen_field',
     'frozen_instance',
@@ -4236,6 +4384,11 @@ ErrorType = Literal[
     'get_attribute_error',
     'model_type',
     'model_attributes_type',
+    'field_comparison',
+    'field_not_comparable',
+    'fields_one_of',
+    'fields_mutually_exclusive',
+    'field_requires',
     'dataclass_type',
     'dataclass_exact_type',
     'default_factory_not_called',
@@ -4256,6 +4409,7 @@ ErrorType = Literal[
     'string_too_short',
     'string_too_long',
     'string_pattern_mismatch',
+    'string_pattern_error',
     'enum',
     'dict_type',
     'mapping_type',
@@ -4304,6 +4458,7 @@ ErrorType = Literal[
     'callable_type',
     'union_tag_invalid',
     'union_tag_not_found',
+    'union_no_match',
     'arguments_type',
     'missing_argument',
     'unexpected_keyword_argument',
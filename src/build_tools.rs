@@ -9,7 +9,7 @@ use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyString};
 use pyo3::{intern, FromPyObject, PyErrArguments};
 
-use crate::errors::{PyLineError, ValError};
+use crate::errors::{LocFormat, PyLineError, ValError};
 use crate::input::InputType;
 use crate::tools::SchemaDict;
 use crate::ValidationError;
@@ -139,7 +139,9 @@ impl SchemaError {
     fn errors(&self, py: Python) -> PyResult<Py<PyList>> {
         match &self.0 {
             SchemaErrorEnum::Message(_) => Ok(PyList::empty(py).unbind()),
-            SchemaErrorEnum::ValidationError(error) => error.errors(py, false, false, true),
+            SchemaErrorEnum::ValidationError(error) => {
+                error.errors(py, false, false, true, false, None, LocFormat::Tuple)
+            }
         }
     }
 
@@ -9,6 +9,7 @@ use jiter::JsonValue;
 
 use crate::input::BorrowInput;
 use crate::input::Input;
+use crate::input::{CborValue, MsgPackValue};
 
 use super::location::{LocItem, Location};
 use super::types::ErrorType;
@@ -95,6 +96,31 @@ impl ValError {
             other => other,
         }
     }
+
+    /// helper function to call with_union_member on line items if applicable
+    pub fn with_union_member(
+        self,
+        into_loc_item: impl Into<LocItem>,
+        member_index: usize,
+        closest: bool,
+        union_input: impl ToErrorValue,
+    ) -> Self {
+        let loc_item = into_loc_item.into();
+        match self {
+            Self::LineErrors(line_errors) => {
+                let union_input = union_input.to_error_value();
+                Self::LineErrors(
+                    line_errors
+                        .into_iter()
+                        .map(|line_error| {
+                            line_error.with_union_member(loc_item.clone(), member_index, closest, union_input.clone())
+                        })
+                        .collect(),
+                )
+            }
+            other => other,
+        }
+    }
 }
 
 /// A `ValLineError` is a single error that occurred during validation which is converted to a `PyLineError`
@@ -106,6 +132,23 @@ pub struct ValLineError {
     // location is reversed so that adding an "outer" location item is pushing, it's reversed before showing to the user
     pub location: Location,
     pub input_value: InputValue,
+    // union members this error was raised within, innermost first, used to group errors by union member
+    pub union_members: Vec<UnionMemberMark>,
+}
+
+/// Records that a line error came from a specific member of a union which failed to validate, so the
+/// errors can later be grouped under each member in `ValidationError.errors(group_by_union_member=True)`.
+#[derive(Clone)]
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub struct UnionMemberMark {
+    /// index of the member's label within the (reversed) location of the line error
+    pub depth: usize,
+    /// index of the member in the union's choices
+    pub member_index: usize,
+    /// whether this member came closest to validating successfully
+    pub closest: bool,
+    /// the input given to the union, shown on the entry grouping its members' errors
+    pub union_input: InputValue,
 }
 
 impl ValLineError {
@@ -114,6 +157,7 @@ impl ValLineError {
             error_type,
             input_value: input.to_error_value(),
             location: Location::default(),
+            union_members: Vec::new(),
         }
     }
 
@@ -122,6 +166,7 @@ impl ValLineError {
             error_type,
             input_value: input.to_error_value(),
             location: Location::new_some(loc.into()),
+            union_members: Vec::new(),
         }
     }
 
@@ -130,6 +175,7 @@ impl ValLineError {
             error_type,
             input_value: input.to_error_value(),
             location,
+            union_members: Vec::new(),
         }
     }
 
@@ -138,6 +184,7 @@ impl ValLineError {
             error_type,
             input_value,
             location: Location::default(),
+            union_members: Vec::new(),
         }
     }
 
@@ -148,6 +195,26 @@ impl ValLineError {
         self
     }
 
+    /// add the label of the union member which raised this error as an "outer" location item,
+    /// recording where it sits so errors can be grouped by union member
+    pub fn with_union_member(
+        mut self,
+        into_loc_item: impl Into<LocItem>,
+        member_index: usize,
+        closest: bool,
+        union_input: InputValue,
+    ) -> Self {
+        let depth = self.location.depth();
+        self.location.with_outer(into_loc_item.into());
+        self.union_members.push(UnionMemberMark {
+            depth,
+            member_index,
+            closest,
+            union_input,
+        });
+        self
+    }
+
     // change the error_type on a error in place
     pub fn with_type(mut self, error_type: ErrorType) -> Self {
         self.error_type = error_type;
@@ -168,4 +235,6 @@ impl ValLineError {
 pub enum InputValue {
     Python(Py<PyAny>),
     Json(JsonValue<'static>),
+    MsgPack(MsgPackValue),
+    Cbor(CborValue),
 }
@@ -1,10 +1,10 @@
-use pyo3::exceptions::PyTypeError;
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::sync::PyOnceLock;
 use std::borrow::Cow;
 use std::fmt;
 
 use pyo3::prelude::*;
-use pyo3::types::{PyList, PyTuple};
+use pyo3::types::{PyList, PyString, PyTuple};
 use serde::ser::SerializeSeq;
 use serde::{Serialize, Serializer};
 
@@ -136,6 +136,22 @@ impl Location {
         Self::List(loc)
     }
 
+    /// number of items in the location
+    pub fn depth(&self) -> usize {
+        match self {
+            Self::List(loc) => loc.len(),
+            Self::Empty => 0,
+        }
+    }
+
+    /// location items in **REVERSE** order, see above
+    pub fn reversed_items(&self) -> &[LocItem] {
+        match self {
+            Self::List(loc) => loc,
+            Self::Empty => &[],
+        }
+    }
+
     pub fn with_outer(&mut self, loc_item: LocItem) {
         match self {
             Self::List(ref mut loc) => loc.push(loc_item),
@@ -193,3 +209,126 @@ impl TryFrom<Option<&Bound<'_, PyAny>>> for Location {
         }
     }
 }
+
+/// How error locations are rendered by `ValidationError.errors()` and `ValidationError.json()`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LocFormat {
+    /// a tuple of strings and ints, e.g. `('items', 3, 'price')`
+    #[default]
+    Tuple,
+    /// an RFC 6901 JSON Pointer, e.g. `/items/3/price`
+    JsonPointer,
+    /// a JSONPath expression, e.g. `$.items[3].price`
+    JsonPath,
+    /// items joined by dots, as in `str(validation_error)`, e.g. `items.3.price`
+    Dotted,
+}
+
+impl FromPyObject<'_> for LocFormat {
+    fn extract_bound(ob: &Bound<'_, PyAny>) -> PyResult<Self> {
+        match ob.downcast::<PyString>()?.to_str()? {
+            "tuple" => Ok(Self::Tuple),
+            "json_pointer" => Ok(Self::JsonPointer),
+            "jsonpath" => Ok(Self::JsonPath),
+            "dotted" => Ok(Self::Dotted),
+            s => Err(PyValueError::new_err(format!(
+                "Invalid loc_format `{s}`, should be 'tuple', 'json_pointer', 'jsonpath' or 'dotted'"
+            ))),
+        }
+    }
+}
+
+impl LocFormat {
+    /// Render location items, given in **REVERSE** order like `Location` stores them, as a string,
+    /// `None` for `LocFormat::Tuple`.
+    pub fn render(self, reversed_items: &[LocItem]) -> Option<String> {
+        let items = reversed_items.iter().rev();
+        match self {
+            Self::Tuple => None,
+            Self::JsonPointer => Some(
+                items
+                    .map(|item| match item {
+                        LocItem::S(s) => format!("/{}", s.replace('~', "~0").replace('/', "~1")),
+                        LocItem::I(i) => format!("/{i}"),
+                    })
+                    .collect(),
+            ),
+            Self::JsonPath => {
+                let mut path = String::from("$");
+                for item in items {
+                    match item {
+                        LocItem::S(s) if is_identifier(s) => {
+                            path.push('.');
+                            path.push_str(s);
+                        }
+                        LocItem::S(s) => {
+                            path.push_str("['");
+                            path.push_str(&s.replace('\\', "\\\\").replace('\'', "\\'"));
+                            path.push_str("']");
+                        }
+                        LocItem::I(i) => {
+                            path.push('[');
+                            path.push_str(&i.to_string());
+                            path.push(']');
+                        }
+                    }
+                }
+                Some(path)
+            }
+            Self::Dotted => Some(items.map(ToString::to_string).collect::<Vec<_>>().join(".")),
+        }
+    }
+
+    /// The location as a Python object, a tuple or a string depending on the format.
+    pub fn to_py<'py>(self, py: Python<'py>, reversed_items: &[LocItem]) -> PyResult<Bound<'py, PyAny>> {
+        match self.render(reversed_items) {
+            Some(loc) => Ok(PyString::new(py, &loc).into_any()),
+            None => Ok(PyTuple::new(py, reversed_items.iter().rev())?.into_any()),
+        }
+    }
+}
+
+/// Whether a key can be used in JSONPath dot notation, anything else is bracket-quoted.
+fn is_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Where a value sits in a JSON document, added to errors by `validate_json(..., track_positions=True)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourcePosition {
+    /// byte offset from the start of the document
+    pub offset: usize,
+    /// line number, starting at 1
+    pub line: usize,
+    /// column number in characters, starting at 1
+    pub column: usize,
+}
+
+/// Byte offsets of the start of each line of a document, to find the `SourcePosition` of offsets in it.
+pub struct LineStarts(Vec<usize>);
+
+impl LineStarts {
+    pub fn new(data: &[u8]) -> Self {
+        let newlines = data
+            .iter()
+            .enumerate()
+            .filter(|(_, b)| **b == b'\n')
+            .map(|(i, _)| i + 1);
+        Self(std::iter::once(0).chain(newlines).collect())
+    }
+
+    pub fn position(&self, data: &[u8], offset: usize) -> SourcePosition {
+        let end = offset.min(data.len());
+        // the first line starts at 0, so there's always at least one line start at or before `end`
+        let line = self.0.partition_point(|start| *start <= end);
+        let line_start = self.0[line - 1];
+        SourcePosition {
+            offset,
+            line,
+            // count characters rather than bytes by skipping UTF-8 continuation bytes
+            column: data[line_start..end].iter().filter(|b| (**b & 0xC0) != 0x80).count() + 1,
+        }
+    }
+}
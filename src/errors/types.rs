@@ -1,6 +1,8 @@
 use std::any::type_name;
 use std::borrow::Cow;
+use std::collections::HashMap;
 use std::fmt;
+use std::sync::{OnceLock, RwLock};
 
 use pyo3::exceptions::{PyKeyError, PyTypeError};
 use pyo3::prelude::*;
@@ -42,6 +44,26 @@ pub fn list_all_errors(py: Python<'_>) -> PyResult<Bound<'_, PyList>> {
     PyList::new(py, errors)
 }
 
+/// Translated message templates added with `register_error_messages`, keyed by locale then by error type.
+static ERROR_MESSAGES: OnceLock<RwLock<AHashMap<String, AHashMap<String, String>>>> = OnceLock::new();
+
+#[pyfunction]
+pub fn register_error_messages(locale: String, messages: HashMap<String, String, ahash::RandomState>) {
+    let mut catalogs = ERROR_MESSAGES
+        .get_or_init(RwLock::default)
+        .write()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    catalogs.entry(locale).or_default().extend(messages);
+}
+
+fn localized_template(locale: &str, error_type: &str) -> Option<String> {
+    let catalogs = ERROR_MESSAGES
+        .get()?
+        .read()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    catalogs.get(locale)?.get(error_type).cloned()
+}
+
 fn field_from_context<'py, T: FromPyObject<'py>>(
     context: Option<&Bound<'py, PyDict>>,
     field_name: &str,
@@ -169,9 +191,31 @@ error_types! {
     JsonType {},
     NeedsPythonObject { method_name: {ctx_type: String, ctx_fn: field_from_context} },
     // ---------------------
+    // MessagePack errors
+    MsgpackInvalid {
+        error: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    MsgpackType {},
+    // ---------------------
+    // CBOR errors
+    CborInvalid {
+        error: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    CborType {},
+    // ---------------------
     // recursion error
     RecursionLoop {},
     // ---------------------
+    // error limit, see `max_errors`
+    TooManyErrors {
+        max_errors: {ctx_type: usize, ctx_fn: field_from_context},
+    },
+    // ---------------------
+    // flat mapping errors, see `validate_strings(case_sensitive=False)`
+    AmbiguousKey {
+        other_key: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    // ---------------------
     // typed dict specific errors
     Missing {},
     FrozenField {},
@@ -188,6 +232,25 @@ error_types! {
     },
     ModelAttributesType {},
     // ---------------------
+    // cross-field constraint errors
+    FieldComparison {
+        comparison: {ctx_type: String, ctx_fn: field_from_context},
+        other_field: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    FieldNotComparable {
+        other_field: {ctx_type: String, ctx_fn: field_from_context},
+        error: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    FieldsOneOf {
+        fields: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    FieldsMutuallyExclusive {
+        other_field: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    FieldRequires {
+        other_field: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    // ---------------------
     // dataclass errors (we don't talk about ArgsKwargs here for simplicity)
     DataclassType {
         class_name: {ctx_type: String, ctx_fn: field_from_context},
@@ -251,6 +314,10 @@ error_types! {
     StringPatternMismatch {
         pattern: {ctx_type: String, ctx_fn: field_from_context},
     },
+    StringPatternError {
+        pattern: {ctx_type: String, ctx_fn: field_from_context},
+        error: {ctx_type: String, ctx_fn: field_from_context},
+    },
     // ---------------------
     // enum errors
     Enum {
@@ -388,6 +455,7 @@ error_types! {
     UnionTagNotFound {
         discriminator: {ctx_type: String, ctx_fn: field_from_context},
     },
+    UnionNoMatch {},
     // ---------------------
     // argument errors
     ArgumentsType {},
@@ -485,8 +553,14 @@ impl ErrorType {
             Self::NoSuchAttribute {..} => "Object has no attribute '{attribute}'",
             Self::JsonInvalid {..} => "Invalid JSON: {error}",
             Self::JsonType {..} => "JSON input should be string, bytes or bytearray",
+            Self::MsgpackInvalid {..} => "Invalid MessagePack: {error}",
+            Self::MsgpackType {..} => "MessagePack input should be bytes or bytearray",
+            Self::CborInvalid {..} => "Invalid CBOR: {error}",
+            Self::CborType {..} => "CBOR input should be bytes or bytearray",
             Self::NeedsPythonObject {..} => "Cannot check `{method_name}` when validating from json, use a JsonOrPython validator instead",
             Self::RecursionLoop {..} => "Recursion error - cyclic reference detected",
+            Self::TooManyErrors {..} => "Validation stopped after {max_errors} error{expected_plural}",
+            Self::AmbiguousKey {..} => "Key differs only by case from '{other_key}'",
             Self::Missing {..} => "Field required",
             Self::FrozenField {..} => "Field is frozen",
             Self::FrozenInstance {..} => "Instance is frozen",
@@ -495,6 +569,11 @@ impl ErrorType {
             Self::GetAttributeError {..} => "Error extracting attribute: {error}",
             Self::ModelType {..} => "Input should be a valid dictionary or instance of {class_name}",
             Self::ModelAttributesType {..} => "Input should be a valid dictionary or object to extract fields from",
+            Self::FieldComparison {..} => "Input should be {comparison} field '{other_field}'",
+            Self::FieldNotComparable {..} => "Input could not be compared to field '{other_field}': {error}",
+            Self::FieldsOneOf {..} => "Exactly one of the fields {fields} should be provided",
+            Self::FieldsMutuallyExclusive {..} => "Field cannot be provided together with field '{other_field}'",
+            Self::FieldRequires {..} => "Field required when field '{other_field}' is provided",
             Self::DataclassType {..} => "Input should be a dictionary or an instance of {class_name}",
             Self::DataclassExactType {..} => "Input should be an instance of {class_name}",
             Self::DefaultFactoryNotCalled {..} => "The default factory uses validated data, but at least one validation error occurred",
@@ -515,6 +594,7 @@ impl ErrorType {
             Self::StringTooShort {..} => "String should have at least {min_length} character{expected_plural}",
             Self::StringTooLong {..} => "String should have at most {max_length} character{expected_plural}",
             Self::StringPatternMismatch {..} => "String should match pattern '{pattern}'",
+            Self::StringPatternError {..} => "Pattern '{pattern}' could not be run against the string: {error}",
             Self::Enum {..} => "Input should be {expected}",
             Self::DictType {..} => "Input should be a valid dictionary",
             Self::MappingType {..} => "Input should be a valid mapping, error: {error}",
@@ -564,6 +644,7 @@ impl ErrorType {
             Self::CallableType {..} => "Input should be callable",
             Self::UnionTagInvalid {..} => "Input tag '{tag}' found using {discriminator} does not match any of the expected tags: {expected_tags}",
             Self::UnionTagNotFound {..} => "Unable to extract tag using discriminator {discriminator}",
+            Self::UnionNoMatch {..} => "Input did not match any union member",
             Self::ArgumentsType {..} => "Arguments must be a tuple, list or a dictionary",
             Self::MissingArgument {..} => "Missing required argument",
             Self::UnexpectedKeywordArgument {..} => "Unexpected keyword argument",
@@ -631,13 +712,28 @@ impl ErrorType {
     }
 
     pub fn render_message(&self, py: Python, input_type: InputType) -> PyResult<String> {
-        let tmpl = match input_type {
-            InputType::Python => self.message_template_python(),
-            _ => self.message_template_json(),
+        self.render_localized_message(py, input_type, None)
+    }
+
+    /// Render the message using the template registered for `locale` with `register_error_messages`,
+    /// falling back to the English template if there isn't one for this error type.
+    pub fn render_localized_message(
+        &self,
+        py: Python,
+        input_type: InputType,
+        locale: Option<&str>,
+    ) -> PyResult<String> {
+        let localized = locale.and_then(|locale| localized_template(locale, &self.type_string()));
+        let tmpl = match (&localized, input_type) {
+            (Some(localized), _) => localized.as_str(),
+            (None, InputType::Python) => self.message_template_python(),
+            (None, _) => self.message_template_json(),
         };
         match self {
             Self::NoSuchAttribute { attribute, .. } => render!(tmpl, attribute),
             Self::JsonInvalid { error, .. }
+            | Self::MsgpackInvalid { error, .. }
+            | Self::CborInvalid { error, .. }
             | Self::GetAttributeError { error, .. }
             | Self::IterationError { error, .. }
             | Self::DatetimeObjectInvalid { error, .. }
@@ -652,9 +748,20 @@ impl ErrorType {
             | Self::TimeDeltaParsing { error, .. }
             | Self::UrlSyntaxViolation { error, .. } => render!(tmpl, error),
             Self::NeedsPythonObject { method_name, .. } => render!(tmpl, method_name),
+            Self::AmbiguousKey { other_key, .. } => render!(tmpl, other_key),
             Self::ModelType { class_name, .. }
             | Self::DataclassType { class_name, .. }
             | Self::DataclassExactType { class_name, .. } => render!(tmpl, class_name),
+            Self::FieldComparison {
+                comparison,
+                other_field,
+                ..
+            } => render!(tmpl, comparison, other_field),
+            Self::FieldNotComparable { other_field, error, .. } => render!(tmpl, other_field, error),
+            Self::FieldsOneOf { fields, .. } => render!(tmpl, fields),
+            Self::FieldsMutuallyExclusive { other_field, .. } | Self::FieldRequires { other_field, .. } => {
+                render!(tmpl, other_field)
+            }
             Self::GreaterThan { gt, .. } => to_string_render!(tmpl, gt),
             Self::GreaterThanEqual { ge, .. } => to_string_render!(tmpl, ge),
             Self::LessThan { lt, .. } => to_string_render!(tmpl, lt),
@@ -679,6 +786,10 @@ impl ErrorType {
                 let actual_length = actual_length.map_or(Cow::Borrowed("more"), |v| Cow::Owned(v.to_string()));
                 to_string_render!(tmpl, field_type, max_length, actual_length, expected_plural,)
             }
+            Self::TooManyErrors { max_errors, .. } => {
+                let expected_plural = plural_s(*max_errors);
+                to_string_render!(tmpl, max_errors, expected_plural)
+            }
             Self::StringTooShort { min_length, .. } | Self::BytesTooShort { min_length, .. } => {
                 let expected_plural = plural_s(*min_length);
                 to_string_render!(tmpl, min_length, expected_plural)
@@ -690,6 +801,7 @@ impl ErrorType {
                 to_string_render!(tmpl, max_length, expected_plural)
             }
             Self::StringPatternMismatch { pattern, .. } => render!(tmpl, pattern),
+            Self::StringPatternError { pattern, error, .. } => render!(tmpl, pattern, error),
             Self::Enum { expected, .. } => to_string_render!(tmpl, expected),
             Self::BytesInvalidEncoding {
                 encoding,
@@ -712,7 +824,10 @@ impl ErrorType {
                 message_template,
                 context,
                 ..
-            } => PydanticCustomError::format_message(message_template, context.as_ref().map(|c| c.bind(py))),
+            } => PydanticCustomError::format_message(
+                localized.as_deref().unwrap_or(message_template),
+                context.as_ref().map(|c| c.bind(py)),
+            ),
             Self::LiteralError { expected, .. } => render!(tmpl, expected),
             Self::TimezoneOffset {
                 tz_expected, tz_actual, ..
@@ -18,12 +18,14 @@ use serde_json::ser::PrettyFormatter;
 use crate::build_tools::py_schema_error_type;
 use crate::errors::LocItem;
 use crate::get_pydantic_version;
-use crate::input::InputType;
-use crate::serializers::{Extra, SerMode, SerializationConfig, SerializationState, WarningsMode};
+use crate::input::{InputType, JsonPositions};
+use crate::serializers::{
+    infer_to_python, Extra, SerMode, SerializationConfig, SerializationState, SortKeys, WarningsMode,
+};
 use crate::tools::{safe_repr, write_truncated_to_limited_bytes, SchemaDict};
 
-use super::line_error::ValLineError;
-use super::location::Location;
+use super::line_error::{UnionMemberMark, ValLineError};
+use super::location::{LocFormat, Location, SourcePosition};
 use super::types::ErrorType;
 use super::value_exception::PydanticCustomError;
 use super::{InputValue, ValError};
@@ -106,6 +108,18 @@ impl ValidationError {
         }
     }
 
+    /// Add the position in `json_data` of each error's location, see `JsonPositions::position`, if the JSON is
+    /// invalid the position is that of the syntax error instead.
+    pub fn add_json_positions(&mut self, json_data: &[u8]) {
+        let positions = JsonPositions::new(json_data);
+        for line_error in &mut self.line_errors {
+            line_error.position = Some(match &positions {
+                Ok(positions) => positions.position(&line_error.location),
+                Err(syntax_error_position) => *syntax_error_position,
+            });
+        }
+    }
+
     pub fn omit_error() -> PyErr {
         py_schema_error_type!("Uncaught Omit error, please check your usage of `default` validators.")
     }
@@ -236,6 +250,26 @@ fn get_formated_url(py: Python) -> &'static str {
     URL_PREFIX.get_or_init(py, || format!("https://errors.pydantic.dev/{pydantic_version}/v/"))
 }
 
+// serialization state used to convert error context values to JSON
+fn json_serialization_state<'a>(py: Python<'_>) -> PyResult<SerializationState<'a, '_>> {
+    let config = SerializationConfig::from_args("iso8601", "iso8601", "utf8", "constants", "number", SortKeys::Off)?;
+    let extra = Extra::new(
+        py,
+        &SerMode::Json,
+        None,
+        false,
+        false,
+        false,
+        false,
+        false,
+        true,
+        None,
+        false,
+        None,
+    );
+    SerializationState::new(config, WarningsMode::None, None, None, extra)
+}
+
 fn get_url_prefix(py: Python<'_>, include_url: bool) -> Option<&str> {
     if include_url {
         Some(get_formated_url(py))
@@ -293,15 +327,34 @@ impl ValidationError {
         self.line_errors.len()
     }
 
-    #[pyo3(signature = (*, include_url = true, include_context = true, include_input = true))]
+    #[pyo3(signature = (*, include_url = true, include_context = true, include_input = true,
+        group_by_union_member = false, locale = None, loc_format = LocFormat::Tuple))]
+    #[allow(clippy::too_many_arguments)]
     pub fn errors(
         &self,
         py: Python,
         include_url: bool,
         include_context: bool,
         include_input: bool,
+        group_by_union_member: bool,
+        locale: Option<&str>,
+        loc_format: LocFormat,
     ) -> PyResult<Py<PyList>> {
         let url_prefix = get_url_prefix(py, include_url);
+        if group_by_union_member {
+            let views: Vec<UnionGroupView> = self.line_errors.iter().map(UnionGroupView::new).collect();
+            let dicts = union_grouped_dicts(
+                py,
+                &views,
+                url_prefix,
+                include_context,
+                self.input_type,
+                include_input,
+                locale,
+                loc_format,
+            )?;
+            return Ok(PyList::new(py, dicts)?.unbind());
+        }
         let mut iteration_error = None;
         let list = PyList::new(
             py,
@@ -312,14 +365,22 @@ impl ValidationError {
                 if iteration_error.is_some() {
                     return py.None();
                 }
-                e.as_dict(py, url_prefix, include_context, self.input_type, include_input)
-                    .map_or_else(
-                        |err| {
-                            iteration_error = Some(err);
-                            py.None()
-                        },
-                        Into::into,
-                    )
+                e.as_dict(
+                    py,
+                    url_prefix,
+                    include_context,
+                    self.input_type,
+                    include_input,
+                    locale,
+                    loc_format,
+                )
+                .map_or_else(
+                    |err| {
+                        iteration_error = Some(err);
+                        py.None()
+                    },
+                    Into::into,
+                )
             }),
         )?;
         if let Some(err) = iteration_error {
@@ -329,7 +390,9 @@ impl ValidationError {
         }
     }
 
-    #[pyo3(signature = (*, indent = None, include_url = true, include_context = true, include_input = true))]
+    #[pyo3(signature = (*, indent = None, include_url = true, include_context = true, include_input = true,
+        locale = None, loc_format = LocFormat::Tuple))]
+    #[allow(clippy::too_many_arguments)]
     pub fn json<'py>(
         &self,
         py: Python<'py>,
@@ -337,23 +400,10 @@ impl ValidationError {
         include_url: bool,
         include_context: bool,
         include_input: bool,
+        locale: Option<&str>,
+        loc_format: LocFormat,
     ) -> PyResult<Bound<'py, PyString>> {
-        let config = SerializationConfig::from_args("iso8601", "iso8601", "utf8", "constants")?;
-        let extra = Extra::new(
-            py,
-            &SerMode::Json,
-            None,
-            false,
-            false,
-            false,
-            false,
-            false,
-            true,
-            None,
-            false,
-            None,
-        );
-        let mut state = SerializationState::new(config, WarningsMode::None, None, None, extra)?;
+        let mut state = json_serialization_state(py)?;
         let mut serializer = ValidationErrorSerializer {
             py,
             line_errors: &self.line_errors,
@@ -362,6 +412,8 @@ impl ValidationError {
             include_input,
             state: &mut state,
             input_type: &self.input_type,
+            locale,
+            loc_format,
         };
 
         let writer: Vec<u8> = Vec::with_capacity(self.line_errors.len() * 200);
@@ -383,6 +435,61 @@ impl ValidationError {
         Ok(PyString::new(py, s))
     }
 
+    #[pyo3(signature = (*, type_uri_prefix = None, status = 422, instance = None))]
+    pub fn to_problem_details<'py>(
+        &self,
+        py: Python<'py>,
+        type_uri_prefix: Option<&str>,
+        status: u16,
+        instance: Option<&str>,
+    ) -> PyResult<Bound<'py, PyDict>> {
+        let mut state = json_serialization_state(py)?;
+        let url_prefix = type_uri_prefix.unwrap_or_else(|| get_formated_url(py));
+        let errors = self
+            .line_errors
+            .iter()
+            .map(|line_error| {
+                let error_dict = line_error.as_dict(
+                    py,
+                    Some(url_prefix),
+                    true,
+                    self.input_type,
+                    false,
+                    None,
+                    LocFormat::JsonPointer,
+                )?;
+                // rename `loc` to `pointer` as in RFC 9457, and make sure `ctx` can be dumped to JSON
+                let problem_error = PyDict::new(py);
+                for (key, value) in error_dict {
+                    match key.extract::<&str>()? {
+                        "loc" => problem_error.set_item("pointer", value)?,
+                        "ctx" => problem_error.set_item(key, infer_to_python(&value, &mut state)?)?,
+                        _ => problem_error.set_item(key, value)?,
+                    }
+                }
+                Ok(problem_error)
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+
+        let count = self.line_errors.len();
+        let plural = if count == 1 { "" } else { "s" };
+        let title = self.title.bind(py).str()?;
+
+        let dict = PyDict::new(py);
+        match type_uri_prefix {
+            Some(type_uri_prefix) => dict.set_item("type", format!("{type_uri_prefix}validation_error"))?,
+            None => dict.set_item("type", "about:blank")?,
+        }
+        dict.set_item("title", "Validation Error")?;
+        dict.set_item("status", status)?;
+        dict.set_item("detail", format!("{count} validation error{plural} for {title}"))?;
+        if let Some(instance) = instance {
+            dict.set_item("instance", instance)?;
+        }
+        dict.set_item("errors", errors)?;
+        Ok(dict)
+    }
+
     fn __repr__(&self, py: Python) -> String {
         self.display(py, None, self.hide_input)
     }
@@ -397,7 +504,7 @@ impl ValidationError {
         let borrow = slf.try_borrow()?;
         let args = (
             &borrow.title,
-            borrow.errors(py, include_url_env(py), true, true)?,
+            borrow.errors(py, include_url_env(py), true, true, false, None, LocFormat::Tuple)?,
             borrow.input_type,
             borrow.hide_input,
         )
@@ -428,6 +535,9 @@ pub struct PyLineError {
     error_type: ErrorType,
     location: Location,
     input_value: Py<PyAny>,
+    union_members: Vec<UnionMemberMark>,
+    // where the error's location is in the JSON input, see `validate_json(..., track_positions=True)`
+    position: Option<SourcePosition>,
 }
 
 impl From<PyLineError> for ValLineError {
@@ -437,6 +547,7 @@ impl From<PyLineError> for ValLineError {
             error_type: other.error_type,
             location: other.location,
             input_value: InputValue::Python(other.input_value),
+            union_members: other.union_members,
         }
     }
 }
@@ -470,10 +581,21 @@ impl TryFrom<&Bound<'_, PyAny>> for PyLineError {
             None => py.None(),
         };
 
+        let position = match (
+            dict.get_as(intern!(py, "offset"))?,
+            dict.get_as(intern!(py, "line"))?,
+            dict.get_as(intern!(py, "column"))?,
+        ) {
+            (Some(offset), Some(line), Some(column)) => Some(SourcePosition { offset, line, column }),
+            _ => None,
+        };
+
         Ok(Self {
             error_type,
             location,
             input_value,
+            union_members: Vec::new(),
+            position,
         })
     }
 }
@@ -484,6 +606,17 @@ impl PyLineError {
             error_type: error.error_type,
             location: error.location,
             input_value: error.input_value.into_pyobject(py)?.unbind(),
+            union_members: error
+                .union_members
+                .into_iter()
+                .map(|mark| {
+                    Ok(UnionMemberMark {
+                        union_input: InputValue::Python(mark.union_input.into_pyobject(py)?.unbind()),
+                        ..mark
+                    })
+                })
+                .collect::<PyResult<_>>()?,
+            position: None,
         })
     }
 
@@ -491,6 +624,7 @@ impl PyLineError {
         format!("{url_prefix}{}", self.error_type.type_string())
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn as_dict<'py>(
         &self,
         py: Python<'py>,
@@ -498,11 +632,21 @@ impl PyLineError {
         include_context: bool,
         input_type: InputType,
         include_input: bool,
+        locale: Option<&str>,
+        loc_format: LocFormat,
     ) -> PyResult<Bound<'py, PyDict>> {
         let dict = PyDict::new(py);
         dict.set_item("type", self.error_type.type_string())?;
-        dict.set_item("loc", &self.location)?;
-        dict.set_item("msg", self.error_type.render_message(py, input_type)?)?;
+        match loc_format.render(self.location.reversed_items()) {
+            Some(loc) => dict.set_item("loc", loc)?,
+            None => dict.set_item("loc", &self.location)?,
+        }
+        if let Some(position) = self.position {
+            dict.set_item("line", position.line)?;
+            dict.set_item("column", position.column)?;
+            dict.set_item("offset", position.offset)?;
+        }
+        dict.set_item("msg", self.error_type.render_localized_message(py, input_type, locale)?)?;
         if include_input {
             dict.set_item("input", &self.input_value)?;
         }
@@ -572,6 +716,134 @@ impl PyLineError {
     }
 }
 
+/// A line error as seen from within a union member: only the union members outside `marks` and the location
+/// items up to `loc_end` (in reversed order) are still to be rendered.
+#[derive(Clone, Copy)]
+struct UnionGroupView<'a> {
+    line_error: &'a PyLineError,
+    marks: usize,
+    loc_end: usize,
+}
+
+impl<'a> UnionGroupView<'a> {
+    fn new(line_error: &'a PyLineError) -> Self {
+        Self {
+            line_error,
+            marks: line_error.union_members.len(),
+            loc_end: line_error.location.depth(),
+        }
+    }
+
+    /// the outermost union member mark which hasn't been rendered yet
+    fn outer_mark(&self) -> Option<&'a UnionMemberMark> {
+        self.marks.checked_sub(1).map(|i| &self.line_error.union_members[i])
+    }
+
+    /// the (reversed) location items outside the union member described by `mark`
+    fn outer_loc(&self, mark: &UnionMemberMark) -> &'a [LocItem] {
+        &self.line_error.location.reversed_items()[mark.depth + 1..self.loc_end]
+    }
+
+    fn inner(&self, mark: &UnionMemberMark) -> Self {
+        Self {
+            line_error: self.line_error,
+            marks: self.marks - 1,
+            loc_end: mark.depth,
+        }
+    }
+}
+
+/// Render line errors, nesting the errors from each union which failed to match under the label of
+/// the member which raised them.
+#[allow(clippy::too_many_arguments)]
+fn union_grouped_dicts<'py>(
+    py: Python<'py>,
+    views: &[UnionGroupView<'_>],
+    url_prefix: Option<&str>,
+    include_context: bool,
+    input_type: InputType,
+    include_input: bool,
+    locale: Option<&str>,
+    loc_format: LocFormat,
+) -> PyResult<Vec<Bound<'py, PyDict>>> {
+    let mut dicts = Vec::with_capacity(views.len());
+    let mut index = 0;
+    while index < views.len() {
+        let view = views[index];
+        let Some(mark) = view.outer_mark() else {
+            let dict = view.line_error.as_dict(
+                py,
+                url_prefix,
+                include_context,
+                input_type,
+                include_input,
+                locale,
+                loc_format,
+            )?;
+            let loc = &view.line_error.location.reversed_items()[..view.loc_end];
+            dict.set_item("loc", loc_format.to_py(py, loc)?)?;
+            dicts.push(dict);
+            index += 1;
+            continue;
+        };
+
+        // errors from the same union are contiguous, share the same outer location and come
+        // from members in the order of the union's choices
+        let outer_loc = view.outer_loc(mark);
+        let mut members: Vec<(&UnionMemberMark, &LocItem, Vec<UnionGroupView>)> = Vec::new();
+        while let Some(next) = views.get(index) {
+            let Some(next_mark) = next.outer_mark() else { break };
+            if next.outer_loc(next_mark) != outer_loc {
+                break;
+            }
+            match members.last_mut() {
+                Some((last_mark, _, member_views)) if last_mark.member_index == next_mark.member_index => {
+                    member_views.push(next.inner(next_mark));
+                }
+                Some((last_mark, ..)) if last_mark.member_index > next_mark.member_index => break,
+                _ => {
+                    let label = &next.line_error.location.reversed_items()[next_mark.depth];
+                    members.push((next_mark, label, vec![next.inner(next_mark)]));
+                }
+            }
+            index += 1;
+        }
+
+        let members_list = PyList::empty(py);
+        for (member_mark, label, member_views) in members {
+            let member = PyDict::new(py);
+            member.set_item("member", label)?;
+            member.set_item("closest", member_mark.closest)?;
+            let member_errors = union_grouped_dicts(
+                py,
+                &member_views,
+                url_prefix,
+                include_context,
+                input_type,
+                include_input,
+                locale,
+                loc_format,
+            )?;
+            member.set_item("errors", member_errors)?;
+            members_list.append(member)?;
+        }
+        let error_type = ErrorType::UnionNoMatch { context: None };
+        let dict = PyDict::new(py);
+        dict.set_item("type", error_type.type_string())?;
+        dict.set_item("loc", loc_format.to_py(py, outer_loc)?)?;
+        dict.set_item("msg", error_type.render_localized_message(py, input_type, locale)?)?;
+        if include_input {
+            dict.set_item("input", mark.union_input.clone())?;
+        }
+        if let Some(url_prefix) = url_prefix {
+            dict.set_item("url", format!("{url_prefix}{}", error_type.type_string()))?;
+        }
+        dict.set_item("members", members_list)?;
+        dicts.push(dict);
+    }
+    Ok(dicts)
+}
+
 pub(super) fn json_py_err(error: impl Display) -> PyErr {
     PyValueError::new_err(format!("Error serializing ValidationError to JSON: {error}"))
 }
@@ -591,6 +863,8 @@ struct ValidationErrorSerializer<'slf, 'a, 'py> {
     include_input: bool,
     state: &'slf mut SerializationState<'a, 'py>,
     input_type: &'py InputType,
+    locale: Option<&'py str>,
+    loc_format: LocFormat,
 }
 
 impl ValidationErrorSerializer<'_, '_, '_> {
@@ -608,6 +882,8 @@ impl ValidationErrorSerializer<'_, '_, '_> {
                 include_input: self.include_input,
                 state: RefCell::new(self.state),
                 input_type: self.input_type,
+                locale: self.locale,
+                loc_format: self.loc_format,
             };
             seq.serialize_element(&line_s)?;
         }
@@ -623,6 +899,8 @@ struct PyLineErrorSerializer<'slf, 'a, 'py> {
     include_input: bool,
     state: RefCell<&'slf mut SerializationState<'a, 'py>>,
     input_type: &'py InputType,
+    locale: Option<&'py str>,
+    loc_format: LocFormat,
 }
 
 impl Serialize for PyLineErrorSerializer<'_, '_, '_> {
@@ -631,21 +909,31 @@ impl Serialize for PyLineErrorSerializer<'_, '_, '_> {
         S: Serializer,
     {
         let py = self.py;
-        let size = 3 + [self.url_prefix.is_some(), self.include_context, self.include_input]
-            .into_iter()
-            .filter(|b| *b)
-            .count();
+        let size = 3
+            + [self.url_prefix.is_some(), self.include_context, self.include_input]
+                .into_iter()
+                .filter(|b| *b)
+                .count()
+            + if self.line_error.position.is_some() { 3 } else { 0 };
         let mut map = serializer.serialize_map(Some(size))?;
         let mut state = self.state.borrow_mut();
 
         map.serialize_entry("type", &self.line_error.error_type.type_string())?;
 
-        map.serialize_entry("loc", &self.line_error.location)?;
+        match self.loc_format.render(self.line_error.location.reversed_items()) {
+            Some(loc) => map.serialize_entry("loc", &loc)?,
+            None => map.serialize_entry("loc", &self.line_error.location)?,
+        }
+        if let Some(position) = self.line_error.position {
+            map.serialize_entry("line", &position.line)?;
+            map.serialize_entry("column", &position.column)?;
+            map.serialize_entry("offset", &position.offset)?;
+        }
 
         let msg = self
             .line_error
             .error_type
-            .render_message(py, *self.input_type)
+            .render_localized_message(py, *self.input_type, self.locale)
             .map_err(py_err_json::<S>)?;
         map.serialize_entry("msg", &msg)?;
 
@@ -8,17 +8,19 @@ use pyo3::{intern, prelude::*, IntoPyObjectExt};
 use crate::errors::{ErrorTypeDefaults, InputValue, LocItem, ValError, ValResult};
 use crate::lookup_key::{LookupKey, LookupPath};
 use crate::tools::py_err;
-use crate::validators::{TemporalUnitMode, ValBytesMode};
+use crate::validators::{StrCollectionParsing, TemporalUnitMode, ValBytesMode};
 
 use super::datetime::{EitherDate, EitherDateTime, EitherTime, EitherTimedelta};
 use super::return_enums::{EitherBytes, EitherComplex, EitherInt, EitherString};
-use super::{EitherFloat, GenericIterator, ValidationMatch};
+use super::{EitherFloat, GenericIterator, StringMapping, ValidationMatch};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum InputType {
     Python,
     Json,
     String,
+    MsgPack,
+    Cbor,
 }
 
 impl<'py> IntoPyObject<'py> for InputType {
@@ -31,6 +33,8 @@ impl<'py> IntoPyObject<'py> for InputType {
             Self::Json => intern!(py, "json"),
             Self::Python => intern!(py, "python"),
             Self::String => intern!(py, "string"),
+            Self::MsgPack => intern!(py, "msgpack"),
+            Self::Cbor => intern!(py, "cbor"),
         };
         Ok(text.as_borrowed())
     }
@@ -44,6 +48,8 @@ impl TryFrom<&str> for InputType {
             "python" => Ok(Self::Python),
             "json" => Ok(Self::Json),
             "string" => Ok(Self::String),
+            "msgpack" => Ok(Self::MsgPack),
+            "cbor" => Ok(Self::Cbor),
             s => py_err!(PyValueError; "Invalid error mode: {}", s),
         }
     }
@@ -156,6 +162,17 @@ pub trait Input<'py>: fmt::Debug {
 
     fn validate_frozenset(&self, strict: bool) -> ValMatch<Self::Set<'_>>;
 
+    /// Parse a string into a list of strings for sequence validators, only implemented by string inputs
+    /// when the `str_collection_parsing` config is enabled
+    fn parse_str_sequence(&self, _parsing: StrCollectionParsing) -> ValResult<Option<StringMapping<'py>>> {
+        Ok(None)
+    }
+
+    /// Parse a string into a mapping of strings for dict validators, see `parse_str_sequence`
+    fn parse_str_mapping(&self, _parsing: StrCollectionParsing) -> ValResult<Option<StringMapping<'py>>> {
+        Ok(None)
+    }
+
     fn validate_iter(&self) -> ValResult<GenericIterator<'static>>;
 
     fn validate_date(&self, strict: bool, mode: TemporalUnitMode) -> ValMatch<EitherDate<'py>>;
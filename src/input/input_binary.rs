@@ -0,0 +1,408 @@
+use std::fmt;
+use std::sync::OnceLock;
+
+use ahash::AHashMap;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyTuple};
+
+use crate::errors::{ErrorType, ErrorTypeDefaults, InputValue, LocItem, ValError, ValResult};
+use crate::lookup_key::{LookupKey, LookupPath};
+
+use super::input_abstract::{ConsumeIterator, ValMatch};
+use super::return_enums::ValidationMatch;
+use super::{
+    Arguments, GenericIterator, Input, KeywordArgs, PositionalArgs, ValidatedDict, ValidatedList, ValidatedSet,
+    ValidatedTuple,
+};
+
+/// A value decoded from a binary format, MessagePack and CBOR both decode to a tree where arrays hold values and
+/// maps hold entries whose keys can be any value; the parts of `Input` which only depend on that shape are
+/// implemented once here.
+///
+/// `Display` should write the value as JSON text, it's used for map keys in error locations.
+pub trait BinaryValue: Clone + PartialEq + fmt::Display + Sized {
+    fn as_str(&self) -> Option<&str>;
+    fn as_int(&self) -> Option<i64>;
+    fn as_array(&self) -> Option<&[Self]>;
+    fn as_map(&self) -> Option<&BinaryMap<Self>>;
+    fn new_str(s: String) -> Self;
+    fn new_array(items: Vec<Self>) -> Self;
+    fn into_error_value(self) -> InputValue;
+}
+
+/// Entries of a map, keys can be any value and are not deduplicated
+#[derive(Debug, Clone)]
+pub struct BinaryMap<V> {
+    entries: Vec<(V, V)>,
+    /// built on the first lookup so validating a model or typed dict doesn't scan the entries for each field
+    index: OnceLock<MapIndex>,
+}
+
+/// Position of the last entry for each string and int key, the last value wins for duplicate keys, as with JSON
+#[derive(Debug, Clone, Default)]
+struct MapIndex {
+    str_keys: AHashMap<String, usize>,
+    int_keys: AHashMap<i64, usize>,
+}
+
+impl<V: BinaryValue> BinaryMap<V> {
+    pub fn new(entries: Vec<(V, V)>) -> Self {
+        Self {
+            entries,
+            index: OnceLock::new(),
+        }
+    }
+
+    pub fn get_str(&self, key: &str) -> Option<&V> {
+        let position = *self.index().str_keys.get(key)?;
+        Some(&self.entries[position].1)
+    }
+
+    pub fn get_int(&self, key: i64) -> Option<&V> {
+        let position = *self.index().int_keys.get(&key)?;
+        Some(&self.entries[position].1)
+    }
+
+    fn index(&self) -> &MapIndex {
+        self.index.get_or_init(|| {
+            let mut index = MapIndex::default();
+            for (position, (key, _)) in self.entries.iter().enumerate() {
+                if let Some(s) = key.as_str() {
+                    index.str_keys.insert(s.to_string(), position);
+                } else if let Some(i) = key.as_int() {
+                    index.int_keys.insert(i, position);
+                }
+            }
+            index
+        })
+    }
+}
+
+impl<'a, V> IntoIterator for &'a BinaryMap<V> {
+    type Item = &'a (V, V);
+    type IntoIter = std::slice::Iter<'a, (V, V)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.iter()
+    }
+}
+
+impl<V: PartialEq> PartialEq for BinaryMap<V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.entries == other.entries
+    }
+}
+
+/// Used for map keys, which can be any value, keys other than strings and ints are rendered as they would be
+/// written in JSON, e.g. `true` rather than `Bool(true)`
+impl<V: BinaryValue> From<&V> for LocItem {
+    fn from(value: &V) -> Self {
+        if let Some(s) = value.as_str() {
+            s.into()
+        } else if let Some(i) = value.as_int() {
+            i.into()
+        } else {
+            value.to_string().into()
+        }
+    }
+}
+
+/// Write `s` as a JSON string, for `Display` implementations of `BinaryValue`
+pub(super) fn write_json_str(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    match serde_json::to_string(s) {
+        Ok(quoted) => f.write_str(&quoted),
+        Err(_) => Err(fmt::Error),
+    }
+}
+
+/// Write `items` as a JSON array, for `Display` implementations of `BinaryValue`
+pub(super) fn write_json_array<V: BinaryValue>(f: &mut fmt::Formatter<'_>, items: &[V]) -> fmt::Result {
+    f.write_str("[")?;
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            f.write_str(",")?;
+        }
+        write!(f, "{item}")?;
+    }
+    f.write_str("]")
+}
+
+/// Write `map` as a JSON object, for `Display` implementations of `BinaryValue`
+pub(super) fn write_json_object<V: BinaryValue>(f: &mut fmt::Formatter<'_>, map: &BinaryMap<V>) -> fmt::Result {
+    f.write_str("{")?;
+    for (i, (k, v)) in map.into_iter().enumerate() {
+        if i > 0 {
+            f.write_str(",")?;
+        }
+        match k.as_str() {
+            Some(s) => write_json_str(f, s)?,
+            // JSON object keys are always strings
+            None => write_json_str(f, &k.to_string())?,
+        }
+        write!(f, ":{v}")?;
+    }
+    f.write_str("}")
+}
+
+pub(super) fn float_json_text(f: f64) -> String {
+    if f.is_nan() {
+        "NaN".to_string()
+    } else if f.is_infinite() {
+        if f > 0.0 { "Infinity" } else { "-Infinity" }.to_string()
+    } else {
+        format!("{f:?}")
+    }
+}
+
+/// Convert a map to a dict, array keys become tuples so they're hashable; if any key still isn't hashable
+/// (e.g. a map) the entries are left as a list of key-value pairs, so any map can be shown as the input of an error
+pub(super) fn map_to_object<'py, V: BinaryValue + Input<'py>>(
+    map: &BinaryMap<V>,
+    py: Python<'py>,
+) -> PyResult<Bound<'py, PyAny>> {
+    if map.into_iter().all(|(k, _)| is_hashable_key(k)) {
+        let dict = PyDict::new(py);
+        for (k, v) in map {
+            dict.set_item(key_to_object(k, py)?, v.to_object(py)?)?;
+        }
+        Ok(dict.into_any())
+    } else {
+        let pairs = map
+            .into_iter()
+            .map(|(k, v)| PyTuple::new(py, [k.to_object(py)?, v.to_object(py)?]))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(PyList::new(py, pairs)?.into_any())
+    }
+}
+
+fn is_hashable_key<V: BinaryValue>(key: &V) -> bool {
+    match key.as_array() {
+        Some(items) => items.iter().all(is_hashable_key),
+        None => key.as_map().is_none(),
+    }
+}
+
+fn key_to_object<'py, V: BinaryValue + Input<'py>>(key: &V, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+    match key.as_array() {
+        Some(items) => {
+            let items = items
+                .iter()
+                .map(|item| key_to_object(item, py))
+                .collect::<PyResult<Vec<_>>>()?;
+            Ok(PyTuple::new(py, items)?.into_any())
+        }
+        None => key.to_object(py),
+    }
+}
+
+pub(super) fn as_kwargs<'py, V: BinaryValue + Input<'py>>(value: &V, py: Python<'py>) -> Option<Bound<'py, PyDict>> {
+    let entries = value.as_map()?;
+    let dict = PyDict::new(py);
+    for (k, v) in entries {
+        dict.set_item(k.to_object(py).ok()?, v.to_object(py).ok()?).ok()?;
+    }
+    Some(dict)
+}
+
+pub(super) fn validate_args<'a, 'py, V: BinaryValue + Input<'py>>(value: &'a V) -> ValResult<BinaryArgs<'a, V>> {
+    if let Some(entries) = value.as_map() {
+        Ok(BinaryArgs::new(None, Some(entries)))
+    } else if let Some(items) = value.as_array() {
+        Ok(BinaryArgs::new(Some(items), None))
+    } else {
+        Err(ValError::new(ErrorTypeDefaults::ArgumentsType, value))
+    }
+}
+
+pub(super) fn validate_dataclass_args<'a, 'py, V: BinaryValue + Input<'py>>(
+    value: &'a V,
+    class_name: &str,
+) -> ValResult<BinaryArgs<'a, V>> {
+    match value.as_map() {
+        Some(entries) => Ok(BinaryArgs::new(None, Some(entries))),
+        None => {
+            let class_name = class_name.to_string();
+            Err(ValError::new(
+                ErrorType::DataclassType {
+                    class_name,
+                    context: None,
+                },
+                value,
+            ))
+        }
+    }
+}
+
+pub(super) fn validate_dict<'a, 'py, V: BinaryValue + Input<'py>>(value: &'a V) -> ValResult<&'a BinaryMap<V>> {
+    value
+        .as_map()
+        .ok_or_else(|| ValError::new(ErrorTypeDefaults::DictType, value))
+}
+
+pub(super) fn validate_list<'a, 'py, V: BinaryValue + Input<'py>>(value: &'a V) -> ValMatch<&'a [V]> {
+    match value.as_array() {
+        Some(items) => Ok(ValidationMatch::exact(items)),
+        None => Err(ValError::new(ErrorTypeDefaults::ListType, value)),
+    }
+}
+
+/// Arrays are also used for tuples, sets and frozensets which neither format has a type for, so are only `strict`
+pub(super) fn validate_array<'a, 'py, V: BinaryValue + Input<'py>>(
+    value: &'a V,
+    error_type: ErrorType,
+) -> ValMatch<&'a [V]> {
+    match value.as_array() {
+        Some(items) => Ok(ValidationMatch::strict(items)),
+        None => Err(ValError::new(error_type, value)),
+    }
+}
+
+pub(super) fn validate_iter<'py, V: BinaryValue + Input<'py>>(value: &V) -> ValResult<GenericIterator<'static>>
+where
+    GenericIterator<'static>: From<Vec<V>>,
+{
+    if let Some(items) = value.as_array() {
+        Ok(items.to_vec().into())
+    } else if let Some(s) = value.as_str() {
+        Ok(s.chars().map(|c| V::new_str(c.to_string())).collect::<Vec<_>>().into())
+    } else if let Some(entries) = value.as_map() {
+        // return keys iterator to match python's behavior
+        Ok(entries
+            .entries
+            .iter()
+            .map(|(k, _)| k.clone())
+            .collect::<Vec<_>>()
+            .into())
+    } else {
+        Err(ValError::new(ErrorTypeDefaults::IterableType, value))
+    }
+}
+
+impl<'py, V: BinaryValue + Input<'py>> ValidatedDict<'py> for &'_ BinaryMap<V> {
+    type Key<'k>
+        = &'k V
+    where
+        Self: 'k;
+
+    type Item<'k>
+        = &'k V
+    where
+        Self: 'k;
+
+    fn get_item<'k>(&self, key: &'k LookupKey) -> ValResult<Option<(&'k LookupPath, Self::Item<'_>)>> {
+        key.binary_get(self)
+    }
+
+    fn iterate<'k, R>(
+        &'k self,
+        consumer: impl ConsumeIterator<ValResult<(Self::Key<'k>, Self::Item<'k>)>, Output = R>,
+    ) -> ValResult<R> {
+        Ok(consumer.consume_iterator(self.entries.iter().map(|(k, v)| Ok((k, v)))))
+    }
+
+    fn last_key(&self) -> Option<Self::Key<'_>> {
+        self.entries.last().map(|(k, _)| k)
+    }
+}
+
+impl<'a, 'py, V: BinaryValue + Input<'py>> ValidatedList<'py> for &'a [V] {
+    type Item = &'a V;
+
+    fn len(&self) -> Option<usize> {
+        Some(<[V]>::len(self))
+    }
+    fn iterate<R>(self, consumer: impl ConsumeIterator<PyResult<Self::Item>, Output = R>) -> ValResult<R> {
+        Ok(consumer.consume_iterator(self.iter().map(Ok)))
+    }
+    fn as_py_list(&self) -> Option<&Bound<'py, PyList>> {
+        None
+    }
+}
+
+impl<'a, 'py, V: BinaryValue + Input<'py>> ValidatedTuple<'py> for &'a [V] {
+    type Item = &'a V;
+
+    fn len(&self) -> Option<usize> {
+        Some(<[V]>::len(self))
+    }
+    fn try_for_each(self, mut f: impl FnMut(PyResult<Self::Item>) -> ValResult<()>) -> ValResult<()> {
+        for item in self {
+            f(Ok(item))?;
+        }
+        Ok(())
+    }
+    fn iterate<R>(self, consumer: impl ConsumeIterator<PyResult<Self::Item>, Output = R>) -> ValResult<R> {
+        Ok(consumer.consume_iterator(self.iter().map(Ok)))
+    }
+}
+
+impl<'a, 'py, V: BinaryValue + Input<'py>> ValidatedSet<'py> for &'a [V] {
+    type Item = &'a V;
+
+    fn iterate<R>(self, consumer: impl ConsumeIterator<PyResult<Self::Item>, Output = R>) -> ValResult<R> {
+        Ok(consumer.consume_iterator(self.iter().map(Ok)))
+    }
+}
+
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub struct BinaryArgs<'a, V> {
+    args: Option<&'a [V]>,
+    kwargs: Option<&'a BinaryMap<V>>,
+}
+
+impl<'a, V> BinaryArgs<'a, V> {
+    fn new(args: Option<&'a [V]>, kwargs: Option<&'a BinaryMap<V>>) -> Self {
+        Self { args, kwargs }
+    }
+}
+
+impl<'py, V: BinaryValue + Input<'py>> Arguments<'py> for BinaryArgs<'_, V> {
+    type Args = [V];
+    type Kwargs = BinaryMap<V>;
+
+    fn args(&self) -> Option<&Self::Args> {
+        self.args
+    }
+
+    fn kwargs(&self) -> Option<&Self::Kwargs> {
+        self.kwargs
+    }
+}
+
+impl<'py, V: BinaryValue + Input<'py>> PositionalArgs<'py> for [V] {
+    type Item<'a>
+        = &'a V
+    where
+        V: 'a;
+
+    fn len(&self) -> usize {
+        <[V]>::len(self)
+    }
+    fn get_item(&self, index: usize) -> Option<Self::Item<'_>> {
+        self.get(index)
+    }
+    fn iter(&self) -> impl Iterator<Item = Self::Item<'_>> {
+        <[V]>::iter(self)
+    }
+}
+
+impl<'py, V: BinaryValue + Input<'py>> KeywordArgs<'py> for BinaryMap<V> {
+    type Key<'a>
+        = &'a V
+    where
+        V: 'a;
+    type Item<'a>
+        = &'a V
+    where
+        V: 'a;
+
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+    fn get_item<'k>(&self, key: &'k LookupKey) -> ValResult<Option<(&'k LookupPath, Self::Item<'_>)>> {
+        key.binary_get(self)
+    }
+    fn iter(&self) -> impl Iterator<Item = ValResult<(Self::Key<'_>, Self::Item<'_>)>> {
+        self.entries.iter().map(|(k, v)| Ok((k, v)))
+    }
+}
@@ -0,0 +1,768 @@
+use std::borrow::Cow;
+use std::fmt;
+
+use num_bigint::{BigInt, Sign};
+use num_traits::cast::ToPrimitive;
+use pyo3::prelude::*;
+use pyo3::types::{PyByteArray, PyBytes, PyDict, PyList, PyString};
+use pyo3::IntoPyObjectExt;
+use speedate::MicrosecondsPrecisionOverflowBehavior;
+use strum::EnumMessage;
+
+use crate::errors::{ErrorType, ErrorTypeDefaults, InputValue, ValError, ValResult};
+use crate::input::return_enums::EitherComplex;
+use crate::validators::complex::string_to_complex;
+use crate::validators::decimal::create_decimal;
+use crate::validators::{TemporalUnitMode, ValBytesMode};
+
+use super::datetime::{
+    bytes_as_date, bytes_as_datetime, bytes_as_time, bytes_as_timedelta, float_as_datetime, float_as_duration,
+    float_as_time, int_as_datetime, int_as_duration, int_as_time, EitherDate, EitherDateTime, EitherTime,
+};
+use super::input_abstract::ValMatch;
+use super::input_binary::{
+    self, float_json_text, write_json_array, write_json_object, write_json_str, BinaryArgs, BinaryMap, BinaryValue,
+};
+use super::return_enums::ValidationMatch;
+use super::shared::{float_as_int, int_as_bool, str_as_bool, str_as_float, str_as_int};
+use super::{BorrowInput, EitherBytes, EitherFloat, EitherInt, EitherString, EitherTimedelta, GenericIterator, Input};
+
+const TAG_DATETIME_STRING: u64 = 0;
+const TAG_EPOCH_DATETIME: u64 = 1;
+const TAG_POSITIVE_BIGNUM: u64 = 2;
+const TAG_NEGATIVE_BIGNUM: u64 = 3;
+const TAG_DECIMAL_FRACTION: u64 = 4;
+/// Marks the end of an indefinite-length item
+const BREAK: u8 = 0xff;
+/// Same default as jiter uses for JSON
+const RECURSION_LIMIT: usize = 200;
+
+/// Entries of a CBOR map, keys can be any value and are not deduplicated
+pub type CborMap = BinaryMap<CborValue>;
+
+/// A decoded CBOR data item, tags we understand are decoded into their own variants,
+/// any other tag is dropped and its content used directly
+#[derive(Debug, Clone, PartialEq)]
+pub enum CborValue {
+    Null,
+    Bool(bool),
+    Int(i64),
+    /// integers outside the range of `i64`, either untagged or from tags 2 and 3
+    BigInt(BigInt),
+    /// tags 2 and 3, kept separate from `BigInt` so strict mode knows the value was tagged
+    BigNum(BigInt),
+    Float(f64),
+    Str(String),
+    Bytes(Vec<u8>),
+    Array(Vec<CborValue>),
+    Map(CborMap),
+    /// tag 0, an RFC 3339 date/time string
+    DateTimeString(String),
+    /// tag 1, seconds since the epoch, the content is always `Int`, `BigInt` or `Float`
+    EpochDateTime(Box<CborValue>),
+    /// tag 4, `mantissa * 10 ** exponent`
+    DecimalFraction {
+        exponent: i64,
+        mantissa: BigInt,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct CborError {
+    message: Cow<'static, str>,
+    index: usize,
+}
+
+impl fmt::Display for CborError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at byte {}", self.message, self.index)
+    }
+}
+
+impl CborValue {
+    /// Decode a single CBOR data item, `data` must not contain anything after the item
+    pub fn parse(data: &[u8]) -> Result<Self, CborError> {
+        let mut decoder = Decoder { data, index: 0 };
+        let value = decoder.decode_value(0)?;
+        if decoder.index < data.len() {
+            return Err(decoder.error("trailing bytes after value", decoder.index));
+        }
+        Ok(value)
+    }
+
+    fn decimal_string(exponent: i64, mantissa: &BigInt) -> String {
+        format!("{mantissa}E{exponent}")
+    }
+
+    fn epoch_as_datetime<'py>(&self, seconds: &CborValue) -> ValResult<EitherDateTime<'py>> {
+        match seconds {
+            CborValue::Int(i) => int_as_datetime(self, *i, 0, TemporalUnitMode::Seconds),
+            CborValue::Float(f) => float_as_datetime(self, *f, TemporalUnitMode::Seconds),
+            _ => Err(ValError::new(
+                ErrorType::DatetimeParsing {
+                    error: Cow::Borrowed(
+                        speedate::ParseError::DateTooLarge
+                            .get_documentation()
+                            .unwrap_or_default(),
+                    ),
+                    context: None,
+                },
+                self,
+            )),
+        }
+    }
+}
+
+/// Decode CBOR data from a python `bytes` or `bytearray` object
+pub fn parse_cbor(input: &Bound<'_, PyAny>) -> ValResult<CborValue> {
+    let result = if let Ok(py_bytes) = input.downcast::<PyBytes>() {
+        CborValue::parse(py_bytes.as_bytes())
+    } else if let Ok(py_byte_array) = input.downcast::<PyByteArray>() {
+        CborValue::parse(&py_byte_array.to_vec())
+    } else {
+        return Err(ValError::new(ErrorTypeDefaults::CborType, input));
+    };
+    result.map_err(|e| {
+        ValError::new(
+            ErrorType::CborInvalid {
+                error: e.to_string(),
+                context: None,
+            },
+            input,
+        )
+    })
+}
+
+struct Decoder<'a> {
+    data: &'a [u8],
+    index: usize,
+}
+
+impl<'a> Decoder<'a> {
+    fn decode_value(&mut self, depth: usize) -> Result<CborValue, CborError> {
+        let start = self.index;
+        let [initial] = self.read_array()?;
+        let major = initial >> 5;
+        let info = initial & 0x1f;
+        let value = match major {
+            0 => {
+                let v = self.read_definite_arg(info, start)?;
+                match i64::try_from(v) {
+                    Ok(i) => CborValue::Int(i),
+                    Err(_) => CborValue::BigInt(v.into()),
+                }
+            }
+            1 => {
+                let v = self.read_definite_arg(info, start)?;
+                match i64::try_from(v) {
+                    Ok(i) => CborValue::Int(-1 - i),
+                    Err(_) => CborValue::BigInt(-1 - BigInt::from(v)),
+                }
+            }
+            2 => CborValue::Bytes(self.decode_string_bytes(2, info, start)?),
+            3 => {
+                let bytes = self.decode_string_bytes(3, info, start)?;
+                match String::from_utf8(bytes) {
+                    Ok(s) => CborValue::Str(s),
+                    Err(_) => return Err(self.error("invalid UTF-8 in text string", start)),
+                }
+            }
+            4 => {
+                self.check_depth(depth, start)?;
+                let mut items = Vec::new();
+                match self.read_arg(info, start)? {
+                    Some(len) => {
+                        // every item takes at least one byte, don't trust the length prefix any further than that
+                        items.reserve(self.capacity(len, 1));
+                        for _ in 0..len {
+                            items.push(self.decode_value(depth + 1)?);
+                        }
+                    }
+                    None => {
+                        while !self.take_break()? {
+                            items.push(self.decode_value(depth + 1)?);
+                        }
+                    }
+                }
+                CborValue::Array(items)
+            }
+            5 => {
+                self.check_depth(depth, start)?;
+                let mut entries = Vec::new();
+                match self.read_arg(info, start)? {
+                    Some(len) => {
+                        entries.reserve(self.capacity(len, 2));
+                        for _ in 0..len {
+                            let key = self.decode_value(depth + 1)?;
+                            entries.push((key, self.decode_value(depth + 1)?));
+                        }
+                    }
+                    None => {
+                        while !self.take_break()? {
+                            let key = self.decode_value(depth + 1)?;
+                            entries.push((key, self.decode_value(depth + 1)?));
+                        }
+                    }
+                }
+                CborValue::Map(CborMap::new(entries))
+            }
+            6 => {
+                self.check_depth(depth, start)?;
+                let tag = self.read_definite_arg(info, start)?;
+                let content = self.decode_value(depth + 1)?;
+                self.apply_tag(tag, content, start)?
+            }
+            _ => match info {
+                20 => CborValue::Bool(false),
+                21 => CborValue::Bool(true),
+                // undefined is treated as null
+                22 | 23 => CborValue::Null,
+                25 => CborValue::Float(f16_to_f64(u16::from_be_bytes(self.read_array()?))),
+                26 => CborValue::Float(f32::from_be_bytes(self.read_array()?).into()),
+                27 => CborValue::Float(f64::from_be_bytes(self.read_array()?)),
+                31 => return Err(self.error("unexpected break", start)),
+                _ => return Err(self.error("unsupported simple value", start)),
+            },
+        };
+        Ok(value)
+    }
+
+    fn apply_tag(&self, tag: u64, content: CborValue, start: usize) -> Result<CborValue, CborError> {
+        let value = match (tag, content) {
+            (TAG_DATETIME_STRING, CborValue::Str(s)) => CborValue::DateTimeString(s),
+            (TAG_EPOCH_DATETIME, seconds @ (CborValue::Int(_) | CborValue::BigInt(_) | CborValue::Float(_))) => {
+                CborValue::EpochDateTime(Box::new(seconds))
+            }
+            (TAG_POSITIVE_BIGNUM, CborValue::Bytes(b)) => CborValue::BigNum(BigInt::from_bytes_be(Sign::Plus, &b)),
+            (TAG_NEGATIVE_BIGNUM, CborValue::Bytes(b)) => CborValue::BigNum(-1 - BigInt::from_bytes_be(Sign::Plus, &b)),
+            (TAG_DECIMAL_FRACTION, CborValue::Array(items)) => match items.as_slice() {
+                [CborValue::Int(exponent), CborValue::Int(mantissa)] => CborValue::DecimalFraction {
+                    exponent: *exponent,
+                    mantissa: (*mantissa).into(),
+                },
+                [CborValue::Int(exponent), CborValue::BigInt(mantissa) | CborValue::BigNum(mantissa)] => {
+                    CborValue::DecimalFraction {
+                        exponent: *exponent,
+                        mantissa: mantissa.clone(),
+                    }
+                }
+                _ => return Err(self.error("invalid content for tag 4", start)),
+            },
+            (TAG_DATETIME_STRING..=TAG_DECIMAL_FRACTION, _) => {
+                return Err(self.error(format!("invalid content for tag {tag}"), start))
+            }
+            // other tags, including the self-described CBOR tag, are ignored
+            (_, content) => content,
+        };
+        Ok(value)
+    }
+
+    /// Read the bytes of a byte or text string, joining the chunks of indefinite-length strings
+    fn decode_string_bytes(&mut self, major: u8, info: u8, start: usize) -> Result<Vec<u8>, CborError> {
+        match self.read_arg(info, start)? {
+            Some(len) => Ok(self.take_len(len)?.to_vec()),
+            None => {
+                let mut bytes = Vec::new();
+                while !self.take_break()? {
+                    let chunk_start = self.index;
+                    let [initial] = self.read_array()?;
+                    if initial >> 5 != major {
+                        return Err(self.error("invalid chunk in indefinite-length string", chunk_start));
+                    }
+                    let len = self.read_definite_arg(initial & 0x1f, chunk_start)?;
+                    bytes.extend_from_slice(self.take_len(len)?);
+                }
+                Ok(bytes)
+            }
+        }
+    }
+
+    /// Read the argument following the initial byte, `None` means indefinite length
+    fn read_arg(&mut self, info: u8, start: usize) -> Result<Option<u64>, CborError> {
+        let arg = match info {
+            0..=23 => info.into(),
+            24 => u8::from_be_bytes(self.read_array()?).into(),
+            25 => u16::from_be_bytes(self.read_array()?).into(),
+            26 => u32::from_be_bytes(self.read_array()?).into(),
+            27 => u64::from_be_bytes(self.read_array()?),
+            31 => return Ok(None),
+            _ => return Err(self.error("invalid additional information", start)),
+        };
+        Ok(Some(arg))
+    }
+
+    fn read_definite_arg(&mut self, info: u8, start: usize) -> Result<u64, CborError> {
+        match self.read_arg(info, start)? {
+            Some(arg) => Ok(arg),
+            None => Err(self.error("unexpected indefinite length", start)),
+        }
+    }
+
+    fn take_break(&mut self) -> Result<bool, CborError> {
+        match self.data.get(self.index) {
+            Some(&BREAK) => {
+                self.index += 1;
+                Ok(true)
+            }
+            Some(_) => Ok(false),
+            None => Err(self.eof()),
+        }
+    }
+
+    fn check_depth(&self, depth: usize, start: usize) -> Result<(), CborError> {
+        if depth >= RECURSION_LIMIT {
+            Err(self.error("recursion limit exceeded", start))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn capacity(&self, len: u64, min_item_size: usize) -> usize {
+        usize::try_from(len)
+            .unwrap_or(usize::MAX)
+            .min((self.data.len() - self.index) / min_item_size)
+    }
+
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N], CborError> {
+        Ok(self.take(N)?.try_into().expect("slice has N bytes"))
+    }
+
+    fn take_len(&mut self, len: u64) -> Result<&'a [u8], CborError> {
+        match usize::try_from(len) {
+            Ok(len) => self.take(len),
+            Err(_) => Err(self.eof()),
+        }
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], CborError> {
+        let end = self.index.checked_add(len).filter(|end| *end <= self.data.len());
+        match end {
+            Some(end) => {
+                let bytes = &self.data[self.index..end];
+                self.index = end;
+                Ok(bytes)
+            }
+            None => Err(self.eof()),
+        }
+    }
+
+    fn eof(&self) -> CborError {
+        self.error("EOF while parsing a value", self.data.len())
+    }
+
+    fn error(&self, message: impl Into<Cow<'static, str>>, index: usize) -> CborError {
+        CborError {
+            message: message.into(),
+            index,
+        }
+    }
+}
+
+fn f16_to_f64(half: u16) -> f64 {
+    let exponent = (half >> 10) & 0x1f;
+    let mantissa = f64::from(half & 0x3ff);
+    let value = match exponent {
+        0 => mantissa * 2f64.powi(-24),
+        31 if mantissa == 0.0 => f64::INFINITY,
+        31 => f64::NAN,
+        _ => (mantissa + 1024.0) * 2f64.powi(i32::from(exponent) - 25),
+    };
+    if half & 0x8000 == 0 {
+        value
+    } else {
+        -value
+    }
+}
+
+impl<'py> IntoPyObject<'py> for &CborValue {
+    type Target = PyAny;
+    type Output = Bound<'py, PyAny>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        match self {
+            CborValue::Null => Ok(py.None().into_bound(py)),
+            CborValue::Bool(b) => b.into_bound_py_any(py),
+            CborValue::Int(i) => i.into_bound_py_any(py),
+            CborValue::BigInt(b) | CborValue::BigNum(b) => b.into_bound_py_any(py),
+            CborValue::Float(f) => f.into_bound_py_any(py),
+            CborValue::Str(s) => Ok(PyString::new(py, s).into_any()),
+            CborValue::Bytes(b) => Ok(PyBytes::new(py, b).into_any()),
+            CborValue::Array(items) => Ok(PyList::new(py, items)?.into_any()),
+            CborValue::Map(entries) => input_binary::map_to_object(entries, py),
+            // tagged values which can't be converted leave their raw content, so any value can be shown as
+            // the input of an error
+            CborValue::DateTimeString(s) => match bytes_as_datetime(
+                self,
+                s.as_bytes(),
+                MicrosecondsPrecisionOverflowBehavior::Truncate,
+                TemporalUnitMode::Seconds,
+            )
+            .and_then(|dt| dt.try_into_py(py, self))
+            {
+                Ok(dt) => Ok(dt.into_bound(py)),
+                Err(_) => Ok(PyString::new(py, s).into_any()),
+            },
+            CborValue::EpochDateTime(seconds) => {
+                match self.epoch_as_datetime(seconds).and_then(|dt| dt.try_into_py(py, self)) {
+                    Ok(dt) => Ok(dt.into_bound(py)),
+                    Err(_) => seconds.as_ref().into_pyobject(py),
+                }
+            }
+            CborValue::DecimalFraction { exponent, mantissa } => match create_decimal(
+                &PyString::new(py, &CborValue::decimal_string(*exponent, mantissa)),
+                self,
+            ) {
+                Ok(decimal) => Ok(decimal),
+                Err(_) => {
+                    Ok(PyList::new(py, [exponent.into_bound_py_any(py)?, mantissa.into_bound_py_any(py)?])?.into_any())
+                }
+            },
+        }
+    }
+}
+
+impl<'py> IntoPyObject<'py> for CborValue {
+    type Target = PyAny;
+    type Output = Bound<'py, PyAny>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        (&self).into_pyobject(py)
+    }
+}
+
+impl BinaryValue for CborValue {
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            CborValue::Str(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    fn as_int(&self) -> Option<i64> {
+        match self {
+            CborValue::Int(i) => Some(*i),
+            _ => None,
+        }
+    }
+
+    fn as_array(&self) -> Option<&[Self]> {
+        match self {
+            CborValue::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    fn as_map(&self) -> Option<&CborMap> {
+        match self {
+            CborValue::Map(entries) => Some(entries),
+            _ => None,
+        }
+    }
+
+    fn new_str(s: String) -> Self {
+        CborValue::Str(s)
+    }
+
+    fn new_array(items: Vec<Self>) -> Self {
+        CborValue::Array(items)
+    }
+
+    fn into_error_value(self) -> InputValue {
+        InputValue::Cbor(self)
+    }
+}
+
+/// JSON text for the value, bytes are decoded as UTF-8 and tagged values written as their content
+impl fmt::Display for CborValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CborValue::Null => f.write_str("null"),
+            CborValue::Bool(b) => write!(f, "{b}"),
+            CborValue::Int(i) => write!(f, "{i}"),
+            CborValue::BigInt(b) | CborValue::BigNum(b) => write!(f, "{b}"),
+            CborValue::Float(v) => f.write_str(&float_json_text(*v)),
+            CborValue::Str(s) | CborValue::DateTimeString(s) => write_json_str(f, s),
+            CborValue::Bytes(b) => write_json_str(f, &String::from_utf8_lossy(b)),
+            CborValue::Array(items) => write_json_array(f, items),
+            CborValue::Map(entries) => write_json_object(f, entries),
+            CborValue::EpochDateTime(seconds) => write!(f, "{seconds}"),
+            CborValue::DecimalFraction { exponent, mantissa } => {
+                f.write_str(&CborValue::decimal_string(*exponent, mantissa))
+            }
+        }
+    }
+}
+
+impl<'py> Input<'py> for CborValue {
+    #[inline]
+    fn py_converter(&self) -> impl IntoPyObject<'py> + '_ {
+        self
+    }
+
+    fn as_error_value(&self) -> InputValue {
+        InputValue::Cbor(self.clone())
+    }
+
+    fn is_none(&self) -> bool {
+        matches!(self, CborValue::Null)
+    }
+
+    fn as_kwargs(&self, py: Python<'py>) -> Option<Bound<'py, PyDict>> {
+        input_binary::as_kwargs(self, py)
+    }
+
+    type Arguments<'a> = BinaryArgs<'a, CborValue>;
+
+    fn validate_args(&self) -> ValResult<Self::Arguments<'_>> {
+        input_binary::validate_args(self)
+    }
+
+    #[cfg_attr(has_coverage_attribute, coverage(off))]
+    fn validate_args_v3(&self) -> ValResult<Self::Arguments<'_>> {
+        Err(ValError::new(ErrorTypeDefaults::ArgumentsType, self))
+    }
+
+    fn validate_dataclass_args<'a>(&'a self, class_name: &str) -> ValResult<Self::Arguments<'a>> {
+        input_binary::validate_dataclass_args(self, class_name)
+    }
+
+    fn validate_str(
+        &self,
+        strict: bool,
+        coerce_numbers_to_str: bool,
+    ) -> ValResult<ValidationMatch<EitherString<'_, 'py>>> {
+        // as with JSON, strings can also represent other datatypes so they are `strict` rather than `exact`
+        match self {
+            CborValue::Str(s) => Ok(ValidationMatch::strict(s.as_str().into())),
+            CborValue::DateTimeString(s) if !strict => Ok(ValidationMatch::lax(s.as_str().into())),
+            CborValue::Bytes(b) if !strict => match std::str::from_utf8(b) {
+                Ok(s) => Ok(ValidationMatch::lax(s.into())),
+                Err(_) => Err(ValError::new(ErrorTypeDefaults::StringUnicode, self)),
+            },
+            CborValue::Int(i) if !strict && coerce_numbers_to_str => Ok(ValidationMatch::lax(i.to_string().into())),
+            CborValue::BigInt(b) | CborValue::BigNum(b) if !strict && coerce_numbers_to_str => {
+                Ok(ValidationMatch::lax(b.to_string().into()))
+            }
+            CborValue::Float(f) if !strict && coerce_numbers_to_str => Ok(ValidationMatch::lax(f.to_string().into())),
+            _ => Err(ValError::new(ErrorTypeDefaults::StringType, self)),
+        }
+    }
+
+    fn validate_bytes<'a>(
+        &'a self,
+        strict: bool,
+        mode: ValBytesMode,
+    ) -> ValResult<ValidationMatch<EitherBytes<'a, 'py>>> {
+        match self {
+            CborValue::Bytes(b) => Ok(ValidationMatch::exact(b.as_slice().into())),
+            CborValue::Str(s) if !strict => match mode.deserialize_string(s) {
+                Ok(b) => Ok(ValidationMatch::lax(b)),
+                Err(e) => Err(ValError::new(e, self)),
+            },
+            _ => Err(ValError::new(ErrorTypeDefaults::BytesType, self)),
+        }
+    }
+
+    fn validate_bool(&self, strict: bool) -> ValResult<ValidationMatch<bool>> {
+        match self {
+            CborValue::Bool(b) => Ok(ValidationMatch::exact(*b)),
+            CborValue::Str(s) if !strict => str_as_bool(self, s).map(ValidationMatch::lax),
+            CborValue::Int(int) if !strict => int_as_bool(self, *int).map(ValidationMatch::lax),
+            CborValue::Float(float) if !strict => match float_as_int(self, *float) {
+                Ok(int) => int
+                    .as_bool()
+                    .ok_or_else(|| ValError::new(ErrorTypeDefaults::BoolParsing, self))
+                    .map(ValidationMatch::lax),
+                _ => Err(ValError::new(ErrorTypeDefaults::BoolType, self)),
+            },
+            _ => Err(ValError::new(ErrorTypeDefaults::BoolType, self)),
+        }
+    }
+
+    fn validate_int(&self, strict: bool) -> ValResult<ValidationMatch<EitherInt<'_>>> {
+        match self {
+            CborValue::Int(i) => Ok(ValidationMatch::exact(EitherInt::I64(*i))),
+            CborValue::BigInt(b) | CborValue::BigNum(b) => Ok(ValidationMatch::exact(EitherInt::BigInt(b.clone()))),
+            CborValue::Bool(b) if !strict => Ok(ValidationMatch::lax(EitherInt::I64((*b).into()))),
+            CborValue::Float(f) if !strict => float_as_int(self, *f).map(ValidationMatch::lax),
+            CborValue::Str(str) if !strict => str_as_int(self, str).map(ValidationMatch::lax),
+            CborValue::EpochDateTime(seconds) if !strict => seconds.validate_int(false),
+            _ => Err(ValError::new(ErrorTypeDefaults::IntType, self)),
+        }
+    }
+
+    fn exact_str(&self) -> ValResult<EitherString<'_, 'py>> {
+        match self {
+            CborValue::Str(s) => Ok(s.as_str().into()),
+            _ => Err(ValError::new(ErrorTypeDefaults::StringType, self)),
+        }
+    }
+
+    fn validate_float(&self, strict: bool) -> ValResult<ValidationMatch<EitherFloat<'_>>> {
+        match self {
+            CborValue::Float(f) => Ok(ValidationMatch::exact(EitherFloat::F64(*f))),
+            CborValue::Int(i) => Ok(ValidationMatch::strict(EitherFloat::F64(*i as f64))),
+            CborValue::BigInt(b) | CborValue::BigNum(b) => Ok(ValidationMatch::strict(EitherFloat::F64(
+                b.to_f64().expect("BigInt should always return some value"),
+            ))),
+            CborValue::Bool(b) if !strict => Ok(ValidationMatch::lax(EitherFloat::F64(if *b { 1.0 } else { 0.0 }))),
+            CborValue::Str(str) if !strict => str_as_float(self, str).map(ValidationMatch::lax),
+            CborValue::DecimalFraction { exponent, mantissa } if !strict => {
+                str_as_float(self, &CborValue::decimal_string(*exponent, mantissa)).map(ValidationMatch::lax)
+            }
+            CborValue::EpochDateTime(seconds) if !strict => seconds.validate_float(false),
+            _ => Err(ValError::new(ErrorTypeDefaults::FloatType, self)),
+        }
+    }
+
+    fn validate_decimal(&self, strict: bool, py: Python<'py>) -> ValMatch<Bound<'py, PyAny>> {
+        match self {
+            CborValue::DecimalFraction { exponent, mantissa } => create_decimal(
+                &PyString::new(py, &CborValue::decimal_string(*exponent, mantissa)),
+                self,
+            )
+            .map(ValidationMatch::exact),
+            CborValue::Float(f) => {
+                create_decimal(&PyString::new(py, &f.to_string()), self).map(ValidationMatch::strict)
+            }
+            CborValue::Str(..) | CborValue::Int(..) | CborValue::BigInt(..) | CborValue::BigNum(..) => {
+                create_decimal(&self.into_pyobject(py)?, self).map(ValidationMatch::strict)
+            }
+            CborValue::EpochDateTime(seconds) if !strict => seconds.validate_decimal(false, py),
+            _ => Err(ValError::new(ErrorTypeDefaults::DecimalType, self)),
+        }
+    }
+
+    type Dict<'a> = &'a CborMap;
+
+    fn validate_dict(&self, _strict: bool) -> ValResult<Self::Dict<'_>> {
+        input_binary::validate_dict(self)
+    }
+    #[cfg_attr(has_coverage_attribute, coverage(off))]
+    fn strict_dict(&self) -> ValResult<Self::Dict<'_>> {
+        self.validate_dict(false)
+    }
+
+    type List<'a> = &'a [CborValue];
+
+    fn validate_list(&self, _strict: bool) -> ValMatch<&[CborValue]> {
+        input_binary::validate_list(self)
+    }
+
+    type Tuple<'a> = &'a [CborValue];
+
+    fn validate_tuple(&self, _strict: bool) -> ValMatch<&[CborValue]> {
+        input_binary::validate_array(self, ErrorTypeDefaults::TupleType)
+    }
+
+    type Set<'a> = &'a [CborValue];
+
+    fn validate_set(&self, _strict: bool) -> ValMatch<&[CborValue]> {
+        input_binary::validate_array(self, ErrorTypeDefaults::SetType)
+    }
+
+    fn validate_frozenset(&self, _strict: bool) -> ValMatch<&[CborValue]> {
+        input_binary::validate_array(self, ErrorTypeDefaults::FrozenSetType)
+    }
+
+    fn validate_iter(&self) -> ValResult<GenericIterator<'static>> {
+        input_binary::validate_iter(self)
+    }
+
+    fn validate_date(&self, strict: bool, mode: TemporalUnitMode) -> ValResult<ValidationMatch<EitherDate<'py>>> {
+        match self {
+            CborValue::Str(v) => bytes_as_date(self, v.as_bytes(), mode).map(ValidationMatch::strict),
+            CborValue::DateTimeString(v) if !strict => {
+                bytes_as_date(self, v.as_bytes(), mode).map(ValidationMatch::lax)
+            }
+            _ => Err(ValError::new(ErrorTypeDefaults::DateType, self)),
+        }
+    }
+
+    fn validate_time(
+        &self,
+        strict: bool,
+        microseconds_overflow_behavior: MicrosecondsPrecisionOverflowBehavior,
+    ) -> ValResult<ValidationMatch<EitherTime<'py>>> {
+        match self {
+            CborValue::Str(v) => {
+                bytes_as_time(self, v.as_bytes(), microseconds_overflow_behavior).map(ValidationMatch::strict)
+            }
+            CborValue::Int(v) if !strict => int_as_time(self, *v, 0).map(ValidationMatch::lax),
+            CborValue::Float(v) if !strict => float_as_time(self, *v).map(ValidationMatch::lax),
+            CborValue::BigInt(_) | CborValue::BigNum(_) if !strict => Err(ValError::new(
+                ErrorType::TimeParsing {
+                    error: Cow::Borrowed(
+                        speedate::ParseError::TimeTooLarge
+                            .get_documentation()
+                            .unwrap_or_default(),
+                    ),
+                    context: None,
+                },
+                self,
+            )),
+            _ => Err(ValError::new(ErrorTypeDefaults::TimeType, self)),
+        }
+    }
+
+    fn validate_datetime(
+        &self,
+        strict: bool,
+        microseconds_overflow_behavior: MicrosecondsPrecisionOverflowBehavior,
+        mode: TemporalUnitMode,
+    ) -> ValResult<ValidationMatch<EitherDateTime<'py>>> {
+        match self {
+            CborValue::DateTimeString(v) => {
+                bytes_as_datetime(self, v.as_bytes(), microseconds_overflow_behavior, mode).map(ValidationMatch::exact)
+            }
+            CborValue::EpochDateTime(seconds) => self.epoch_as_datetime(seconds).map(ValidationMatch::exact),
+            CborValue::Str(v) => {
+                bytes_as_datetime(self, v.as_bytes(), microseconds_overflow_behavior, mode).map(ValidationMatch::strict)
+            }
+            CborValue::Int(v) if !strict => int_as_datetime(self, *v, 0, mode).map(ValidationMatch::lax),
+            CborValue::Float(v) if !strict => float_as_datetime(self, *v, mode).map(ValidationMatch::lax),
+            _ => Err(ValError::new(ErrorTypeDefaults::DatetimeType, self)),
+        }
+    }
+
+    fn validate_timedelta(
+        &self,
+        strict: bool,
+        microseconds_overflow_behavior: MicrosecondsPrecisionOverflowBehavior,
+    ) -> ValResult<ValidationMatch<EitherTimedelta<'py>>> {
+        match self {
+            CborValue::Str(v) => {
+                bytes_as_timedelta(self, v.as_bytes(), microseconds_overflow_behavior).map(ValidationMatch::strict)
+            }
+            CborValue::Int(v) if !strict => {
+                int_as_duration(self, *v).map(|duration| ValidationMatch::lax(duration.into()))
+            }
+            CborValue::Float(v) if !strict => {
+                float_as_duration(self, *v).map(|duration| ValidationMatch::lax(duration.into()))
+            }
+            _ => Err(ValError::new(ErrorTypeDefaults::TimeDeltaType, self)),
+        }
+    }
+
+    fn validate_complex(&self, strict: bool, py: Python<'py>) -> ValResult<ValidationMatch<EitherComplex<'py>>> {
+        match self {
+            CborValue::Str(s) => Ok(ValidationMatch::strict(EitherComplex::Py(string_to_complex(
+                &PyString::new(py, s),
+                self,
+            )?))),
+            CborValue::Float(f) if !strict => Ok(ValidationMatch::lax(EitherComplex::Complex([*f, 0.0]))),
+            CborValue::Int(i) if !strict => Ok(ValidationMatch::lax(EitherComplex::Complex([(*i) as f64, 0.0]))),
+            CborValue::Float(_) | CborValue::Int(_) => Err(ValError::new(ErrorTypeDefaults::ComplexStrParsing, self)),
+            _ => Err(ValError::new(ErrorTypeDefaults::ComplexType, self)),
+        }
+    }
+}
+
+impl BorrowInput<'_> for CborValue {
+    type Input = CborValue;
+    fn borrow_input(&self) -> &Self::Input {
+        self
+    }
+}
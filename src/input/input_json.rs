@@ -1,14 +1,17 @@
 use std::borrow::Cow;
 use std::collections::HashSet;
 
-use jiter::{JsonArray, JsonObject, JsonValue};
+use ahash::AHashMap;
+use jiter::{Jiter, JiterResult, JsonArray, JsonObject, JsonValue, Peek};
 use num_traits::cast::ToPrimitive;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyString};
 use speedate::MicrosecondsPrecisionOverflowBehavior;
 use strum::EnumMessage;
 
-use crate::errors::{ErrorType, ErrorTypeDefaults, InputValue, LocItem, ValError, ValResult};
+use crate::errors::{
+    ErrorType, ErrorTypeDefaults, InputValue, LineStarts, LocItem, Location, SourcePosition, ValError, ValResult,
+};
 use crate::input::return_enums::EitherComplex;
 use crate::lookup_key::{LookupKey, LookupPath};
 use crate::validators::complex::string_to_complex;
@@ -698,3 +701,148 @@ impl<'data> KeywordArgs<'_> for JsonObject<'data> {
         self.as_slice().iter().map(|(k, v)| Ok((k.as_ref(), v)))
     }
 }
+
+/// The offset of every value in a JSON document, built in one pass so the positions of many error
+/// locations can be found without rescanning the document.
+pub(crate) struct JsonPositions<'a> {
+    json_data: &'a [u8],
+    line_starts: LineStarts,
+    /// the root value is first
+    nodes: Vec<JsonNode>,
+}
+
+struct JsonNode {
+    offset: usize,
+    children: JsonChildren,
+}
+
+enum JsonChildren {
+    Scalar,
+    Array(Vec<usize>),
+    /// the last value wins when a key is repeated, as when validating
+    Object(AHashMap<String, usize>),
+}
+
+/// An array or object whose values are still being added, `key` is the key of the object's next value.
+struct OpenContainer {
+    index: usize,
+    children: JsonChildren,
+    key: String,
+}
+
+impl<'a> JsonPositions<'a> {
+    /// Index `json_data`, if it isn't valid JSON the error is the position where it stops being valid.
+    pub fn new(json_data: &'a [u8]) -> Result<Self, SourcePosition> {
+        let line_starts = LineStarts::new(json_data);
+        // check the document with jiter first, so syntax errors (including nesting deeper than jiter's
+        // recursion limit) come straight from jiter without indexing anything
+        let mut jiter = Jiter::new(json_data).with_allow_inf_nan();
+        if let Err(error) = jiter.next_skip().and_then(|()| jiter.finish()) {
+            return Err(line_starts.position(json_data, error.index));
+        }
+
+        let mut positions = Self {
+            json_data,
+            line_starts,
+            nodes: Vec::new(),
+        };
+        let mut jiter = Jiter::new(json_data).with_allow_inf_nan();
+        match positions.add_values(&mut jiter) {
+            Ok(()) => Ok(positions),
+            Err(error) => Err(positions.line_starts.position(json_data, error.index)),
+        }
+    }
+
+    /// Find where the value at `location` sits in the document, location items which don't match the
+    /// structure of the document, e.g. union member labels, are ignored. For errors about a missing key
+    /// the position is that of the object the key is missing from.
+    pub fn position(&self, location: &Location) -> SourcePosition {
+        let mut node = &self.nodes[0];
+        for item in location.reversed_items().iter().rev() {
+            let child = match (&node.children, item) {
+                (JsonChildren::Object(keys), LocItem::S(key)) => keys.get(key.as_str()),
+                (JsonChildren::Object(keys), LocItem::I(key)) => keys.get(&key.to_string()),
+                (JsonChildren::Array(items), LocItem::I(index)) => {
+                    usize::try_from(*index).ok().and_then(|index| items.get(index))
+                }
+                _ => None,
+            };
+            if let Some(&child) = child {
+                node = &self.nodes[child];
+            }
+        }
+        self.line_starts.position(self.json_data, node.offset)
+    }
+
+    /// Add the value `jiter` is at and everything inside it, with a stack of the containers being filled
+    /// rather than recursion.
+    fn add_values(&mut self, jiter: &mut Jiter) -> JiterResult<()> {
+        let mut open: Vec<OpenContainer> = Vec::new();
+        loop {
+            let peek = jiter.peek()?;
+            let index = self.nodes.len();
+            self.nodes.push(JsonNode {
+                offset: jiter.current_index(),
+                children: JsonChildren::Scalar,
+            });
+            if let Some(parent) = open.last_mut() {
+                match &mut parent.children {
+                    JsonChildren::Array(items) => items.push(index),
+                    JsonChildren::Object(keys) => {
+                        keys.insert(std::mem::take(&mut parent.key), index);
+                    }
+                    JsonChildren::Scalar => {}
+                }
+            }
+            match peek {
+                Peek::Array => {
+                    if jiter.known_array()?.is_some() {
+                        open.push(OpenContainer {
+                            index,
+                            children: JsonChildren::Array(Vec::new()),
+                            key: String::new(),
+                        });
+                        continue;
+                    }
+                    self.nodes[index].children = JsonChildren::Array(Vec::new());
+                }
+                Peek::Object => {
+                    if let Some(key) = jiter.known_object()? {
+                        open.push(OpenContainer {
+                            index,
+                            children: JsonChildren::Object(AHashMap::new()),
+                            key: key.to_owned(),
+                        });
+                        continue;
+                    }
+                    self.nodes[index].children = JsonChildren::Object(AHashMap::new());
+                }
+                _ => jiter.next_skip()?,
+            }
+
+            // the value is complete, move on to the next value of the innermost unfinished container
+            loop {
+                let Some(container) = open.last_mut() else {
+                    return jiter.finish();
+                };
+                let more = match container.children {
+                    JsonChildren::Array(_) => jiter.array_step()?.is_some(),
+                    JsonChildren::Object(_) => match jiter.next_key()? {
+                        Some(key) => {
+                            key.clone_into(&mut container.key);
+                            true
+                        }
+                        None => false,
+                    },
+                    JsonChildren::Scalar => false,
+                };
+                if more {
+                    break;
+                }
+                if let Some(container) = open.pop() {
+                    self.nodes[container.index].children = container.children;
+                }
+            }
+        }
+    }
+}
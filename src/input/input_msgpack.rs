@@ -0,0 +1,662 @@
+use std::borrow::Cow;
+use std::fmt;
+
+use num_bigint::BigInt;
+use num_traits::cast::ToPrimitive;
+use pyo3::prelude::*;
+use pyo3::types::{PyByteArray, PyBytes, PyDict, PyList, PyString, PyTuple};
+use pyo3::IntoPyObjectExt;
+use rmp::Marker;
+use speedate::MicrosecondsPrecisionOverflowBehavior;
+use strum::EnumMessage;
+
+use crate::errors::{ErrorType, ErrorTypeDefaults, InputValue, ValError, ValResult};
+use crate::input::return_enums::EitherComplex;
+use crate::validators::complex::string_to_complex;
+use crate::validators::decimal::create_decimal;
+use crate::validators::{TemporalUnitMode, ValBytesMode};
+
+use super::datetime::{
+    bytes_as_date, bytes_as_datetime, bytes_as_time, bytes_as_timedelta, float_as_datetime, float_as_duration,
+    float_as_time, int_as_datetime, int_as_duration, int_as_time, EitherDate, EitherDateTime, EitherTime,
+};
+use super::input_abstract::ValMatch;
+use super::input_binary::{
+    self, float_json_text, write_json_array, write_json_object, write_json_str, BinaryArgs, BinaryMap, BinaryValue,
+};
+use super::return_enums::ValidationMatch;
+use super::shared::{float_as_int, int_as_bool, str_as_bool, str_as_float, str_as_int};
+use super::{BorrowInput, EitherBytes, EitherFloat, EitherInt, EitherString, EitherTimedelta, GenericIterator, Input};
+
+/// Extension type reserved by the MessagePack spec for timestamps
+const TIMESTAMP_EXT_TYPE: i8 = -1;
+/// Same default as jiter uses for JSON
+const RECURSION_LIMIT: usize = 200;
+
+/// Entries of a MessagePack map, keys can be any value and are not deduplicated
+pub type MsgPackMap = BinaryMap<MsgPackValue>;
+
+/// A decoded MessagePack value
+#[derive(Debug, Clone, PartialEq)]
+pub enum MsgPackValue {
+    Nil,
+    Bool(bool),
+    Int(i64),
+    /// unsigned integers too large for an `i64`
+    BigInt(BigInt),
+    Float(f64),
+    Str(String),
+    Bin(Vec<u8>),
+    Array(Vec<MsgPackValue>),
+    Map(MsgPackMap),
+    /// the timestamp extension type
+    Timestamp {
+        seconds: i64,
+        nanoseconds: u32,
+    },
+    /// any other extension type
+    Ext(i8, Vec<u8>),
+}
+
+#[derive(Debug, Clone)]
+pub struct MsgPackError {
+    message: Cow<'static, str>,
+    index: usize,
+}
+
+impl fmt::Display for MsgPackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at byte {}", self.message, self.index)
+    }
+}
+
+impl MsgPackValue {
+    /// Decode a single MessagePack value, `data` must not contain anything after the value
+    pub fn parse(data: &[u8]) -> Result<Self, MsgPackError> {
+        let mut decoder = Decoder { data, index: 0 };
+        let value = decoder.decode_value(0)?;
+        if decoder.index < data.len() {
+            return Err(decoder.error("trailing bytes after value", decoder.index));
+        }
+        Ok(value)
+    }
+
+    fn timestamp_as_datetime<'py>(&self, seconds: i64, nanoseconds: u32) -> ValResult<EitherDateTime<'py>> {
+        int_as_datetime(self, seconds, nanoseconds / 1_000, TemporalUnitMode::Seconds)
+    }
+}
+
+/// Decode MessagePack data from a python `bytes` or `bytearray` object
+pub fn parse_msgpack(input: &Bound<'_, PyAny>) -> ValResult<MsgPackValue> {
+    let result = if let Ok(py_bytes) = input.downcast::<PyBytes>() {
+        MsgPackValue::parse(py_bytes.as_bytes())
+    } else if let Ok(py_byte_array) = input.downcast::<PyByteArray>() {
+        MsgPackValue::parse(&py_byte_array.to_vec())
+    } else {
+        return Err(ValError::new(ErrorTypeDefaults::MsgpackType, input));
+    };
+    result.map_err(|e| {
+        ValError::new(
+            ErrorType::MsgpackInvalid {
+                error: e.to_string(),
+                context: None,
+            },
+            input,
+        )
+    })
+}
+
+struct Decoder<'a> {
+    data: &'a [u8],
+    index: usize,
+}
+
+impl<'a> Decoder<'a> {
+    fn decode_value(&mut self, depth: usize) -> Result<MsgPackValue, MsgPackError> {
+        let start = self.index;
+        let [byte] = self.read_array()?;
+        let value = match Marker::from_u8(byte) {
+            Marker::Null => MsgPackValue::Nil,
+            Marker::True => MsgPackValue::Bool(true),
+            Marker::False => MsgPackValue::Bool(false),
+            Marker::FixPos(v) => MsgPackValue::Int(v.into()),
+            Marker::FixNeg(v) => MsgPackValue::Int(v.into()),
+            Marker::U8 => MsgPackValue::Int(u8::from_be_bytes(self.read_array()?).into()),
+            Marker::U16 => MsgPackValue::Int(u16::from_be_bytes(self.read_array()?).into()),
+            Marker::U32 => MsgPackValue::Int(u32::from_be_bytes(self.read_array()?).into()),
+            Marker::U64 => {
+                let v = u64::from_be_bytes(self.read_array()?);
+                match i64::try_from(v) {
+                    Ok(i) => MsgPackValue::Int(i),
+                    Err(_) => MsgPackValue::BigInt(v.into()),
+                }
+            }
+            Marker::I8 => MsgPackValue::Int(i8::from_be_bytes(self.read_array()?).into()),
+            Marker::I16 => MsgPackValue::Int(i16::from_be_bytes(self.read_array()?).into()),
+            Marker::I32 => MsgPackValue::Int(i32::from_be_bytes(self.read_array()?).into()),
+            Marker::I64 => MsgPackValue::Int(i64::from_be_bytes(self.read_array()?)),
+            Marker::F32 => MsgPackValue::Float(f32::from_be_bytes(self.read_array()?).into()),
+            Marker::F64 => MsgPackValue::Float(f64::from_be_bytes(self.read_array()?)),
+            Marker::FixStr(len) => self.decode_str(len.into(), start)?,
+            Marker::Str8 => self.decode_str_len(1, start)?,
+            Marker::Str16 => self.decode_str_len(2, start)?,
+            Marker::Str32 => self.decode_str_len(4, start)?,
+            Marker::Bin8 => self.decode_bin(1)?,
+            Marker::Bin16 => self.decode_bin(2)?,
+            Marker::Bin32 => self.decode_bin(4)?,
+            Marker::FixArray(len) => self.decode_array(len.into(), depth, start)?,
+            Marker::Array16 => self.decode_array_len(2, depth, start)?,
+            Marker::Array32 => self.decode_array_len(4, depth, start)?,
+            Marker::FixMap(len) => self.decode_map(len.into(), depth, start)?,
+            Marker::Map16 => self.decode_map_len(2, depth, start)?,
+            Marker::Map32 => self.decode_map_len(4, depth, start)?,
+            Marker::FixExt1 => self.decode_ext(1, start)?,
+            Marker::FixExt2 => self.decode_ext(2, start)?,
+            Marker::FixExt4 => self.decode_ext(4, start)?,
+            Marker::FixExt8 => self.decode_ext(8, start)?,
+            Marker::FixExt16 => self.decode_ext(16, start)?,
+            Marker::Ext8 => self.decode_ext_len(1, start)?,
+            Marker::Ext16 => self.decode_ext_len(2, start)?,
+            Marker::Ext32 => self.decode_ext_len(4, start)?,
+            Marker::Reserved => return Err(self.error(format!("reserved marker 0x{byte:02x}"), start)),
+        };
+        Ok(value)
+    }
+
+    fn decode_str_len(&mut self, size: usize, start: usize) -> Result<MsgPackValue, MsgPackError> {
+        let len = self.read_len(size)?;
+        self.decode_str(len, start)
+    }
+
+    fn decode_str(&mut self, len: usize, start: usize) -> Result<MsgPackValue, MsgPackError> {
+        let bytes = self.take(len)?;
+        match std::str::from_utf8(bytes) {
+            Ok(s) => Ok(MsgPackValue::Str(s.to_owned())),
+            Err(_) => Err(self.error("invalid UTF-8 in string", start)),
+        }
+    }
+
+    fn decode_bin(&mut self, size: usize) -> Result<MsgPackValue, MsgPackError> {
+        let len = self.read_len(size)?;
+        Ok(MsgPackValue::Bin(self.take(len)?.to_vec()))
+    }
+
+    fn decode_array_len(&mut self, size: usize, depth: usize, start: usize) -> Result<MsgPackValue, MsgPackError> {
+        let len = self.read_len(size)?;
+        self.decode_array(len, depth, start)
+    }
+
+    fn decode_array(&mut self, len: usize, depth: usize, start: usize) -> Result<MsgPackValue, MsgPackError> {
+        self.check_depth(depth, start)?;
+        // every item takes at least one byte, don't trust the length prefix any further than that
+        let mut items = Vec::with_capacity(len.min(self.remaining()));
+        for _ in 0..len {
+            items.push(self.decode_value(depth + 1)?);
+        }
+        Ok(MsgPackValue::Array(items))
+    }
+
+    fn decode_map_len(&mut self, size: usize, depth: usize, start: usize) -> Result<MsgPackValue, MsgPackError> {
+        let len = self.read_len(size)?;
+        self.decode_map(len, depth, start)
+    }
+
+    fn decode_map(&mut self, len: usize, depth: usize, start: usize) -> Result<MsgPackValue, MsgPackError> {
+        self.check_depth(depth, start)?;
+        let mut entries = Vec::with_capacity(len.min(self.remaining() / 2));
+        for _ in 0..len {
+            let key = self.decode_value(depth + 1)?;
+            let value = self.decode_value(depth + 1)?;
+            entries.push((key, value));
+        }
+        Ok(MsgPackValue::Map(MsgPackMap::new(entries)))
+    }
+
+    fn decode_ext_len(&mut self, size: usize, start: usize) -> Result<MsgPackValue, MsgPackError> {
+        let len = self.read_len(size)?;
+        self.decode_ext(len, start)
+    }
+
+    fn decode_ext(&mut self, len: usize, start: usize) -> Result<MsgPackValue, MsgPackError> {
+        let ext_type = i8::from_be_bytes(self.read_array()?);
+        let data = self.take(len)?;
+        if ext_type != TIMESTAMP_EXT_TYPE {
+            return Ok(MsgPackValue::Ext(ext_type, data.to_vec()));
+        }
+        let (seconds, nanoseconds) = match data.len() {
+            4 => (
+                u32::from_be_bytes(data.try_into().expect("slice has 4 bytes")).into(),
+                0,
+            ),
+            8 => {
+                let v = u64::from_be_bytes(data.try_into().expect("slice has 8 bytes"));
+                ((v & 0x3_ffff_ffff) as i64, (v >> 34) as u32)
+            }
+            12 => (
+                i64::from_be_bytes(data[4..].try_into().expect("slice has 8 bytes")),
+                u32::from_be_bytes(data[..4].try_into().expect("slice has 4 bytes")),
+            ),
+            _ => return Err(self.error(format!("invalid timestamp length {len}"), start)),
+        };
+        if nanoseconds >= 1_000_000_000 {
+            return Err(self.error("timestamp nanoseconds out of range", start));
+        }
+        Ok(MsgPackValue::Timestamp { seconds, nanoseconds })
+    }
+
+    fn check_depth(&self, depth: usize, start: usize) -> Result<(), MsgPackError> {
+        if depth >= RECURSION_LIMIT {
+            Err(self.error("recursion limit exceeded", start))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn read_len(&mut self, size: usize) -> Result<usize, MsgPackError> {
+        Ok(self.take(size)?.iter().fold(0, |len, &b| (len << 8) | usize::from(b)))
+    }
+
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N], MsgPackError> {
+        Ok(self.take(N)?.try_into().expect("slice has N bytes"))
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], MsgPackError> {
+        let end = self.index.checked_add(len).filter(|end| *end <= self.data.len());
+        match end {
+            Some(end) => {
+                let bytes = &self.data[self.index..end];
+                self.index = end;
+                Ok(bytes)
+            }
+            None => Err(self.error("EOF while parsing a value", self.data.len())),
+        }
+    }
+
+    fn remaining(&self) -> usize {
+        self.data.len() - self.index
+    }
+
+    fn error(&self, message: impl Into<Cow<'static, str>>, index: usize) -> MsgPackError {
+        MsgPackError {
+            message: message.into(),
+            index,
+        }
+    }
+}
+
+impl<'py> IntoPyObject<'py> for &MsgPackValue {
+    type Target = PyAny;
+    type Output = Bound<'py, PyAny>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        match self {
+            MsgPackValue::Nil => Ok(py.None().into_bound(py)),
+            MsgPackValue::Bool(b) => b.into_bound_py_any(py),
+            MsgPackValue::Int(i) => i.into_bound_py_any(py),
+            MsgPackValue::BigInt(b) => b.into_bound_py_any(py),
+            MsgPackValue::Float(f) => f.into_bound_py_any(py),
+            MsgPackValue::Str(s) => Ok(PyString::new(py, s).into_any()),
+            MsgPackValue::Bin(b) => Ok(PyBytes::new(py, b).into_any()),
+            MsgPackValue::Array(items) => Ok(PyList::new(py, items)?.into_any()),
+            MsgPackValue::Map(entries) => input_binary::map_to_object(entries, py),
+            MsgPackValue::Timestamp { seconds, nanoseconds } => {
+                match self
+                    .timestamp_as_datetime(*seconds, *nanoseconds)
+                    .and_then(|dt| dt.try_into_py(py, self))
+                {
+                    Ok(dt) => Ok(dt.into_bound(py)),
+                    // out of range for a `datetime`, leave the raw timestamp
+                    Err(_) => Ok(PyTuple::new(
+                        py,
+                        [seconds.into_bound_py_any(py)?, nanoseconds.into_bound_py_any(py)?],
+                    )?
+                    .into_any()),
+                }
+            }
+            MsgPackValue::Ext(code, data) => {
+                Ok(PyTuple::new(py, [code.into_bound_py_any(py)?, PyBytes::new(py, data).into_any()])?.into_any())
+            }
+        }
+    }
+}
+
+impl<'py> IntoPyObject<'py> for MsgPackValue {
+    type Target = PyAny;
+    type Output = Bound<'py, PyAny>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        (&self).into_pyobject(py)
+    }
+}
+
+impl BinaryValue for MsgPackValue {
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            MsgPackValue::Str(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    fn as_int(&self) -> Option<i64> {
+        match self {
+            MsgPackValue::Int(i) => Some(*i),
+            _ => None,
+        }
+    }
+
+    fn as_array(&self) -> Option<&[Self]> {
+        match self {
+            MsgPackValue::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    fn as_map(&self) -> Option<&MsgPackMap> {
+        match self {
+            MsgPackValue::Map(entries) => Some(entries),
+            _ => None,
+        }
+    }
+
+    fn new_str(s: String) -> Self {
+        MsgPackValue::Str(s)
+    }
+
+    fn new_array(items: Vec<Self>) -> Self {
+        MsgPackValue::Array(items)
+    }
+
+    fn into_error_value(self) -> InputValue {
+        InputValue::MsgPack(self)
+    }
+}
+
+/// JSON text for the value, bytes are decoded as UTF-8 and timestamps written as RFC 3339 strings
+impl fmt::Display for MsgPackValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MsgPackValue::Nil => f.write_str("null"),
+            MsgPackValue::Bool(b) => write!(f, "{b}"),
+            MsgPackValue::Int(i) => write!(f, "{i}"),
+            MsgPackValue::BigInt(b) => write!(f, "{b}"),
+            MsgPackValue::Float(v) => f.write_str(&float_json_text(*v)),
+            MsgPackValue::Str(s) => write_json_str(f, s),
+            MsgPackValue::Bin(b) => write_json_str(f, &String::from_utf8_lossy(b)),
+            MsgPackValue::Array(items) => write_json_array(f, items),
+            MsgPackValue::Map(entries) => write_json_object(f, entries),
+            MsgPackValue::Timestamp { seconds, nanoseconds } => {
+                match speedate::DateTime::from_timestamp(*seconds, nanoseconds / 1_000) {
+                    Ok(dt) => write_json_str(f, &dt.to_string()),
+                    Err(_) => write!(f, "{seconds}"),
+                }
+            }
+            MsgPackValue::Ext(code, data) => {
+                write!(f, "[{code},")?;
+                write_json_str(f, &String::from_utf8_lossy(data))?;
+                f.write_str("]")
+            }
+        }
+    }
+}
+
+impl<'py> Input<'py> for MsgPackValue {
+    #[inline]
+    fn py_converter(&self) -> impl IntoPyObject<'py> + '_ {
+        self
+    }
+
+    fn as_error_value(&self) -> InputValue {
+        InputValue::MsgPack(self.clone())
+    }
+
+    fn is_none(&self) -> bool {
+        matches!(self, MsgPackValue::Nil)
+    }
+
+    fn as_kwargs(&self, py: Python<'py>) -> Option<Bound<'py, PyDict>> {
+        input_binary::as_kwargs(self, py)
+    }
+
+    type Arguments<'a> = BinaryArgs<'a, MsgPackValue>;
+
+    fn validate_args(&self) -> ValResult<Self::Arguments<'_>> {
+        input_binary::validate_args(self)
+    }
+
+    #[cfg_attr(has_coverage_attribute, coverage(off))]
+    fn validate_args_v3(&self) -> ValResult<Self::Arguments<'_>> {
+        Err(ValError::new(ErrorTypeDefaults::ArgumentsType, self))
+    }
+
+    fn validate_dataclass_args<'a>(&'a self, class_name: &str) -> ValResult<Self::Arguments<'a>> {
+        input_binary::validate_dataclass_args(self, class_name)
+    }
+
+    fn validate_str(
+        &self,
+        strict: bool,
+        coerce_numbers_to_str: bool,
+    ) -> ValResult<ValidationMatch<EitherString<'_, 'py>>> {
+        // as with JSON, strings can also represent other datatypes so they are `strict` rather than `exact`
+        match self {
+            MsgPackValue::Str(s) => Ok(ValidationMatch::strict(s.as_str().into())),
+            MsgPackValue::Bin(b) if !strict => match std::str::from_utf8(b) {
+                Ok(s) => Ok(ValidationMatch::lax(s.into())),
+                Err(_) => Err(ValError::new(ErrorTypeDefaults::StringUnicode, self)),
+            },
+            MsgPackValue::Int(i) if !strict && coerce_numbers_to_str => Ok(ValidationMatch::lax(i.to_string().into())),
+            MsgPackValue::BigInt(b) if !strict && coerce_numbers_to_str => {
+                Ok(ValidationMatch::lax(b.to_string().into()))
+            }
+            MsgPackValue::Float(f) if !strict && coerce_numbers_to_str => {
+                Ok(ValidationMatch::lax(f.to_string().into()))
+            }
+            _ => Err(ValError::new(ErrorTypeDefaults::StringType, self)),
+        }
+    }
+
+    fn validate_bytes<'a>(
+        &'a self,
+        strict: bool,
+        mode: ValBytesMode,
+    ) -> ValResult<ValidationMatch<EitherBytes<'a, 'py>>> {
+        match self {
+            MsgPackValue::Bin(b) => Ok(ValidationMatch::exact(b.as_slice().into())),
+            MsgPackValue::Str(s) if !strict => match mode.deserialize_string(s) {
+                Ok(b) => Ok(ValidationMatch::lax(b)),
+                Err(e) => Err(ValError::new(e, self)),
+            },
+            _ => Err(ValError::new(ErrorTypeDefaults::BytesType, self)),
+        }
+    }
+
+    fn validate_bool(&self, strict: bool) -> ValResult<ValidationMatch<bool>> {
+        match self {
+            MsgPackValue::Bool(b) => Ok(ValidationMatch::exact(*b)),
+            MsgPackValue::Str(s) if !strict => str_as_bool(self, s).map(ValidationMatch::lax),
+            MsgPackValue::Int(int) if !strict => int_as_bool(self, *int).map(ValidationMatch::lax),
+            MsgPackValue::Float(float) if !strict => match float_as_int(self, *float) {
+                Ok(int) => int
+                    .as_bool()
+                    .ok_or_else(|| ValError::new(ErrorTypeDefaults::BoolParsing, self))
+                    .map(ValidationMatch::lax),
+                _ => Err(ValError::new(ErrorTypeDefaults::BoolType, self)),
+            },
+            _ => Err(ValError::new(ErrorTypeDefaults::BoolType, self)),
+        }
+    }
+
+    fn validate_int(&self, strict: bool) -> ValResult<ValidationMatch<EitherInt<'_>>> {
+        match self {
+            MsgPackValue::Int(i) => Ok(ValidationMatch::exact(EitherInt::I64(*i))),
+            MsgPackValue::BigInt(b) => Ok(ValidationMatch::exact(EitherInt::BigInt(b.clone()))),
+            MsgPackValue::Bool(b) if !strict => Ok(ValidationMatch::lax(EitherInt::I64((*b).into()))),
+            MsgPackValue::Float(f) if !strict => float_as_int(self, *f).map(ValidationMatch::lax),
+            MsgPackValue::Str(str) if !strict => str_as_int(self, str).map(ValidationMatch::lax),
+            _ => Err(ValError::new(ErrorTypeDefaults::IntType, self)),
+        }
+    }
+
+    fn exact_str(&self) -> ValResult<EitherString<'_, 'py>> {
+        match self {
+            MsgPackValue::Str(s) => Ok(s.as_str().into()),
+            _ => Err(ValError::new(ErrorTypeDefaults::StringType, self)),
+        }
+    }
+
+    fn validate_float(&self, strict: bool) -> ValResult<ValidationMatch<EitherFloat<'_>>> {
+        match self {
+            MsgPackValue::Float(f) => Ok(ValidationMatch::exact(EitherFloat::F64(*f))),
+            MsgPackValue::Int(i) => Ok(ValidationMatch::strict(EitherFloat::F64(*i as f64))),
+            MsgPackValue::BigInt(b) => Ok(ValidationMatch::strict(EitherFloat::F64(
+                b.to_f64().expect("BigInt should always return some value"),
+            ))),
+            MsgPackValue::Bool(b) if !strict => Ok(ValidationMatch::lax(EitherFloat::F64(if *b { 1.0 } else { 0.0 }))),
+            MsgPackValue::Str(str) if !strict => str_as_float(self, str).map(ValidationMatch::lax),
+            _ => Err(ValError::new(ErrorTypeDefaults::FloatType, self)),
+        }
+    }
+
+    fn validate_decimal(&self, _strict: bool, py: Python<'py>) -> ValMatch<Bound<'py, PyAny>> {
+        match self {
+            MsgPackValue::Float(f) => {
+                create_decimal(&PyString::new(py, &f.to_string()), self).map(ValidationMatch::strict)
+            }
+            MsgPackValue::Str(..) | MsgPackValue::Int(..) | MsgPackValue::BigInt(..) => {
+                create_decimal(&self.into_pyobject(py)?, self).map(ValidationMatch::strict)
+            }
+            _ => Err(ValError::new(ErrorTypeDefaults::DecimalType, self)),
+        }
+    }
+
+    type Dict<'a> = &'a MsgPackMap;
+
+    fn validate_dict(&self, _strict: bool) -> ValResult<Self::Dict<'_>> {
+        input_binary::validate_dict(self)
+    }
+    #[cfg_attr(has_coverage_attribute, coverage(off))]
+    fn strict_dict(&self) -> ValResult<Self::Dict<'_>> {
+        self.validate_dict(false)
+    }
+
+    type List<'a> = &'a [MsgPackValue];
+
+    fn validate_list(&self, _strict: bool) -> ValMatch<&[MsgPackValue]> {
+        input_binary::validate_list(self)
+    }
+
+    type Tuple<'a> = &'a [MsgPackValue];
+
+    fn validate_tuple(&self, _strict: bool) -> ValMatch<&[MsgPackValue]> {
+        input_binary::validate_array(self, ErrorTypeDefaults::TupleType)
+    }
+
+    type Set<'a> = &'a [MsgPackValue];
+
+    fn validate_set(&self, _strict: bool) -> ValMatch<&[MsgPackValue]> {
+        input_binary::validate_array(self, ErrorTypeDefaults::SetType)
+    }
+
+    fn validate_frozenset(&self, _strict: bool) -> ValMatch<&[MsgPackValue]> {
+        input_binary::validate_array(self, ErrorTypeDefaults::FrozenSetType)
+    }
+
+    fn validate_iter(&self) -> ValResult<GenericIterator<'static>> {
+        input_binary::validate_iter(self)
+    }
+
+    fn validate_date(&self, _strict: bool, mode: TemporalUnitMode) -> ValResult<ValidationMatch<EitherDate<'py>>> {
+        match self {
+            MsgPackValue::Str(v) => bytes_as_date(self, v.as_bytes(), mode).map(ValidationMatch::strict),
+            _ => Err(ValError::new(ErrorTypeDefaults::DateType, self)),
+        }
+    }
+
+    fn validate_time(
+        &self,
+        strict: bool,
+        microseconds_overflow_behavior: MicrosecondsPrecisionOverflowBehavior,
+    ) -> ValResult<ValidationMatch<EitherTime<'py>>> {
+        match self {
+            MsgPackValue::Str(v) => {
+                bytes_as_time(self, v.as_bytes(), microseconds_overflow_behavior).map(ValidationMatch::strict)
+            }
+            MsgPackValue::Int(v) if !strict => int_as_time(self, *v, 0).map(ValidationMatch::lax),
+            MsgPackValue::Float(v) if !strict => float_as_time(self, *v).map(ValidationMatch::lax),
+            MsgPackValue::BigInt(_) if !strict => Err(ValError::new(
+                ErrorType::TimeParsing {
+                    error: Cow::Borrowed(
+                        speedate::ParseError::TimeTooLarge
+                            .get_documentation()
+                            .unwrap_or_default(),
+                    ),
+                    context: None,
+                },
+                self,
+            )),
+            _ => Err(ValError::new(ErrorTypeDefaults::TimeType, self)),
+        }
+    }
+
+    fn validate_datetime(
+        &self,
+        strict: bool,
+        microseconds_overflow_behavior: MicrosecondsPrecisionOverflowBehavior,
+        mode: TemporalUnitMode,
+    ) -> ValResult<ValidationMatch<EitherDateTime<'py>>> {
+        match self {
+            MsgPackValue::Timestamp { seconds, nanoseconds } => self
+                .timestamp_as_datetime(*seconds, *nanoseconds)
+                .map(ValidationMatch::exact),
+            MsgPackValue::Str(v) => {
+                bytes_as_datetime(self, v.as_bytes(), microseconds_overflow_behavior, mode).map(ValidationMatch::strict)
+            }
+            MsgPackValue::Int(v) if !strict => int_as_datetime(self, *v, 0, mode).map(ValidationMatch::lax),
+            MsgPackValue::Float(v) if !strict => float_as_datetime(self, *v, mode).map(ValidationMatch::lax),
+            _ => Err(ValError::new(ErrorTypeDefaults::DatetimeType, self)),
+        }
+    }
+
+    fn validate_timedelta(
+        &self,
+        strict: bool,
+        microseconds_overflow_behavior: MicrosecondsPrecisionOverflowBehavior,
+    ) -> ValResult<ValidationMatch<EitherTimedelta<'py>>> {
+        match self {
+            MsgPackValue::Str(v) => {
+                bytes_as_timedelta(self, v.as_bytes(), microseconds_overflow_behavior).map(ValidationMatch::strict)
+            }
+            MsgPackValue::Int(v) if !strict => {
+                int_as_duration(self, *v).map(|duration| ValidationMatch::lax(duration.into()))
+            }
+            MsgPackValue::Float(v) if !strict => {
+                float_as_duration(self, *v).map(|duration| ValidationMatch::lax(duration.into()))
+            }
+            _ => Err(ValError::new(ErrorTypeDefaults::TimeDeltaType, self)),
+        }
+    }
+
+    fn validate_complex(&self, strict: bool, py: Python<'py>) -> ValResult<ValidationMatch<EitherComplex<'py>>> {
+        match self {
+            MsgPackValue::Str(s) => Ok(ValidationMatch::strict(EitherComplex::Py(string_to_complex(
+                &PyString::new(py, s),
+                self,
+            )?))),
+            MsgPackValue::Float(f) if !strict => Ok(ValidationMatch::lax(EitherComplex::Complex([*f, 0.0]))),
+            MsgPackValue::Int(i) if !strict => Ok(ValidationMatch::lax(EitherComplex::Complex([(*i) as f64, 0.0]))),
+            MsgPackValue::Float(_) | MsgPackValue::Int(_) => {
+                Err(ValError::new(ErrorTypeDefaults::ComplexStrParsing, self))
+            }
+            _ => Err(ValError::new(ErrorTypeDefaults::ComplexType, self)),
+        }
+    }
+}
+
+impl BorrowInput<'_> for MsgPackValue {
+    type Input = MsgPackValue;
+    fn borrow_input(&self) -> &Self::Input {
+        self
+    }
+}
@@ -1,15 +1,19 @@
+use std::borrow::Cow;
+
+use ahash::AHashMap;
+use jiter::{Jiter, JiterResult, Peek};
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyString};
+use pyo3::types::{PyDict, PyList, PyString};
 
 use speedate::MicrosecondsPrecisionOverflowBehavior;
 
-use crate::errors::{ErrorTypeDefaults, InputValue, LocItem, ValError, ValResult};
+use crate::errors::{ErrorType, ErrorTypeDefaults, InputValue, LocItem, Location, ValError, ValLineError, ValResult};
 use crate::input::py_string_str;
 use crate::lookup_key::{LookupKey, LookupPath};
 use crate::tools::safe_repr;
 use crate::validators::complex::string_to_complex;
 use crate::validators::decimal::create_decimal;
-use crate::validators::{TemporalUnitMode, ValBytesMode};
+use crate::validators::{StrCollectionParsing, TemporalUnitMode, ValBytesMode};
 
 use super::datetime::{
     bytes_as_date, bytes_as_datetime, bytes_as_time, bytes_as_timedelta, EitherDate, EitherDateTime, EitherTime,
@@ -18,14 +22,19 @@ use super::input_abstract::{Never, ValMatch};
 use super::return_enums::EitherComplex;
 use super::shared::{str_as_bool, str_as_float, str_as_int};
 use super::{
-    Arguments, BorrowInput, EitherBytes, EitherFloat, EitherInt, EitherString, EitherTimedelta, GenericIterator, Input,
-    KeywordArgs, ValidatedDict, ValidationMatch,
+    Arguments, BorrowInput, ConsumeIterator, EitherBytes, EitherFloat, EitherInt, EitherString, EitherTimedelta,
+    GenericIterator, Input, KeywordArgs, ValidatedDict, ValidatedList, ValidatedSet, ValidationMatch,
 };
 
 #[derive(Debug, Clone, IntoPyObject, IntoPyObjectRef)]
 pub enum StringMapping<'py> {
     String(Bound<'py, PyString>),
     Mapping(Bound<'py, PyDict>),
+    /// a mapping built by `new_flat_mapping` with `case_sensitive=False`, its keys are lowercase and
+    /// lookups are lowercased to match
+    FoldedMapping(Bound<'py, PyDict>),
+    /// a list of strings, only created by parsing a string with `str_collection_parsing`
+    Sequence(Bound<'py, PyList>),
 }
 
 impl<'py> StringMapping<'py> {
@@ -39,11 +48,12 @@ impl<'py> StringMapping<'py> {
         }
     }
 
-    pub fn new_value(py_value: Bound<'py, PyAny>) -> ValResult<Self> {
+    pub fn new_value(py_value: Bound<'py, PyAny>, case_sensitive: bool) -> ValResult<Self> {
         match py_value.downcast_into::<PyString>() {
             Ok(py_str) => Ok(Self::String(py_str)),
             Err(downcast_error) => match downcast_error.into_inner().downcast_into::<PyDict>() {
-                Ok(value) => Ok(Self::Mapping(value)),
+                Ok(value) if case_sensitive => Ok(Self::Mapping(value)),
+                Ok(value) => Ok(Self::FoldedMapping(value)),
                 Err(downcast_error) => Err(ValError::new(
                     ErrorTypeDefaults::StringType,
                     downcast_error.into_inner(),
@@ -53,11 +63,163 @@ impl<'py> StringMapping<'py> {
     }
 }
 
+/// Records which key of a flat mapping each value in an unflattened `StringMapping` came from, so error
+/// locations can be reported in terms of the keys the user passed in
+#[derive(Debug)]
+pub struct FlatKeys {
+    delimiter: Option<String>,
+    keys: AHashMap<Vec<String>, String>,
+}
+
+impl<'py> StringMapping<'py> {
+    /// Build a nested `StringMapping` from a flat dict of strings like `{"DB__PORT": "5432"}`, splitting keys
+    /// on `delimiter` and lowercasing them unless `case_sensitive` is set, in which case the result is a
+    /// `FoldedMapping` so field lookups are lowercased too
+    pub fn new_flat_mapping(
+        py_value: Bound<'py, PyAny>,
+        delimiter: Option<&str>,
+        case_sensitive: bool,
+    ) -> ValResult<(Self, FlatKeys)> {
+        let py = py_value.py();
+        let flat = py_value
+            .downcast_into::<PyDict>()
+            .map_err(|e| ValError::new(ErrorTypeDefaults::DictType, e.into_inner()))?;
+        let root = PyDict::new(py);
+        let mut keys: AHashMap<Vec<String>, String> = AHashMap::with_capacity(flat.len());
+        let mut errors: Vec<ValLineError> = Vec::new();
+
+        for (py_key, value) in flat.iter() {
+            let Ok(py_key) = py_key.downcast::<PyString>() else {
+                errors.push(ValLineError::new(ErrorTypeDefaults::StringType, &py_key));
+                continue;
+            };
+            let key = py_string_str(py_key)?;
+            if !value.is_instance_of::<PyString>() {
+                errors.push(ValLineError::new_with_loc(ErrorTypeDefaults::StringType, &value, key));
+                continue;
+            }
+            let normalized = if case_sensitive {
+                Cow::Borrowed(key)
+            } else {
+                Cow::Owned(key.to_lowercase())
+            };
+            let path: Vec<String> = match delimiter {
+                Some(delimiter) => normalized.split(delimiter).map(ToString::to_string).collect(),
+                None => vec![normalized.into_owned()],
+            };
+            if let Some(other_key) = keys.get(&path) {
+                // only possible when lowercasing, as splitting on the delimiter can be reversed
+                errors.push(ValLineError::new_with_loc(
+                    ErrorType::AmbiguousKey {
+                        other_key: other_key.clone(),
+                        context: None,
+                    },
+                    &value,
+                    key,
+                ));
+                continue;
+            }
+
+            let (last, parents) = path.split_last().expect("split always returns at least one item");
+            let mut node = root.clone();
+            for (depth, part) in parents.iter().enumerate() {
+                node = match node.get_item(part)? {
+                    Some(child) => match child.downcast_into::<PyDict>() {
+                        Ok(child) => child,
+                        // a key is also a prefix of this key, so its value can't be a string
+                        Err(e) => {
+                            let scalar_key = keys.remove(&path[..=depth]).unwrap_or_default();
+                            errors.push(ValLineError::new_with_loc(
+                                ErrorTypeDefaults::DictType,
+                                e.into_inner(),
+                                scalar_key,
+                            ));
+                            let child = PyDict::new(py);
+                            node.set_item(part, &child)?;
+                            child
+                        }
+                    },
+                    None => {
+                        let child = PyDict::new(py);
+                        node.set_item(part, &child)?;
+                        child
+                    }
+                };
+            }
+            if node.get_item(last)?.is_some_and(|v| v.is_instance_of::<PyDict>()) {
+                errors.push(ValLineError::new_with_loc(ErrorTypeDefaults::DictType, &value, key));
+                continue;
+            }
+            node.set_item(last, value)?;
+            keys.insert(path, key.to_string());
+        }
+
+        if errors.is_empty() {
+            let flat_keys = FlatKeys {
+                delimiter: delimiter.map(ToString::to_string),
+                keys,
+            };
+            let mapping = if case_sensitive {
+                Self::Mapping(root)
+            } else {
+                Self::FoldedMapping(root)
+            };
+            Ok((mapping, flat_keys))
+        } else {
+            Err(ValError::LineErrors(errors))
+        }
+    }
+}
+
+impl FlatKeys {
+    /// Replace the start of each error location with the flat key the value came from, locations which
+    /// don't match a key, e.g. for missing fields, are joined with the delimiter instead
+    pub fn restore_locations(&self, error: ValError) -> ValError {
+        match error {
+            ValError::LineErrors(mut line_errors) => {
+                for line_error in &mut line_errors {
+                    self.restore_location(&mut line_error.location);
+                }
+                ValError::LineErrors(line_errors)
+            }
+            other => other,
+        }
+    }
+
+    fn restore_location(&self, location: &mut Location) {
+        // location items are stored in reverse order
+        let Location::List(items) = location else {
+            return;
+        };
+        let path: Vec<String> = items
+            .iter()
+            .rev()
+            .map_while(|item| match item {
+                LocItem::S(s) => Some(s.clone()),
+                LocItem::I(_) => None,
+            })
+            .collect();
+        if path.is_empty() {
+            return;
+        }
+        let (depth, key) = (1..=path.len())
+            .rev()
+            .find_map(|depth| self.keys.get(&path[..depth]).map(|key| (depth, key.clone())))
+            .unwrap_or_else(|| match &self.delimiter {
+                Some(delimiter) => (path.len(), path.join(delimiter)),
+                None => (1, path[0].clone()),
+            });
+        items.truncate(items.len() - depth);
+        items.push(LocItem::S(key));
+    }
+}
+
 impl From<StringMapping<'_>> for LocItem {
     fn from(string_mapping: StringMapping<'_>) -> Self {
         match string_mapping {
             StringMapping::String(s) => s.to_string_lossy().as_ref().into(),
-            StringMapping::Mapping(d) => safe_repr(&d).to_string().into(),
+            StringMapping::Mapping(d) | StringMapping::FoldedMapping(d) => safe_repr(&d).to_string().into(),
+            StringMapping::Sequence(l) => safe_repr(&l).to_string().into(),
         }
     }
 }
@@ -71,7 +233,8 @@ impl<'py> Input<'py> for StringMapping<'py> {
     fn as_error_value(&self) -> InputValue {
         match self {
             Self::String(s) => s.as_error_value(),
-            Self::Mapping(d) => d.as_error_value(),
+            Self::Mapping(d) | Self::FoldedMapping(d) => d.as_error_value(),
+            Self::Sequence(l) => l.as_error_value(),
         }
     }
 
@@ -96,8 +259,11 @@ impl<'py> Input<'py> for StringMapping<'py> {
 
     fn validate_dataclass_args<'a>(&'a self, _dataclass_name: &str) -> ValResult<StringMappingDict<'py>> {
         match self {
-            StringMapping::String(_) => Err(ValError::new(ErrorTypeDefaults::ArgumentsType, self)),
-            StringMapping::Mapping(m) => Ok(StringMappingDict(m.clone())),
+            StringMapping::String(_) | StringMapping::Sequence(_) => {
+                Err(ValError::new(ErrorTypeDefaults::ArgumentsType, self))
+            }
+            StringMapping::Mapping(m) => Ok(StringMappingDict::new(m.clone(), true)),
+            StringMapping::FoldedMapping(m) => Ok(StringMappingDict::new(m.clone(), false)),
         }
     }
 
@@ -108,7 +274,9 @@ impl<'py> Input<'py> for StringMapping<'py> {
     ) -> ValResult<ValidationMatch<EitherString<'_, 'py>>> {
         match self {
             Self::String(s) => Ok(ValidationMatch::strict(s.clone().into())),
-            Self::Mapping(_) => Err(ValError::new(ErrorTypeDefaults::StringType, self)),
+            Self::Mapping(_) | Self::FoldedMapping(_) | Self::Sequence(_) => {
+                Err(ValError::new(ErrorTypeDefaults::StringType, self))
+            }
         }
     }
 
@@ -122,35 +290,45 @@ impl<'py> Input<'py> for StringMapping<'py> {
                 Ok(b) => Ok(ValidationMatch::strict(b)),
                 Err(e) => Err(ValError::new(e, self)),
             }),
-            Self::Mapping(_) => Err(ValError::new(ErrorTypeDefaults::BytesType, self)),
+            Self::Mapping(_) | Self::FoldedMapping(_) | Self::Sequence(_) => {
+                Err(ValError::new(ErrorTypeDefaults::BytesType, self))
+            }
         }
     }
 
     fn validate_bool(&self, _strict: bool) -> ValResult<ValidationMatch<bool>> {
         match self {
             Self::String(s) => str_as_bool(self, py_string_str(s)?).map(ValidationMatch::strict),
-            Self::Mapping(_) => Err(ValError::new(ErrorTypeDefaults::BoolType, self)),
+            Self::Mapping(_) | Self::FoldedMapping(_) | Self::Sequence(_) => {
+                Err(ValError::new(ErrorTypeDefaults::BoolType, self))
+            }
         }
     }
 
     fn validate_int(&self, _strict: bool) -> ValResult<ValidationMatch<EitherInt<'_>>> {
         match self {
             Self::String(s) => str_as_int(self, py_string_str(s)?).map(ValidationMatch::strict),
-            Self::Mapping(_) => Err(ValError::new(ErrorTypeDefaults::IntType, self)),
+            Self::Mapping(_) | Self::FoldedMapping(_) | Self::Sequence(_) => {
+                Err(ValError::new(ErrorTypeDefaults::IntType, self))
+            }
         }
     }
 
     fn validate_float(&self, _strict: bool) -> ValResult<ValidationMatch<EitherFloat<'_>>> {
         match self {
             Self::String(s) => str_as_float(self, py_string_str(s)?).map(ValidationMatch::strict),
-            Self::Mapping(_) => Err(ValError::new(ErrorTypeDefaults::FloatType, self)),
+            Self::Mapping(_) | Self::FoldedMapping(_) | Self::Sequence(_) => {
+                Err(ValError::new(ErrorTypeDefaults::FloatType, self))
+            }
         }
     }
 
     fn validate_decimal(&self, _strict: bool, _py: Python<'py>) -> ValMatch<Bound<'py, PyAny>> {
         match self {
             Self::String(s) => create_decimal(s, self).map(ValidationMatch::strict),
-            Self::Mapping(_) => Err(ValError::new(ErrorTypeDefaults::DecimalType, self)),
+            Self::Mapping(_) | Self::FoldedMapping(_) | Self::Sequence(_) => {
+                Err(ValError::new(ErrorTypeDefaults::DecimalType, self))
+            }
         }
     }
 
@@ -161,18 +339,22 @@ impl<'py> Input<'py> for StringMapping<'py> {
 
     fn strict_dict(&self) -> ValResult<StringMappingDict<'py>> {
         match self {
-            Self::String(_) => Err(ValError::new(ErrorTypeDefaults::DictType, self)),
-            Self::Mapping(d) => Ok(StringMappingDict(d.clone())),
+            Self::String(_) | Self::Sequence(_) => Err(ValError::new(ErrorTypeDefaults::DictType, self)),
+            Self::Mapping(d) => Ok(StringMappingDict::new(d.clone(), true)),
+            Self::FoldedMapping(d) => Ok(StringMappingDict::new(d.clone(), false)),
         }
     }
 
     type List<'a>
-        = Never
+        = StringMappingSequence<'py>
     where
         Self: 'a;
 
-    fn validate_list(&self, _strict: bool) -> ValMatch<Never> {
-        Err(ValError::new(ErrorTypeDefaults::ListType, self))
+    fn validate_list(&self, _strict: bool) -> ValMatch<StringMappingSequence<'py>> {
+        match self {
+            Self::Sequence(l) => Ok(ValidationMatch::strict(StringMappingSequence(l.clone()))),
+            _ => Err(ValError::new(ErrorTypeDefaults::ListType, self)),
+        }
     }
 
     type Tuple<'a>
@@ -185,16 +367,67 @@ impl<'py> Input<'py> for StringMapping<'py> {
     }
 
     type Set<'a>
-        = Never
+        = StringMappingSequence<'py>
     where
         Self: 'a;
 
-    fn validate_set(&self, _strict: bool) -> ValMatch<Never> {
-        Err(ValError::new(ErrorTypeDefaults::SetType, self))
+    fn validate_set(&self, _strict: bool) -> ValMatch<StringMappingSequence<'py>> {
+        match self {
+            Self::Sequence(l) => Ok(ValidationMatch::strict(StringMappingSequence(l.clone()))),
+            _ => Err(ValError::new(ErrorTypeDefaults::SetType, self)),
+        }
     }
 
-    fn validate_frozenset(&self, _strict: bool) -> ValMatch<Never> {
-        Err(ValError::new(ErrorTypeDefaults::FrozenSetType, self))
+    fn validate_frozenset(&self, _strict: bool) -> ValMatch<StringMappingSequence<'py>> {
+        match self {
+            Self::Sequence(l) => Ok(ValidationMatch::strict(StringMappingSequence(l.clone()))),
+            _ => Err(ValError::new(ErrorTypeDefaults::FrozenSetType, self)),
+        }
+    }
+
+    fn parse_str_sequence(&self, parsing: StrCollectionParsing) -> ValResult<Option<StringMapping<'py>>> {
+        let Self::String(py_str) = self else {
+            return Ok(None);
+        };
+        let s = py_string_str(py_str)?;
+        let items = match parsing {
+            StrCollectionParsing::Off => return Ok(None),
+            StrCollectionParsing::Json => match json_collection_items(self, s, Peek::Array)? {
+                Some(items) => items,
+                None => return Ok(None),
+            },
+            StrCollectionParsing::Csv => csv_items(s).map(ToString::to_string).collect(),
+        };
+        Ok(Some(Self::Sequence(PyList::new(py_str.py(), items)?)))
+    }
+
+    fn parse_str_mapping(&self, parsing: StrCollectionParsing) -> ValResult<Option<StringMapping<'py>>> {
+        let Self::String(py_str) = self else {
+            return Ok(None);
+        };
+        let s = py_string_str(py_str)?;
+        let dict = PyDict::new(py_str.py());
+        match parsing {
+            StrCollectionParsing::Off => return Ok(None),
+            StrCollectionParsing::Json => {
+                let Some(items) = json_collection_items(self, s, Peek::Object)? else {
+                    return Ok(None);
+                };
+                let mut items = items.into_iter();
+                while let (Some(key), Some(value)) = (items.next(), items.next()) {
+                    dict.set_item(key, value)?;
+                }
+            }
+            StrCollectionParsing::Csv => {
+                for item in csv_items(s) {
+                    let Some((key, value)) = item.split_once('=') else {
+                        return Err(ValError::new(ErrorTypeDefaults::DictType, self));
+                    };
+                    dict.set_item(key.trim_end(), value.trim_start())?;
+                }
+            }
+        }
+        Ok(Some(Self::Mapping(dict)))
     }
 
     fn validate_iter(&self) -> ValResult<GenericIterator<'static>> {
@@ -204,7 +437,9 @@ impl<'py> Input<'py> for StringMapping<'py> {
     fn validate_date(&self, _strict: bool, mode: TemporalUnitMode) -> ValResult<ValidationMatch<EitherDate<'py>>> {
         match self {
             Self::String(s) => bytes_as_date(self, py_string_str(s)?.as_bytes(), mode).map(ValidationMatch::strict),
-            Self::Mapping(_) => Err(ValError::new(ErrorTypeDefaults::DateType, self)),
+            Self::Mapping(_) | Self::FoldedMapping(_) | Self::Sequence(_) => {
+                Err(ValError::new(ErrorTypeDefaults::DateType, self))
+            }
         }
     }
 
@@ -216,7 +451,9 @@ impl<'py> Input<'py> for StringMapping<'py> {
         match self {
             Self::String(s) => bytes_as_time(self, py_string_str(s)?.as_bytes(), microseconds_overflow_behavior)
                 .map(ValidationMatch::strict),
-            Self::Mapping(_) => Err(ValError::new(ErrorTypeDefaults::TimeType, self)),
+            Self::Mapping(_) | Self::FoldedMapping(_) | Self::Sequence(_) => {
+                Err(ValError::new(ErrorTypeDefaults::TimeType, self))
+            }
         }
     }
 
@@ -231,7 +468,9 @@ impl<'py> Input<'py> for StringMapping<'py> {
                 bytes_as_datetime(self, py_string_str(s)?.as_bytes(), microseconds_overflow_behavior, mode)
                     .map(ValidationMatch::strict)
             }
-            Self::Mapping(_) => Err(ValError::new(ErrorTypeDefaults::DatetimeType, self)),
+            Self::Mapping(_) | Self::FoldedMapping(_) | Self::Sequence(_) => {
+                Err(ValError::new(ErrorTypeDefaults::DatetimeType, self))
+            }
         }
     }
 
@@ -243,16 +482,81 @@ impl<'py> Input<'py> for StringMapping<'py> {
         match self {
             Self::String(s) => bytes_as_timedelta(self, py_string_str(s)?.as_bytes(), microseconds_overflow_behavior)
                 .map(ValidationMatch::strict),
-            Self::Mapping(_) => Err(ValError::new(ErrorTypeDefaults::TimeDeltaType, self)),
+            Self::Mapping(_) | Self::FoldedMapping(_) | Self::Sequence(_) => {
+                Err(ValError::new(ErrorTypeDefaults::TimeDeltaType, self))
+            }
         }
     }
 
     fn validate_complex(&self, _strict: bool, _py: Python<'py>) -> ValResult<ValidationMatch<EitherComplex<'py>>> {
         match self {
             Self::String(s) => Ok(ValidationMatch::strict(EitherComplex::Py(string_to_complex(s, self)?))),
-            Self::Mapping(_) => Err(ValError::new(ErrorTypeDefaults::ComplexType, self)),
+            Self::Mapping(_) | Self::FoldedMapping(_) | Self::Sequence(_) => {
+                Err(ValError::new(ErrorTypeDefaults::ComplexType, self))
+            }
+        }
+    }
+}
+
+/// Split a comma separated string into its items, surrounding whitespace is removed and an empty string
+/// has no items
+fn csv_items(s: &str) -> impl Iterator<Item = &str> {
+    let s = s.trim();
+    s.split(',').map(str::trim).filter(move |_| !s.is_empty())
+}
+
+/// Get the items of a JSON array, or the keys and values of a JSON object alternately. Strings are unquoted
+/// while other values are kept as JSON, so nested collections can be parsed in turn.
+/// `None` means `s` is valid JSON but not of the `expected` type.
+fn json_collection_items(input: &StringMapping<'_>, s: &str, expected: Peek) -> ValResult<Option<Vec<String>>> {
+    let mut jiter = Jiter::new(s.as_bytes());
+    let mut items = Vec::new();
+    match read_json_collection(&mut jiter, s, expected, &mut items) {
+        Ok(true) => Ok(Some(items)),
+        Ok(false) => Ok(None),
+        Err(e) => Err(ValError::new(
+            ErrorType::JsonInvalid {
+                error: e.description(&jiter),
+                context: None,
+            },
+            input,
+        )),
+    }
+}
+
+fn read_json_collection(jiter: &mut Jiter, s: &str, expected: Peek, items: &mut Vec<String>) -> JiterResult<bool> {
+    if jiter.peek()? != expected {
+        jiter.next_skip()?;
+        jiter.finish()?;
+        return Ok(false);
+    }
+    if expected == Peek::Array {
+        let mut next = jiter.known_array()?;
+        while let Some(peek) = next {
+            items.push(json_item(jiter, s, peek)?);
+            next = jiter.array_step()?;
+        }
+    } else {
+        let mut next = jiter.known_object()?.map(ToString::to_string);
+        while let Some(key) = next {
+            items.push(key);
+            let peek = jiter.peek()?;
+            items.push(json_item(jiter, s, peek)?);
+            next = jiter.next_key()?.map(ToString::to_string);
         }
     }
+    jiter.finish()?;
+    Ok(true)
+}
+
+fn json_item(jiter: &mut Jiter, s: &str, peek: Peek) -> JiterResult<String> {
+    if peek == Peek::String {
+        Ok(jiter.known_str()?.to_string())
+    } else {
+        let start = jiter.current_index();
+        jiter.known_skip(peek)?;
+        Ok(s[start..jiter.current_index()].to_string())
+    }
 }
 
 impl<'py> BorrowInput<'py> for StringMapping<'py> {
@@ -262,7 +566,58 @@ impl<'py> BorrowInput<'py> for StringMapping<'py> {
     }
 }
 
-pub struct StringMappingDict<'py>(Bound<'py, PyDict>);
+pub struct StringMappingSequence<'py>(Bound<'py, PyList>);
+
+impl<'py> StringMappingSequence<'py> {
+    fn items(&self) -> impl Iterator<Item = PyResult<StringMapping<'py>>> + '_ {
+        self.0
+            .iter()
+            .map(|item| Ok(StringMapping::String(item.downcast_into::<PyString>()?)))
+    }
+}
+
+impl<'py> ValidatedList<'py> for StringMappingSequence<'py> {
+    type Item = StringMapping<'py>;
+
+    fn len(&self) -> Option<usize> {
+        Some(self.0.len())
+    }
+    fn as_py_list(&self) -> Option<&Bound<'py, PyList>> {
+        Some(&self.0)
+    }
+    fn iterate<R>(self, consumer: impl ConsumeIterator<PyResult<Self::Item>, Output = R>) -> ValResult<R> {
+        Ok(consumer.consume_iterator(self.items()))
+    }
+}
+
+impl<'py> ValidatedSet<'py> for StringMappingSequence<'py> {
+    type Item = StringMapping<'py>;
+
+    fn iterate<R>(self, consumer: impl ConsumeIterator<PyResult<Self::Item>, Output = R>) -> ValResult<R> {
+        Ok(consumer.consume_iterator(self.items()))
+    }
+}
+
+pub struct StringMappingDict<'py> {
+    dict: Bound<'py, PyDict>,
+    /// `false` for a `StringMapping::FoldedMapping`, whose keys are all lowercase
+    case_sensitive: bool,
+}
+
+impl<'py> StringMappingDict<'py> {
+    fn new(dict: Bound<'py, PyDict>, case_sensitive: bool) -> Self {
+        Self { dict, case_sensitive }
+    }
+
+    fn items(&self) -> impl Iterator<Item = ValResult<(StringMapping<'py>, StringMapping<'py>)>> + '_ {
+        self.dict.iter().map(|(key, val)| {
+            Ok((
+                StringMapping::new_key(key)?,
+                StringMapping::new_value(val, self.case_sensitive)?,
+            ))
+        })
+    }
+}
 
 impl<'py> Arguments<'py> for StringMappingDict<'py> {
     type Args = Never;
@@ -289,17 +644,15 @@ impl<'py> KeywordArgs<'py> for StringMappingDict<'py> {
         Self: 'a;
 
     fn len(&self) -> usize {
-        self.0.len()
+        self.dict.len()
     }
 
     fn get_item<'k>(&self, key: &'k LookupKey) -> ValResult<Option<(&'k LookupPath, Self::Item<'_>)>> {
-        key.py_get_string_mapping_item(&self.0)
+        key.py_get_string_mapping_item(&self.dict, self.case_sensitive)
     }
 
     fn iter(&self) -> impl Iterator<Item = ValResult<(Self::Key<'_>, Self::Item<'_>)>> {
-        self.0
-            .iter()
-            .map(|(key, val)| Ok((StringMapping::new_key(key)?, StringMapping::new_value(val)?)))
+        self.items()
     }
 }
 
@@ -314,21 +667,17 @@ impl<'py> ValidatedDict<'py> for StringMappingDict<'py> {
     where
         Self: 'a;
     fn get_item<'k>(&self, key: &'k LookupKey) -> ValResult<Option<(&'k LookupPath, Self::Item<'_>)>> {
-        key.py_get_string_mapping_item(&self.0)
+        key.py_get_string_mapping_item(&self.dict, self.case_sensitive)
     }
     fn iterate<'a, R>(
         &'a self,
         consumer: impl super::ConsumeIterator<ValResult<(Self::Key<'a>, Self::Item<'a>)>, Output = R>,
     ) -> ValResult<R> {
-        Ok(consumer.consume_iterator(
-            self.0
-                .iter()
-                .map(|(key, val)| Ok((StringMapping::new_key(key)?, StringMapping::new_value(val)?))),
-        ))
+        Ok(consumer.consume_iterator(self.items()))
     }
 
     fn last_key(&self) -> Option<Self::Key<'_>> {
-        self.0
+        self.dict
             .keys()
             .iter()
             .last()
@@ -0,0 +1,365 @@
+use jiter::{JsonErrorType, JsonValue, LinePosition, PartialMode};
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyByteArray, PyBytes, PyIterator, PyString};
+
+use crate::errors::{ErrorType, InputValue, ValError, ValResult};
+use crate::py_gc::PyGcTraverse;
+
+/// Number of bytes requested from a reader's `read()` method at a time, unless set by the caller.
+pub(crate) const DEFAULT_CHUNK_SIZE: usize = 65_536;
+
+enum ChunkSource {
+    /// a file-like object, read with `.read(chunk_size)`
+    Reader(Py<PyAny>),
+    /// an iterator of `bytes`, `bytearray` or `str` chunks
+    Iterator(Py<PyIterator>),
+    /// `bytes`, `bytearray` or `str` which was already complete, all data is in the buffer from the start
+    Complete,
+}
+
+/// JSON data read incrementally from a Python file-like object or an iterator of chunks, only data which
+/// hasn't been consumed yet is kept in memory.
+pub(crate) struct JsonStream {
+    source: ChunkSource,
+    // the object data is read from, used as the input value in errors
+    input: Py<PyAny>,
+    chunk_size: usize,
+    buffer: Vec<u8>,
+    // index in `buffer` of the first byte which hasn't been consumed
+    position: usize,
+    // offset in the document of `buffer[0]`, and the line it's on and the offset where that line starts,
+    // used to report the position of syntax errors relative to the whole document
+    buffer_offset: usize,
+    buffer_line: usize,
+    buffer_line_start: usize,
+    eof: bool,
+}
+
+impl JsonStream {
+    pub fn new(source: &Bound<'_, PyAny>, chunk_size: usize) -> PyResult<Self> {
+        let py = source.py();
+        if chunk_size == 0 {
+            return Err(PyValueError::new_err("`chunk_size` must be greater than zero"));
+        }
+        let mut buffer = Vec::new();
+        let chunk_source = if extend_from_chunk(&mut buffer, source).is_ok() {
+            ChunkSource::Complete
+        } else if source.hasattr(intern!(py, "read"))? {
+            ChunkSource::Reader(source.clone().unbind())
+        } else if let Ok(iterator) = source.try_iter() {
+            ChunkSource::Iterator(iterator.unbind())
+        } else {
+            return Err(PyTypeError::new_err(
+                "Expected bytes, bytearray, str, a file-like object with a `read()` method or an iterator of chunks",
+            ));
+        };
+        Ok(Self {
+            eof: matches!(chunk_source, ChunkSource::Complete),
+            source: chunk_source,
+            input: source.clone().unbind(),
+            chunk_size,
+            buffer,
+            position: 0,
+            buffer_offset: 0,
+            buffer_line: 1,
+            buffer_line_start: 0,
+        })
+    }
+
+    /// The object data is read from.
+    pub fn input<'py>(&self, py: Python<'py>) -> &Bound<'py, PyAny> {
+        self.input.bind(py)
+    }
+
+    /// Consume the opening `[` of a top-level array, returns `false` without consuming anything if the
+    /// document isn't an array.
+    pub fn start_array(&mut self, py: Python) -> PyResult<bool> {
+        if self.peek(py)? == Some(b'[') {
+            self.position += 1;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Read and parse the next item of the array started with `start_array`, returns `None` once the closing
+    /// `]` has been consumed.
+    pub fn next_array_item(&mut self, py: Python, first: bool) -> ValResult<Option<JsonValue<'static>>> {
+        match self.peek(py)? {
+            None => return Err(self.error(py, JsonErrorType::EofWhileParsingList, self.position)),
+            Some(b']') => {
+                self.position += 1;
+                return Ok(None);
+            }
+            Some(b',') if !first => {
+                self.position += 1;
+                match self.peek(py)? {
+                    None => return Err(self.error(py, JsonErrorType::EofWhileParsingValue, self.position)),
+                    Some(b']') => return Err(self.error(py, JsonErrorType::TrailingComma, self.position)),
+                    Some(_) => {}
+                }
+            }
+            Some(_) if !first => return Err(self.error(py, JsonErrorType::ExpectedListCommaOrEnd, self.position)),
+            Some(_) => {}
+        }
+
+        let length = self.scan_value(py)?;
+        let start = self.position;
+        let value = match JsonValue::parse_owned(&self.buffer[start..start + length], true, PartialMode::Off) {
+            Ok(value) => value,
+            // the value was cut short by a delimiter, e.g. `tru]`, include it to get the same error as parsing
+            // the whole document would
+            Err(e) if is_eof_error(&e.error_type) && start + length < self.buffer.len() => {
+                let e = JsonValue::parse_owned(&self.buffer[start..=start + length], true, PartialMode::Off)
+                    .err()
+                    .unwrap_or(e);
+                return Err(self.error(py, e.error_type, start + e.index));
+            }
+            Err(e) => return Err(self.error(py, e.error_type, start + e.index)),
+        };
+        self.position += length;
+        Ok(Some(value))
+    }
+
+    /// Check nothing but whitespace is left after the top-level value.
+    pub fn check_end(&mut self, py: Python) -> ValResult<()> {
+        match self.peek(py)? {
+            None => Ok(()),
+            Some(_) => Err(self.error(py, JsonErrorType::TrailingCharacters, self.position)),
+        }
+    }
+
+    /// Read all remaining data.
+    pub fn read_to_end(&mut self, py: Python) -> PyResult<Vec<u8>> {
+        while self.read_chunk(py)? {}
+        self.compact();
+        Ok(std::mem::take(&mut self.buffer))
+    }
+
+    /// Skip whitespace, returning the next byte without consuming it, or `None` at the end of the data.
+    fn peek(&mut self, py: Python) -> PyResult<Option<u8>> {
+        loop {
+            while let Some(&next) = self.buffer.get(self.position) {
+                if matches!(next, b' ' | b'\t' | b'\n' | b'\r') {
+                    self.position += 1;
+                } else {
+                    return Ok(Some(next));
+                }
+            }
+            if !self.read_chunk(py)? {
+                return Ok(None);
+            }
+        }
+    }
+
+    /// Find the length of the value starting at `position` by tracking strings and nesting, reading more data
+    /// as required; the value is only parsed once it's complete.
+    fn scan_value(&mut self, py: Python) -> PyResult<usize> {
+        let mut depth: usize = 0;
+        let mut in_string = false;
+        let mut escaped = false;
+        let mut length = 0;
+        loop {
+            while let Some(&next) = self.buffer.get(self.position + length) {
+                if in_string {
+                    if escaped {
+                        escaped = false;
+                    } else if next == b'\\' {
+                        escaped = true;
+                    } else if next == b'"' {
+                        in_string = false;
+                        if depth == 0 {
+                            return Ok(length + 1);
+                        }
+                    }
+                } else {
+                    match next {
+                        // a scalar ends where the next value would start, so the separator check reports it
+                        b'"' | b'[' | b'{' if depth == 0 && length > 0 => return Ok(length),
+                        b'"' => in_string = true,
+                        b'[' | b'{' => depth += 1,
+                        b']' | b'}' | b',' | b' ' | b'\t' | b'\n' | b'\r' if depth == 0 => return Ok(length),
+                        b']' | b'}' => {
+                            depth -= 1;
+                            if depth == 0 {
+                                return Ok(length + 1);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                length += 1;
+            }
+            if !self.read_chunk(py)? {
+                // incomplete value, parsing it will give the right error
+                return Ok(length);
+            }
+        }
+    }
+
+    /// Append the next non-empty chunk to the buffer, returns `false` at the end of the data.
+    fn read_chunk(&mut self, py: Python) -> PyResult<bool> {
+        while !self.eof {
+            let chunk = match &self.source {
+                ChunkSource::Reader(reader) => reader.bind(py).call_method1(intern!(py, "read"), (self.chunk_size,))?,
+                ChunkSource::Iterator(iterator) => match iterator.bind(py).clone().next() {
+                    Some(chunk) => chunk?,
+                    None => {
+                        self.eof = true;
+                        break;
+                    }
+                },
+                ChunkSource::Complete => {
+                    self.eof = true;
+                    break;
+                }
+            };
+            self.compact();
+            let buffer_length = self.buffer.len();
+            extend_from_chunk(&mut self.buffer, &chunk)?;
+            if self.buffer.len() > buffer_length {
+                return Ok(true);
+            } else if matches!(self.source, ChunkSource::Reader(_)) {
+                // an empty read means the reader is exhausted
+                self.eof = true;
+            }
+        }
+        Ok(false)
+    }
+
+    /// Drop consumed data from the buffer, keeping track of where the buffer starts in the document.
+    fn compact(&mut self) {
+        if self.position == 0 {
+            return;
+        }
+        let (line, line_start) = self.line_and_start(self.position);
+        self.buffer_line = line;
+        self.buffer_line_start = line_start;
+        self.buffer_offset += self.position;
+        self.buffer.drain(..self.position);
+        self.position = 0;
+    }
+
+    /// The line of the document containing `buffer[index]`, and the offset where that line starts.
+    fn line_and_start(&self, index: usize) -> (usize, usize) {
+        let mut line = self.buffer_line;
+        let mut line_start = self.buffer_line_start;
+        for (i, byte) in self.buffer[..index.min(self.buffer.len())].iter().enumerate() {
+            if *byte == b'\n' {
+                line += 1;
+                line_start = self.buffer_offset + i + 1;
+            }
+        }
+        (line, line_start)
+    }
+
+    fn error(&self, py: Python, error_type: JsonErrorType, index: usize) -> ValError {
+        // match `LinePosition::find` which counts the byte at the error index, if there is one
+        let end = (index + 1).min(self.buffer.len());
+        let (line, line_start) = self.line_and_start(end);
+        let column = (self.buffer_offset + end).saturating_sub(line_start);
+        ValError::new_custom_input(
+            ErrorType::JsonInvalid {
+                error: format!("{error_type} at {}", LinePosition::new(line, column)),
+                context: None,
+            },
+            InputValue::Python(self.input.clone_ref(py)),
+        )
+    }
+}
+
+impl PyGcTraverse for JsonStream {
+    fn py_gc_traverse(&self, visit: &pyo3::PyVisit<'_>) -> Result<(), pyo3::PyTraverseError> {
+        match &self.source {
+            ChunkSource::Reader(reader) => reader.py_gc_traverse(visit)?,
+            ChunkSource::Iterator(iterator) => iterator.py_gc_traverse(visit)?,
+            ChunkSource::Complete => (),
+        }
+        self.input.py_gc_traverse(visit)
+    }
+}
+
+fn is_eof_error(error_type: &JsonErrorType) -> bool {
+    matches!(
+        error_type,
+        JsonErrorType::EofWhileParsingValue
+            | JsonErrorType::EofWhileParsingString
+            | JsonErrorType::EofWhileParsingList
+            | JsonErrorType::EofWhileParsingObject
+    )
+}
+
+fn extend_from_chunk(buffer: &mut Vec<u8>, chunk: &Bound<'_, PyAny>) -> PyResult<()> {
+    if let Ok(py_bytes) = chunk.downcast::<PyBytes>() {
+        buffer.extend_from_slice(py_bytes.as_bytes());
+    } else if let Ok(py_str) = chunk.downcast::<PyString>() {
+        buffer.extend_from_slice(py_str.to_str()?.as_bytes());
+    } else if let Ok(py_byte_array) = chunk.downcast::<PyByteArray>() {
+        buffer.extend_from_slice(&py_byte_array.to_vec());
+    } else {
+        return Err(PyTypeError::new_err(format!(
+            "Expected chunks of bytes, bytearray or str, got {}",
+            chunk.get_type().qualname()?
+        )));
+    }
+    Ok(())
+}
+
+/// Iterates over the items of a top-level JSON array in a `JsonStream`, stopping at the first syntax error,
+/// which is kept to be returned by `finish`.
+pub(crate) struct JsonStreamItems<'s, 'py> {
+    py: Python<'py>,
+    stream: &'s mut JsonStream,
+    first: bool,
+    done: bool,
+    error: Option<ValError>,
+}
+
+impl<'s, 'py> JsonStreamItems<'s, 'py> {
+    pub fn new(py: Python<'py>, stream: &'s mut JsonStream) -> Self {
+        Self {
+            py,
+            stream,
+            first: true,
+            done: false,
+            error: None,
+        }
+    }
+
+    /// Consume any remaining items and check the rest of the document, invalid JSON anywhere takes precedence
+    /// over validation errors, as with `validate_json`.
+    pub fn finish(mut self) -> ValResult<()> {
+        for _ in &mut self {}
+        match self.error.take() {
+            Some(error) => Err(error),
+            None => self.stream.check_end(self.py),
+        }
+    }
+}
+
+impl Iterator for JsonStreamItems<'_, '_> {
+    type Item = PyResult<JsonValue<'static>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.stream.next_array_item(self.py, self.first) {
+            Ok(Some(value)) => {
+                self.first = false;
+                Some(Ok(value))
+            }
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(error) => {
+                self.done = true;
+                self.error = Some(error);
+                None
+            }
+        }
+    }
+}
@@ -4,9 +4,13 @@ use pyo3::prelude::*;
 
 mod datetime;
 mod input_abstract;
+mod input_binary;
+mod input_cbor;
 mod input_json;
+mod input_msgpack;
 mod input_python;
 mod input_string;
+mod json_stream;
 mod return_enums;
 mod shared;
 
@@ -19,11 +23,16 @@ pub(crate) use input_abstract::{
     Arguments, BorrowInput, ConsumeIterator, Input, InputType, KeywordArgs, PositionalArgs, ValidatedDict,
     ValidatedList, ValidatedSet, ValidatedTuple,
 };
+pub(crate) use input_binary::{BinaryMap, BinaryValue};
+pub(crate) use input_cbor::{parse_cbor, CborValue};
+pub(crate) use input_json::JsonPositions;
+pub(crate) use input_msgpack::{parse_msgpack, MsgPackValue};
 pub(crate) use input_python::{downcast_python_input, input_as_python_instance};
 pub(crate) use input_string::StringMapping;
+pub(crate) use json_stream::{JsonStream, JsonStreamItems, DEFAULT_CHUNK_SIZE};
 pub(crate) use return_enums::{
     no_validator_iter_to_vec, py_string_str, validate_iter_to_set, validate_iter_to_vec, EitherBytes, EitherFloat,
-    EitherInt, EitherString, GenericIterator, Int, MaxLengthCheck, ValidationMatch,
+    EitherInt, EitherString, GenericIterator, Int, MaxLengthCheck, ValidationMatch, BIGINT_TOKEN,
 };
 
 // Defined here as it's not exported by pyo3
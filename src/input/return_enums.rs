@@ -24,7 +24,7 @@ use crate::py_gc::PyGcTraverse;
 use crate::tools::{extract_i64, extract_int, new_py_string, py_err};
 use crate::validators::{CombinedValidator, Exactness, ValidationState, Validator};
 
-use super::{py_error_on_minusone, BorrowInput, Input};
+use super::{py_error_on_minusone, BinaryValue, BorrowInput, CborValue, Input, MsgPackValue};
 
 pub struct ValidationMatch<T>(T, Exactness);
 
@@ -130,6 +130,7 @@ pub(crate) fn validate_iter_to_vec<'py>(
     let mut output: Vec<Py<PyAny>> = Vec::with_capacity(capacity);
     let mut errors: Vec<ValLineError> = Vec::new();
     let allow_partial = state.allow_partial;
+    let errors_before = state.error_count;
 
     for (index, is_last_partial, item_result) in state.enumerate_last_partial(iter) {
         state.allow_partial = match is_last_partial {
@@ -154,6 +155,9 @@ pub(crate) fn validate_iter_to_vec<'py>(
             Err(ValError::Omit) => (),
             Err(err) => return Err(err),
         }
+        if state.reached_max_errors(errors_before, errors.len()) {
+            return Err(ValError::LineErrors(errors));
+        }
     }
 
     if errors.is_empty() {
@@ -227,6 +231,7 @@ pub(crate) fn validate_iter_to_set<'py>(
     let mut errors: Vec<ValLineError> = Vec::new();
 
     let allow_partial = state.allow_partial;
+    let errors_before = state.error_count;
 
     for (index, is_last_partial, item_result) in state.enumerate_last_partial(iter) {
         state.allow_partial = match is_last_partial {
@@ -261,7 +266,7 @@ pub(crate) fn validate_iter_to_set<'py>(
             Err(ValError::Omit) => (),
             Err(err) => return Err(err),
         }
-        if fail_fast && !errors.is_empty() {
+        if (fail_fast && !errors.is_empty()) || state.reached_max_errors(errors_before, errors.len()) {
             return Err(ValError::LineErrors(errors));
         }
     }
@@ -360,6 +365,8 @@ pub(crate) fn iterate_attributes<'a, 'py>(
 pub enum GenericIterator<'data> {
     PyIterator(GenericPyIterator),
     JsonArray(GenericJsonIterator<'data>),
+    MsgPackArray(GenericBinaryIterator<MsgPackValue>),
+    CborArray(GenericBinaryIterator<CborValue>),
 }
 
 impl PyGcTraverse for GenericIterator<'_> {
@@ -376,6 +383,8 @@ impl GenericIterator<'_> {
         match self {
             GenericIterator::PyIterator(iter) => GenericIterator::PyIterator(iter),
             GenericIterator::JsonArray(iter) => GenericIterator::JsonArray(iter.into_static()),
+            GenericIterator::MsgPackArray(iter) => GenericIterator::MsgPackArray(iter),
+            GenericIterator::CborArray(iter) => GenericIterator::CborArray(iter),
         }
     }
 }
@@ -387,6 +396,18 @@ impl<'data> From<JsonArray<'data>> for GenericIterator<'data> {
     }
 }
 
+impl From<Vec<MsgPackValue>> for GenericIterator<'_> {
+    fn from(array: Vec<MsgPackValue>) -> Self {
+        Self::MsgPackArray(GenericBinaryIterator { array, index: 0 })
+    }
+}
+
+impl From<Vec<CborValue>> for GenericIterator<'_> {
+    fn from(array: Vec<CborValue>) -> Self {
+        Self::CborArray(GenericBinaryIterator { array, index: 0 })
+    }
+}
+
 impl From<&Bound<'_, PyAny>> for GenericIterator<'_> {
     fn from(obj: &Bound<'_, PyAny>) -> Self {
         let py_iter = GenericPyIterator {
@@ -467,6 +488,33 @@ impl<'data> GenericJsonIterator<'data> {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct GenericBinaryIterator<V> {
+    array: Vec<V>,
+    index: usize,
+}
+
+impl<V: BinaryValue> GenericBinaryIterator<V> {
+    pub fn next(&mut self, _py: Python) -> PyResult<Option<(&V, usize)>> {
+        match self.array.get(self.index) {
+            Some(next) => {
+                let a = (next, self.index);
+                self.index += 1;
+                Ok(Some(a))
+            }
+            None => Ok(None),
+        }
+    }
+
+    pub fn input_as_error_value(&self, _py: Python<'_>) -> InputValue {
+        V::new_array(self.array.clone()).into_error_value()
+    }
+
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
 #[cfg_attr(debug_assertions, derive(Debug))]
 pub enum EitherString<'a, 'py> {
     Cow(Cow<'a, str>),
@@ -664,18 +712,45 @@ pub enum Int {
     Big(BigInt),
 }
 
+/// Name of the newtype struct wrapping integers which don't fit in 64 bits when serializing to binary formats,
+/// only the CBOR serializer recognises it and writes a bignum, see `serializers::cbor`
+pub(crate) const BIGINT_TOKEN: &str = "$pydantic_core::BigInt";
+
 // The default serialization for BigInt is some internal representation which roundtrips efficiently
 // but is not the JSON value which users would expect to see.
 fn serialize_bigint_as_number<S>(big_int: &BigInt, serializer: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
 {
+    // binary formats don't understand serde_json's arbitrary precision numbers, but can represent 64 bit ints
+    if !serializer.is_human_readable() {
+        if let Ok(i) = i64::try_from(big_int) {
+            return serializer.serialize_i64(i);
+        } else if let Ok(u) = u64::try_from(big_int) {
+            return serializer.serialize_u64(u);
+        }
+        return serializer.serialize_newtype_struct(BIGINT_TOKEN, &OutOfRangeInt(big_int));
+    }
     serde_json::Number::from_str(&big_int.to_string())
         .map_err(S::Error::custom)
         .expect("a valid number")
         .serialize(serializer)
 }
 
+/// Content of the `BIGINT_TOKEN` newtype struct, formats which don't recognise the token can't represent the
+/// integer so get an error, the CBOR serializer reads the decimal string with a human readable serializer
+struct OutOfRangeInt<'a>(&'a BigInt);
+
+impl Serialize for OutOfRangeInt<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self.0)
+        } else {
+            Err(S::Error::custom(format!("integer {} is out of range", self.0)))
+        }
+    }
+}
+
 impl PartialOrd for Int {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         match (self, other) {
@@ -33,11 +33,12 @@ pub use self::url::{PyMultiHostUrl, PyUrl};
 pub use argument_markers::{ArgsKwargs, PydanticUndefinedType};
 pub use build_tools::SchemaError;
 pub use errors::{
-    list_all_errors, PydanticCustomError, PydanticKnownError, PydanticOmit, PydanticUseDefault, ValidationError,
+    list_all_errors, register_error_messages, PydanticCustomError, PydanticKnownError, PydanticOmit,
+    PydanticUseDefault, ValidationError,
 };
 pub use serializers::{
     to_json, to_jsonable_python, PydanticSerializationError, PydanticSerializationUnexpectedValue, SchemaSerializer,
-    WarningsArg,
+    SortKeys, WarningsArg,
 };
 pub use validators::{PySome, SchemaValidator};
 
@@ -113,8 +114,8 @@ pub mod _pydantic_core {
 
     #[pymodule_export]
     use crate::{
-        from_json, list_all_errors, to_json, to_jsonable_python, ArgsKwargs, PyMultiHostUrl, PySome, PyUrl,
-        PydanticCustomError, PydanticKnownError, PydanticOmit, PydanticSerializationError,
+        from_json, list_all_errors, register_error_messages, to_json, to_jsonable_python, ArgsKwargs, PyMultiHostUrl,
+        PySome, PyUrl, PydanticCustomError, PydanticKnownError, PydanticOmit, PydanticSerializationError,
         PydanticSerializationUnexpectedValue, PydanticUndefinedType, PydanticUseDefault, SchemaError, SchemaSerializer,
         SchemaValidator, TzInfo, ValidationError,
     };
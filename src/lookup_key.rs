@@ -10,7 +10,7 @@ use jiter::{JsonObject, JsonValue};
 
 use crate::build_tools::py_schema_err;
 use crate::errors::{py_err_string, ErrorType, LocItem, Location, ToErrorValue, ValError, ValLineError, ValResult};
-use crate::input::StringMapping;
+use crate::input::{BinaryMap, BinaryValue, StringMapping};
 use crate::tools::{extract_i64, mapping_get, py_err};
 
 /// Used for getting items from python dicts, python objects, or JSON objects, in different ways
@@ -97,12 +97,23 @@ impl LookupKey {
         )
     }
 
+    /// With `case_sensitive=false` the keys of `dict` are all lowercase, so the lookup key is lowercased too
     pub fn py_get_string_mapping_item<'py, 's>(
         &'s self,
         dict: &Bound<'py, PyDict>,
+        case_sensitive: bool,
     ) -> ValResult<Option<(&'s LookupPath, StringMapping<'py>)>> {
-        if let Some((path, py_any)) = self.py_get_dict_item(dict)? {
-            let value = StringMapping::new_value(py_any)?;
+        let item = if case_sensitive {
+            self.py_get_dict_item(dict)?
+        } else {
+            self.get_impl(
+                dict,
+                |dict, path| dict.get_item(path.folded_py_key()),
+                |d, loc| Ok(loc.py_get_folded_item(&d)),
+            )?
+        };
+        if let Some((path, py_any)) = item {
+            let value = StringMapping::new_value(py_any, case_sensitive)?;
             Ok(Some((path, value)))
         } else {
             Ok(None)
@@ -221,6 +232,17 @@ impl LookupKey {
         }
     }
 
+    pub fn binary_get<'a, 's, V: BinaryValue>(
+        &'s self,
+        map: &'a BinaryMap<V>,
+    ) -> ValResult<Option<(&'s LookupPath, &'a V)>> {
+        Ok(self.get_impl(
+            map,
+            |map, key| Ok(map.get_str(&key.key)),
+            |value, loc| Ok(loc.binary_get(value)),
+        )?)
+    }
+
     fn get_impl<'s, 'a, SourceT, OutputT: 'a>(
         &'s self,
         source: &'a SourceT,
@@ -280,11 +302,7 @@ impl LookupKey {
         field_name: &str,
     ) -> ValLineError {
         if loc_by_alias {
-            let lookup_path = match self {
-                Self::Simple(path, ..) => path,
-                Self::Choice { path1, .. } => path1,
-                Self::PathChoices(paths) => paths.first().unwrap(),
-            };
+            let lookup_path = self.first_path();
 
             let mut location = Vec::with_capacity(1 + lookup_path.rest.len());
             for item in lookup_path.rest.iter().rev() {
@@ -297,6 +315,19 @@ impl LookupKey {
             ValLineError::new_with_loc(error_type, input, field_name.to_string())
         }
     }
+
+    /// locate an error at a field which wasn't found, the same way as `error`
+    pub fn apply_error_loc(&self, line_error: ValLineError, loc_by_alias: bool, field_name: &str) -> ValLineError {
+        self.first_path().apply_error_loc(line_error, loc_by_alias, field_name)
+    }
+
+    fn first_path(&self) -> &LookupPath {
+        match self {
+            Self::Simple(path, ..) => path,
+            Self::Choice { path1, .. } => path1,
+            Self::PathChoices(paths) => paths.first().unwrap(),
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -324,10 +355,7 @@ impl LookupPath {
             None => PyString::new(py, key),
         };
         Self {
-            first_item: PathItemString {
-                key: key.to_string(),
-                py_key: py_key.clone().unbind(),
-            },
+            first_item: PathItemString::new(key.to_string(), py_key),
             rest: Vec::new(),
         }
     }
@@ -343,10 +371,7 @@ impl LookupPath {
             return py_err!(PyTypeError; "The first item in an alias path should be a string");
         };
 
-        let first_item = PathItemString {
-            key: first_item_py_str.to_str()?.to_owned(),
-            py_key: first_item_py_str.clone().unbind(),
-        };
+        let first_item = PathItemString::new(first_item_py_str.to_str()?.to_owned(), first_item_py_str);
 
         let rest = iter.map(PathItem::from_py).collect::<PyResult<_>>()?;
 
@@ -386,6 +411,8 @@ pub(crate) enum PathItem {
 pub(crate) struct PathItemString {
     key: String,
     py_key: Py<PyString>,
+    /// lowercase `py_key` for case-insensitive lookups, `None` if `key` is already lowercase
+    lower_py_key: Option<Py<PyString>>,
 }
 
 impl fmt::Display for PathItem {
@@ -436,10 +463,7 @@ impl PathItem {
         let obj = match obj.downcast_into::<PyString>() {
             Ok(py_str_key) => {
                 let str_key = py_str_key.to_str()?.to_string();
-                return Ok(Self::S(PathItemString {
-                    key: str_key,
-                    py_key: py_str_key.unbind(),
-                }));
+                return Ok(Self::S(PathItemString::new(str_key, py_str_key)));
             }
             Err(e) => e.into_inner(),
         };
@@ -463,6 +487,16 @@ impl PathItem {
         }
     }
 
+    /// Like `py_get_item` but with the lowercase key, for the case-insensitive mappings of `validate_strings`
+    fn py_get_folded_item<'py>(&self, py_any: &Bound<'py, PyAny>) -> Option<Bound<'py, PyAny>> {
+        match self {
+            Self::S(path_item_string) if py_any.downcast::<PyString>().is_err() => {
+                py_any.get_item(path_item_string.folded_py_key()).ok()
+            }
+            _ => self.py_get_item(py_any),
+        }
+    }
+
     pub fn py_get_attrs<'py>(&self, obj: &Bound<'py, PyAny>) -> PyResult<Option<Bound<'py, PyAny>>> {
         match self {
             Self::S(path_item_string) => path_item_string.py_get_attrs(obj),
@@ -496,6 +530,25 @@ impl PathItem {
         }
     }
 
+    pub fn binary_get<'a, V: BinaryValue>(&self, value: &'a V) -> Option<&'a V> {
+        if let Some(map) = value.as_map() {
+            match self {
+                Self::S(PathItemString { key, .. }) => map.get_str(key),
+                // maps can have int keys
+                Self::Pos(index) => map.get_int(*index as i64),
+                Self::Neg(index) => map.get_int(-(*index as i64)),
+            }
+        } else if let Some(array) = value.as_array() {
+            match self {
+                Self::Pos(index) => array.get(*index),
+                Self::Neg(index) => array.len().checked_sub(*index).and_then(|index| array.get(index)),
+                Self::S(..) => None,
+            }
+        } else {
+            None
+        }
+    }
+
     fn to_loc_item(&self) -> LocItem {
         match self {
             Self::S(PathItemString { key, .. }) => LocItem::from(key),
@@ -506,6 +559,20 @@ impl PathItem {
 }
 
 impl PathItemString {
+    fn new(key: String, py_key: Bound<'_, PyString>) -> Self {
+        let lower = key.to_lowercase();
+        let lower_py_key = (lower != key).then(|| PyString::new(py_key.py(), &lower).unbind());
+        Self {
+            key,
+            py_key: py_key.unbind(),
+            lower_py_key,
+        }
+    }
+
+    fn folded_py_key(&self) -> &Py<PyString> {
+        self.lower_py_key.as_ref().unwrap_or(&self.py_key)
+    }
+
     fn py_get_attrs<'py>(&self, obj: &Bound<'py, PyAny>) -> PyResult<Option<Bound<'py, PyAny>>> {
         // if obj is a dict, we want to use get_item, not getattr
         if obj.downcast::<PyDict>().is_ok() {
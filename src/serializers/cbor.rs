@@ -0,0 +1,435 @@
+use num_bigint::{BigInt, Sign};
+use serde::ser::{self, Error as _, Serialize};
+
+use crate::input::BIGINT_TOKEN;
+
+use super::errors::PythonSerializerError;
+
+type Result<T> = std::result::Result<T, PythonSerializerError>;
+
+const MAJOR_UNSIGNED: u8 = 0;
+const MAJOR_NEGATIVE: u8 = 1;
+const MAJOR_BYTES: u8 = 2;
+const MAJOR_TEXT: u8 = 3;
+const MAJOR_ARRAY: u8 = 4;
+const MAJOR_MAP: u8 = 5;
+const MAJOR_TAG: u8 = 6;
+const FALSE: u8 = 0xf4;
+const TRUE: u8 = 0xf5;
+const NULL: u8 = 0xf6;
+const FLOAT32: u8 = 0xfa;
+const FLOAT64: u8 = 0xfb;
+const BREAK: u8 = 0xff;
+/// additional information marking an indefinite-length array or map
+const INDEFINITE: u8 = 31;
+const TAG_POSITIVE_BIGNUM: u64 = 2;
+const TAG_NEGATIVE_BIGNUM: u64 = 3;
+
+/// Serializer writing CBOR (RFC 8949), containers whose length isn't known up front use indefinite-length
+/// encoding, integers outside the 64 bit range are written as bignums (tags 2 and 3).
+pub struct CborSerializer {
+    writer: Vec<u8>,
+}
+
+impl CborSerializer {
+    pub fn new(writer: Vec<u8>) -> Self {
+        Self { writer }
+    }
+
+    pub fn into_inner(self) -> Vec<u8> {
+        self.writer
+    }
+
+    fn write_head(&mut self, major: u8, arg: u64) {
+        let major = major << 5;
+        if arg < 24 {
+            self.writer.push(major | arg as u8);
+        } else if let Ok(arg) = u8::try_from(arg) {
+            self.writer.extend_from_slice(&[major | 0x18, arg]);
+        } else if let Ok(arg) = u16::try_from(arg) {
+            self.writer.push(major | 0x19);
+            self.writer.extend_from_slice(&arg.to_be_bytes());
+        } else if let Ok(arg) = u32::try_from(arg) {
+            self.writer.push(major | 0x1a);
+            self.writer.extend_from_slice(&arg.to_be_bytes());
+        } else {
+            self.writer.push(major | 0x1b);
+            self.writer.extend_from_slice(&arg.to_be_bytes());
+        }
+    }
+
+    fn write_len(&mut self, major: u8, len: Option<usize>) {
+        match len {
+            Some(len) => self.write_head(major, len as u64),
+            None => self.writer.push((major << 5) | INDEFINITE),
+        }
+    }
+
+    fn write_bigint(&mut self, value: &BigInt) {
+        // negative integers hold `-1 - n`
+        let negative = -1i32 - value;
+        if let Ok(u) = u64::try_from(value) {
+            self.write_head(MAJOR_UNSIGNED, u);
+        } else if let Ok(n) = u64::try_from(&negative) {
+            self.write_head(MAJOR_NEGATIVE, n);
+        } else {
+            let (tag, bytes) = if value.sign() == Sign::Minus {
+                (TAG_NEGATIVE_BIGNUM, negative.to_bytes_be().1)
+            } else {
+                (TAG_POSITIVE_BIGNUM, value.to_bytes_be().1)
+            };
+            self.write_head(MAJOR_TAG, tag);
+            self.write_head(MAJOR_BYTES, bytes.len() as u64);
+            self.writer.extend_from_slice(&bytes);
+        }
+    }
+
+    fn write_i64(&mut self, value: i64) {
+        if value < 0 {
+            // negative integers hold `-1 - n`, which is the bitwise not for two's complement
+            self.write_head(MAJOR_NEGATIVE, !value as u64);
+        } else {
+            self.write_head(MAJOR_UNSIGNED, value as u64);
+        }
+    }
+
+    fn write_str(&mut self, value: &str) {
+        self.write_head(MAJOR_TEXT, value.len() as u64);
+        self.writer.extend_from_slice(value.as_bytes());
+    }
+}
+
+impl<'a> ser::Serializer for &'a mut CborSerializer {
+    type Ok = ();
+    type Error = PythonSerializerError;
+
+    type SerializeSeq = Compound<'a>;
+    type SerializeTuple = Compound<'a>;
+    type SerializeTupleStruct = Compound<'a>;
+    type SerializeTupleVariant = Compound<'a>;
+    type SerializeMap = Compound<'a>;
+    type SerializeStruct = Compound<'a>;
+    type SerializeStructVariant = Compound<'a>;
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+
+    fn serialize_bool(self, value: bool) -> Result<()> {
+        self.writer.push(if value { TRUE } else { FALSE });
+        Ok(())
+    }
+
+    fn serialize_i8(self, value: i8) -> Result<()> {
+        self.serialize_i64(value.into())
+    }
+
+    fn serialize_i16(self, value: i16) -> Result<()> {
+        self.serialize_i64(value.into())
+    }
+
+    fn serialize_i32(self, value: i32) -> Result<()> {
+        self.serialize_i64(value.into())
+    }
+
+    fn serialize_i64(self, value: i64) -> Result<()> {
+        self.write_i64(value);
+        Ok(())
+    }
+
+    fn serialize_i128(self, value: i128) -> Result<()> {
+        self.write_bigint(&value.into());
+        Ok(())
+    }
+
+    fn serialize_u8(self, value: u8) -> Result<()> {
+        self.serialize_u64(value.into())
+    }
+
+    fn serialize_u16(self, value: u16) -> Result<()> {
+        self.serialize_u64(value.into())
+    }
+
+    fn serialize_u32(self, value: u32) -> Result<()> {
+        self.serialize_u64(value.into())
+    }
+
+    fn serialize_u64(self, value: u64) -> Result<()> {
+        self.write_head(MAJOR_UNSIGNED, value);
+        Ok(())
+    }
+
+    fn serialize_u128(self, value: u128) -> Result<()> {
+        self.write_bigint(&value.into());
+        Ok(())
+    }
+
+    fn serialize_f32(self, value: f32) -> Result<()> {
+        self.writer.push(FLOAT32);
+        self.writer.extend_from_slice(&value.to_be_bytes());
+        Ok(())
+    }
+
+    fn serialize_f64(self, value: f64) -> Result<()> {
+        self.writer.push(FLOAT64);
+        self.writer.extend_from_slice(&value.to_be_bytes());
+        Ok(())
+    }
+
+    fn serialize_char(self, value: char) -> Result<()> {
+        self.write_str(value.encode_utf8(&mut [0; 4]));
+        Ok(())
+    }
+
+    fn serialize_str(self, value: &str) -> Result<()> {
+        self.write_str(value);
+        Ok(())
+    }
+
+    fn serialize_bytes(self, value: &[u8]) -> Result<()> {
+        self.write_head(MAJOR_BYTES, value.len() as u64);
+        self.writer.extend_from_slice(value);
+        Ok(())
+    }
+
+    fn serialize_none(self) -> Result<()> {
+        self.serialize_unit()
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<()> {
+        self.writer.push(NULL);
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<()> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(self, _name: &'static str, _variant_index: u32, variant: &'static str) -> Result<()> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T>(self, name: &'static str, value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        if name == BIGINT_TOKEN {
+            // the content writes its decimal string to human readable serializers
+            let digits = match serde_json::to_value(value).map_err(PythonSerializerError::custom)? {
+                serde_json::Value::String(digits) => digits,
+                other => return Err(PythonSerializerError::custom(format!("invalid integer {other}"))),
+            };
+            let big_int: BigInt = digits.parse().map_err(PythonSerializerError::custom)?;
+            self.write_bigint(&big_int);
+            Ok(())
+        } else {
+            value.serialize(self)
+        }
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.write_head(MAJOR_MAP, 1);
+        self.write_str(variant);
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq> {
+        self.write_len(MAJOR_ARRAY, len);
+        Ok(Compound::new(self, len.is_none()))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> Result<Self::SerializeTupleStruct> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        self.write_head(MAJOR_MAP, 1);
+        self.write_str(variant);
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap> {
+        self.write_len(MAJOR_MAP, len);
+        Ok(Compound::new(self, len.is_none()))
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<Self::SerializeStruct> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        self.write_head(MAJOR_MAP, 1);
+        self.write_str(variant);
+        self.serialize_map(Some(len))
+    }
+}
+
+pub struct Compound<'a> {
+    ser: &'a mut CborSerializer,
+    indefinite: bool,
+}
+
+impl<'a> Compound<'a> {
+    fn new(ser: &'a mut CborSerializer, indefinite: bool) -> Self {
+        Self { ser, indefinite }
+    }
+
+    fn finish(self) -> Result<()> {
+        if self.indefinite {
+            self.ser.writer.push(BREAK);
+        }
+        Ok(())
+    }
+}
+
+impl ser::SerializeSeq for Compound<'_> {
+    type Ok = ();
+    type Error = PythonSerializerError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(&mut *self.ser)
+    }
+
+    fn end(self) -> Result<()> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTuple for Compound<'_> {
+    type Ok = ();
+    type Error = PythonSerializerError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<()> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleStruct for Compound<'_> {
+    type Ok = ();
+    type Error = PythonSerializerError;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<()> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleVariant for Compound<'_> {
+    type Ok = ();
+    type Error = PythonSerializerError;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<()> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeMap for Compound<'_> {
+    type Ok = ();
+    type Error = PythonSerializerError;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        key.serialize(&mut *self.ser)
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(&mut *self.ser)
+    }
+
+    fn end(self) -> Result<()> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStruct for Compound<'_> {
+    type Ok = ();
+    type Error = PythonSerializerError;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.ser.write_str(key);
+        value.serialize(&mut *self.ser)
+    }
+
+    fn end(self) -> Result<()> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStructVariant for Compound<'_> {
+    type Ok = ();
+    type Error = PythonSerializerError;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        ser::SerializeStruct::serialize_field(self, key, value)
+    }
+
+    fn end(self) -> Result<()> {
+        self.finish()
+    }
+}
@@ -12,6 +12,7 @@ use crate::py_gc::PyGcTraverse;
 use crate::serializers::extra::FieldName;
 use crate::serializers::filter::SchemaFilter;
 use crate::serializers::shared::{BuildSerializer, CombinedSerializer, PydanticSerializer};
+use crate::serializers::sorted_map::SortedMap;
 use crate::serializers::SerializationState;
 use crate::tools::SchemaDict;
 
@@ -72,7 +73,7 @@ impl ComputedFields {
     pub fn serde_serialize<'py, S: serde::ser::Serializer>(
         &self,
         model: &Bound<'py, PyAny>,
-        map: &mut S::SerializeMap,
+        map: &mut SortedMap<S::SerializeMap>,
         filter: &SchemaFilter<isize>,
         state: &mut SerializationState<'_, 'py>,
     ) -> Result<(), S::Error> {
@@ -2,13 +2,14 @@ use std::borrow::Cow;
 use std::str::{from_utf8, FromStr, Utf8Error};
 
 use base64::Engine;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use pyo3::types::{PyDate, PyDateTime, PyDict, PyString, PyTime};
+use pyo3::types::{PyBool, PyDate, PyDateTime, PyDict, PyString, PyTime};
 use pyo3::{intern, IntoPyObjectExt};
 
 use serde::ser::Error;
 
-use crate::build_tools::py_schema_err;
+use crate::build_tools::{py_schema_err, schema_or_config};
 use crate::input::EitherTimedelta;
 use crate::serializers::type_serializers::datetime_etc::{
     date_to_milliseconds, date_to_seconds, date_to_string, datetime_to_milliseconds, datetime_to_seconds,
@@ -24,6 +25,10 @@ pub(crate) struct SerializationConfig {
     pub temporal_mode: TemporalMode,
     pub bytes_mode: BytesMode,
     pub inf_nan_mode: InfNanMode,
+    pub sort_keys: SortKeys,
+    pub float_format: FloatFormat,
+    pub decimal_mode: DecimalMode,
+    pub int_mode: IntMode,
 }
 
 impl SerializationConfig {
@@ -38,10 +43,18 @@ impl SerializationConfig {
         };
         let bytes_mode = BytesMode::from_config(config)?;
         let inf_nan_mode = InfNanMode::from_config(config)?;
+        let sort_keys = SortKeys::from_config(config)?;
+        let float_format = FloatFormat::from_config(config)?;
+        let decimal_mode = DecimalMode::from_config(config)?;
+        let int_mode = IntMode::from_config(config)?;
         Ok(Self {
             temporal_mode,
             bytes_mode,
             inf_nan_mode,
+            sort_keys,
+            float_format,
+            decimal_mode,
+            int_mode,
         })
     }
 
@@ -50,6 +63,8 @@ impl SerializationConfig {
         temporal_mode: &str,
         bytes_mode: &str,
         inf_nan_mode: &str,
+        int_mode: &str,
+        sort_keys: SortKeys,
     ) -> PyResult<Self> {
         let resolved_temporal_mode = if temporal_mode != "iso8601" {
             TemporalMode::from_str(temporal_mode)?
@@ -60,6 +75,10 @@ impl SerializationConfig {
             temporal_mode: resolved_temporal_mode,
             bytes_mode: BytesMode::from_str(bytes_mode)?,
             inf_nan_mode: InfNanMode::from_str(inf_nan_mode)?,
+            sort_keys,
+            float_format: FloatFormat::default(),
+            decimal_mode: DecimalMode::default(),
+            int_mode: IntMode::from_str(int_mode)?,
         })
     }
 }
@@ -136,6 +155,168 @@ serialization_mode! {
     Strings => "strings",
 }
 
+serialization_mode! {
+    IntMode,
+    "ser_json_int",
+    Number => "number",
+    String => "string",
+    Safe => "safe",
+}
+
+serialization_mode! {
+    DecimalMode,
+    "ser_json_decimal",
+    Str => "str",
+    Number => "number",
+}
+
+serialization_mode! {
+    FloatFormatMode,
+    "ser_json_float_format",
+    Shortest => "shortest",
+    Fixed => "fixed",
+    Scientific => "scientific",
+}
+
+/// How floats and decimals are written in JSON, from `float_format` and `float_precision` on the schema,
+/// falling back to `ser_json_float_format` and `ser_json_float_precision` from the config.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FloatFormat {
+    pub mode: FloatFormatMode,
+    /// Digits after the decimal point, `Shortest` rounds to this many places, `Fixed` and `Scientific` default to 6
+    pub precision: Option<usize>,
+}
+
+impl FloatFormat {
+    pub fn from_schema_or_config(schema: &Bound<'_, PyDict>, config: Option<&Bound<'_, PyDict>>) -> PyResult<Self> {
+        let py = schema.py();
+        let mode = match schema.get_as::<Bound<'_, PyString>>(intern!(py, "float_format"))? {
+            Some(mode) => FloatFormatMode::from_str(mode.to_str()?)?,
+            None => FloatFormatMode::from_config(config)?,
+        };
+        let precision = schema_or_config(
+            schema,
+            config,
+            intern!(py, "float_precision"),
+            intern!(py, "ser_json_float_precision"),
+        )?;
+        Ok(Self {
+            mode,
+            precision: check_float_precision(precision)?,
+        })
+    }
+
+    pub fn is_shortest(self) -> bool {
+        self == Self::default()
+    }
+
+    /// The text of the JSON number to write for the finite float `value`, `None` if the shortest
+    /// representation of `self.round(value)` should be written.
+    pub fn format_f64(self, value: f64) -> Option<String> {
+        let precision = self.precision.unwrap_or(6);
+        match self.mode {
+            FloatFormatMode::Shortest => None,
+            FloatFormatMode::Fixed => Some(format!("{value:.precision$}")),
+            FloatFormatMode::Scientific => {
+                // rust writes `1.50e3` where python writes `1.50e+03`, match python
+                let formatted = format!("{value:.precision$e}");
+                let (mantissa, exponent) = formatted.split_once('e')?;
+                let exponent: i32 = exponent.parse().ok()?;
+                let sign = if exponent < 0 { '-' } else { '+' };
+                Some(format!("{mantissa}e{sign}{:02}", exponent.unsigned_abs()))
+            }
+        }
+    }
+
+    /// The finite float `value` rounded as it would be formatted, used where a float rather than text is required.
+    pub fn round(self, value: f64) -> f64 {
+        let formatted = match (self.mode, self.precision) {
+            (FloatFormatMode::Shortest, None) => return value,
+            (FloatFormatMode::Shortest, Some(precision)) => format!("{value:.precision$}"),
+            _ => self.format_f64(value).unwrap_or_default(),
+        };
+        formatted.parse().unwrap_or(value)
+    }
+}
+
+impl FromConfig for FloatFormat {
+    fn from_config(config: Option<&Bound<'_, PyDict>>) -> PyResult<Self> {
+        let mode = FloatFormatMode::from_config(config)?;
+        let precision = match config {
+            Some(config_dict) => config_dict.get_as(intern!(config_dict.py(), "ser_json_float_precision"))?,
+            None => None,
+        };
+        Ok(Self {
+            mode,
+            precision: check_float_precision(precision)?,
+        })
+    }
+}
+
+fn check_float_precision(precision: Option<i64>) -> PyResult<Option<usize>> {
+    match precision.map(usize::try_from) {
+        Some(Ok(precision)) => Ok(Some(precision)),
+        Some(Err(_)) => py_schema_err!(
+            "`float_precision` should be a non-negative integer, got {}",
+            precision.unwrap_or_default()
+        ),
+        None => Ok(None),
+    }
+}
+
+/// Whether mapping keys are sorted during serialization, `TopLevel` only sorts the outermost mapping.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortKeys {
+    #[default]
+    Off,
+    TopLevel,
+    Recursive,
+    /// Set by `to_json(canonical=True)`, sorts recursively by UTF-16 code units as RFC 8785 requires,
+    /// and rejects mappings with duplicate keys
+    Canonical,
+}
+
+impl SortKeys {
+    pub fn enabled(self) -> bool {
+        self != Self::Off
+    }
+
+    /// The setting to use for mappings nested inside a mapping serialized with this setting.
+    #[must_use]
+    pub fn nested(self) -> Self {
+        match self {
+            Self::TopLevel => Self::Off,
+            other => other,
+        }
+    }
+}
+
+impl FromPyObject<'_> for SortKeys {
+    fn extract_bound(ob: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if let Ok(py_bool) = ob.downcast::<PyBool>() {
+            return Ok(if py_bool.is_true() { Self::Recursive } else { Self::Off });
+        }
+        match ob.extract::<&str>() {
+            Ok("recursive") => Ok(Self::Recursive),
+            Ok("top-level") => Ok(Self::TopLevel),
+            _ => Err(PyValueError::new_err(
+                "Invalid sort_keys value, should be a `bool`, `'recursive'` or `'top-level'`",
+            )),
+        }
+    }
+}
+
+impl FromConfig for SortKeys {
+    fn from_config(config: Option<&Bound<'_, PyDict>>) -> PyResult<Self> {
+        let Some(config_dict) = config else {
+            return Ok(Self::default());
+        };
+        Ok(config_dict
+            .get_as::<Self>(intern!(config_dict.py(), "ser_json_sort_keys"))?
+            .unwrap_or_default())
+    }
+}
+
 impl TimedeltaMode {}
 
 impl From<TimedeltaMode> for TemporalMode {
@@ -329,6 +510,10 @@ impl BytesMode {
     }
 
     pub fn serialize_bytes<S: serde::ser::Serializer>(self, bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        // binary formats can represent bytes natively
+        if !serializer.is_human_readable() {
+            return serializer.serialize_bytes(bytes);
+        }
         match self {
             Self::Utf8 => match from_utf8(bytes) {
                 Ok(s) => serializer.serialize_str(s),
@@ -58,7 +58,18 @@ impl serde::ser::Error for PythonSerializerError {
 
 /// convert a serde serialization error into a `PyErr`
 pub(super) fn se_err_py_err(error: PythonSerializerError) -> PyErr {
-    let s = error.to_string();
+    ser_err_py_err(&error.to_string(), "JSON")
+}
+
+pub(super) fn msgpack_se_err_py_err(error: rmp_serde::encode::Error) -> PyErr {
+    ser_err_py_err(&error.to_string(), "MessagePack")
+}
+
+pub(super) fn cbor_se_err_py_err(error: PythonSerializerError) -> PyErr {
+    ser_err_py_err(&error.to_string(), "CBOR")
+}
+
+fn ser_err_py_err(s: &str, format_name: &str) -> PyErr {
     if let Some(msg) = s.strip_prefix(UNEXPECTED_TYPE_SER_MARKER) {
         if msg.is_empty() {
             PydanticSerializationUnexpectedValue::new_from_msg(None).to_py_err()
@@ -68,7 +79,7 @@ pub(super) fn se_err_py_err(error: PythonSerializerError) -> PyErr {
     } else if let Some(msg) = s.strip_prefix(SERIALIZATION_ERR_MARKER) {
         PydanticSerializationError::new_err(msg.to_string())
     } else {
-        let msg = format!("Error serializing to JSON: {s}");
+        let msg = format!("Error serializing to {format_name}: {s}");
         PydanticSerializationError::new_err(msg)
     }
 }
@@ -0,0 +1,66 @@
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyString, PyTuple};
+
+use crate::build_tools::py_schema_error_type;
+
+/// Predicate used by `serialization_exclude_if`, `extras_exclude_if` and `values_exclude_if` to decide whether
+/// a value should be omitted, the built-in predicates are evaluated without calling into Python code.
+#[derive(Debug)]
+pub(crate) enum ExcludeIf {
+    /// a Python callable returning a bool
+    Callable(Py<PyAny>),
+    /// `'none'` - the value is `None`
+    None,
+    /// `'empty'` - the value has a length of zero, e.g. `''`, `[]` or `{}`
+    Empty,
+    /// `'falsy'` - the value is falsy
+    Falsy,
+    /// `{'equals': value}` - the value is equal to `value`
+    Equals(Py<PyAny>),
+    /// `{'in': [values]}` - the value is equal to one of `values`
+    In(Py<PyTuple>),
+}
+
+impl ExcludeIf {
+    pub fn exclude(&self, value: &Bound<'_, PyAny>) -> PyResult<bool> {
+        let py = value.py();
+        match self {
+            Self::Callable(callable) => callable.call1(py, (value,))?.extract(py),
+            Self::None => Ok(value.is_none()),
+            Self::Empty => Ok(matches!(value.len(), Ok(0))),
+            Self::Falsy => Ok(!value.is_truthy()?),
+            Self::Equals(other) => value.eq(other),
+            Self::In(values) => values.bind(py).contains(value),
+        }
+    }
+}
+
+impl FromPyObject<'_> for ExcludeIf {
+    fn extract_bound(ob: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if let Ok(py_str) = ob.downcast::<PyString>() {
+            return match py_str.to_str()? {
+                "none" => Ok(Self::None),
+                "empty" => Ok(Self::Empty),
+                "falsy" => Ok(Self::Falsy),
+                s => Err(py_schema_error_type!("Invalid exclude_if predicate: `{}`", s)),
+            };
+        }
+        if let Ok(py_dict) = ob.downcast::<PyDict>() {
+            if py_dict.len() == 1 {
+                if let Some(other) = py_dict.get_item("equals")? {
+                    return Ok(Self::Equals(other.unbind()));
+                }
+                if let Some(values) = py_dict.get_item("in")? {
+                    let values = PyTuple::new(ob.py(), values.try_iter()?.collect::<PyResult<Vec<_>>>()?)?;
+                    return Ok(Self::In(values.unbind()));
+                }
+            }
+        } else if ob.is_callable() {
+            return Ok(Self::Callable(ob.clone().unbind()));
+        }
+        Err(py_schema_error_type!(
+            "Invalid exclude_if value, should be a callable, `'none'`, `'empty'`, `'falsy'`, \
+             `{'equals': value}` or `{'in': [values]}`"
+        ))
+    }
+}
@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::convert::Infallible;
 use std::ffi::CString;
 use std::fmt;
@@ -177,6 +178,8 @@ pub(crate) struct Extra<'a, 'py> {
     pub fallback: Option<&'a Bound<'py, PyAny>>,
     pub serialize_as_any: bool,
     pub context: Option<&'a Bound<'py, PyAny>>,
+    /// Only fields in one of these groups (or without `serialization_groups`) are serialized
+    pub groups: Option<&'a HashSet<String>>,
 }
 
 impl<'a, 'py> Extra<'a, 'py> {
@@ -208,6 +211,7 @@ impl<'a, 'py> Extra<'a, 'py> {
             fallback,
             serialize_as_any,
             context,
+            groups: None,
         }
     }
 
@@ -253,6 +257,7 @@ pub(crate) struct ExtraOwned {
     pub fallback: Option<Py<PyAny>>,
     serialize_as_any: bool,
     pub context: Option<Py<PyAny>>,
+    groups: Option<HashSet<String>>,
     include: Option<Py<PyAny>>,
     exclude: Option<Py<PyAny>>,
 }
@@ -304,6 +309,7 @@ impl ExtraOwned {
             fallback: extra.fallback.map(|model| model.clone().into()),
             serialize_as_any: extra.serialize_as_any,
             context: extra.context.map(|model| model.clone().into()),
+            groups: extra.groups.cloned(),
             include: state.include().map(|m| m.clone().into()),
             exclude: state.exclude().map(|m| m.clone().into()),
         }
@@ -323,6 +329,7 @@ impl ExtraOwned {
             fallback: self.fallback.as_ref().map(|m| m.bind(py)),
             serialize_as_any: self.serialize_as_any,
             context: self.context.as_ref().map(|m| m.bind(py)),
+            groups: self.groups.as_ref(),
         }
     }
 
@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::collections::HashSet;
 use std::string::ToString;
 use std::sync::Arc;
 
@@ -17,11 +18,14 @@ use crate::serializers::SerializationState;
 use crate::PydanticSerializationUnexpectedValue;
 
 use super::computed_fields::ComputedFields;
+use super::config::SortKeys;
 use super::errors::py_err_se_err;
+use super::exclude_if::ExcludeIf;
 use super::extra::Extra;
 use super::filter::SchemaFilter;
 use super::infer::{infer_json_key, infer_serialize, infer_to_python, SerializeInfer};
-use super::shared::{CombinedSerializer, PydanticSerializer, TypeSerializer};
+use super::shared::{serde_len_hint, CombinedSerializer, PydanticSerializer, TypeSerializer};
+use super::sorted_map::{sort_dict, SortedMap};
 
 /// representation of a field for serialization
 #[derive(Debug)]
@@ -33,12 +37,15 @@ pub(super) struct SerField {
     pub serializer: Option<Arc<CombinedSerializer>>,
     pub required: bool,
     pub serialize_by_alias: Option<bool>,
-    pub serialization_exclude_if: Option<Py<PyAny>>,
+    pub serialization_exclude_if: Option<ExcludeIf>,
+    /// The field is only serialized when one of these groups is requested, if groups are requested at all
+    pub serialization_groups: Option<Vec<String>>,
 }
 
 impl_py_gc_traverse!(SerField { serializer });
 
 impl SerField {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         py: Python,
         key_py: Py<PyString>,
@@ -46,7 +53,8 @@ impl SerField {
         serializer: Option<Arc<CombinedSerializer>>,
         required: bool,
         serialize_by_alias: Option<bool>,
-        serialization_exclude_if: Option<Py<PyAny>>,
+        serialization_exclude_if: Option<ExcludeIf>,
+        serialization_groups: Option<Vec<String>>,
     ) -> Self {
         let alias_py = alias.as_ref().map(|alias| PyString::new(py, alias.as_str()).into());
         Self {
@@ -57,6 +65,7 @@ impl SerField {
             required,
             serialize_by_alias,
             serialization_exclude_if,
+            serialization_groups,
         }
     }
 
@@ -69,6 +78,15 @@ impl SerField {
         self.key_py.bind(py)
     }
 
+    /// Whether the field belongs to one of the `groups` requested, fields without `serialization_groups`
+    /// are always serialized.
+    pub fn in_groups(&self, groups: Option<&HashSet<String>>) -> bool {
+        match (groups, &self.serialization_groups) {
+            (Some(groups), Some(field_groups)) => field_groups.iter().any(|group| groups.contains(group)),
+            _ => true,
+        }
+    }
+
     pub fn get_key_json<'a>(&'a self, key_str: &'a str, extra: &Extra) -> Cow<'a, str> {
         if extra.serialize_by_alias_or(self.serialize_by_alias) {
             if let Some(ref alias) = self.alias {
@@ -79,16 +97,11 @@ impl SerField {
     }
 }
 
-fn serialization_exclude_if(exclude_if_callable: Option<&Py<PyAny>>, value: &Bound<'_, PyAny>) -> PyResult<bool> {
-    if let Some(exclude_if_callable) = exclude_if_callable {
-        let py = value.py();
-        let result = exclude_if_callable.call1(py, (value,))?;
-        let exclude = result.extract::<bool>(py)?;
-        if exclude {
-            return Ok(true);
-        }
+fn serialization_exclude_if(exclude_if: Option<&ExcludeIf>, value: &Bound<'_, PyAny>) -> PyResult<bool> {
+    match exclude_if {
+        Some(exclude_if) => exclude_if.exclude(value),
+        None => Ok(false),
     }
-    Ok(false)
 }
 
 fn exclude_default<'py>(
@@ -123,6 +136,7 @@ pub struct GeneralFieldsSerializer {
     computed_fields: Option<ComputedFields>,
     mode: FieldsMode,
     extra_serializer: Option<Arc<CombinedSerializer>>,
+    extras_exclude_if: Option<ExcludeIf>,
     // isize because we look up filter via `.hash()` which returns an isize
     filter: SchemaFilter<isize>,
     required_fields: usize,
@@ -142,6 +156,7 @@ impl GeneralFieldsSerializer {
         fields: AHashMap<String, SerField>,
         mode: FieldsMode,
         extra_serializer: Option<Arc<CombinedSerializer>>,
+        extras_exclude_if: Option<ExcludeIf>,
         computed_fields: Option<ComputedFields>,
     ) -> Self {
         let required_fields = fields.values().filter(|f| f.required).count();
@@ -149,6 +164,7 @@ impl GeneralFieldsSerializer {
             fields,
             mode,
             extra_serializer,
+            extras_exclude_if,
             filter: SchemaFilter::default(),
             computed_fields,
             required_fields,
@@ -208,6 +224,9 @@ impl GeneralFieldsSerializer {
 
                     (field.get_key_py(output_dict.py(), &state.extra), serializer)
                 } else if self.mode == FieldsMode::TypedDictAllow {
+                    if serialization_exclude_if(self.extras_exclude_if.as_ref(), &value)? {
+                        continue;
+                    }
                     let serializer = self
                         .extra_serializer
                         .as_ref()
@@ -259,11 +278,13 @@ impl GeneralFieldsSerializer {
         main_iter: impl Iterator<Item = PyResult<(Bound<'py, PyAny>, Bound<'py, PyAny>)>>,
         expected_len: usize,
         serializer: S,
+        sort_keys: SortKeys,
         state: &mut SerializationState<'_, 'py>,
-    ) -> Result<S::SerializeMap, S::Error> {
+    ) -> Result<SortedMap<S::SerializeMap>, S::Error> {
         // NOTE! As above, we maintain the order of the input dict assuming that's right
         // we don't both with `used_req_fields` here because on unions, `to_python(..., mode='json')` is used
-        let mut map = serializer.serialize_map(Some(expected_len))?;
+        let len_hint = serde_len_hint(&serializer, expected_len);
+        let mut map = SortedMap::start(serializer, len_hint, sort_keys)?;
 
         for result in main_iter {
             let (key, value) = result.map_err(py_err_se_err)?;
@@ -293,6 +314,9 @@ impl GeneralFieldsSerializer {
                     let s = PydanticSerializer::new_no_infer(&value, serializer, state);
                     map.serialize_entry(&output_key, &s)?;
                 } else if self.mode == FieldsMode::TypedDictAllow {
+                    if serialization_exclude_if(self.extras_exclude_if.as_ref(), &value).map_err(py_err_se_err)? {
+                        continue;
+                    }
                     // FIXME: why is `extra_serializer` not used here when `serialize_as_any` is not set?
                     let output_key = infer_json_key(&key, state).map_err(py_err_se_err)?;
                     let s = SerializeInfer::new(&value, state);
@@ -316,11 +340,14 @@ impl GeneralFieldsSerializer {
             return Ok(None);
         };
 
+        if !field.in_groups(field_extra.groups) {
+            return Ok(None);
+        }
+
         if exclude_default(value, field_extra, serializer)? {
             return Ok(None);
         }
 
-        // FIXME: should `exclude_if` be applied to extra fields too?
         if serialization_exclude_if(field.serialization_exclude_if.as_ref(), value)? {
             return Ok(None);
         }
@@ -362,7 +389,7 @@ impl GeneralFieldsSerializer {
     pub(crate) fn add_computed_fields_json<'py, S: serde::ser::Serializer>(
         &self,
         model: &Bound<'py, PyAny>,
-        map: &mut S::SerializeMap,
+        map: &mut SortedMap<S::SerializeMap>,
         state: &mut SerializationState<'_, 'py>,
     ) -> Result<(), S::Error> {
         if let Some(ref computed_fields) = self.computed_fields {
@@ -396,6 +423,8 @@ impl TypeSerializer for GeneralFieldsSerializer {
             state.warn_fallback_py(self.get_name(), value)?;
             return infer_to_python(value, state);
         };
+        let sort_keys = state.config.sort_keys;
+        let state = &mut state.scoped_set(|s| &mut s.config.sort_keys, sort_keys.nested());
         let output_dict = self.main_to_python(py, &model, dict_items(&main_dict), state)?;
 
         // this is used to include `__pydantic_extra__` in serialization on models
@@ -407,6 +436,9 @@ impl TypeSerializer for GeneralFieldsSerializer {
                 if value.is(missing_sentinel) {
                     continue;
                 }
+                if serialization_exclude_if(self.extras_exclude_if.as_ref(), &value)? {
+                    continue;
+                }
                 if let Some((next_include, next_exclude)) = self.filter.key_filter(&key, state)? {
                     let state = &mut state.scoped_include_exclude(next_include, next_exclude);
                     let value = match &self.extra_serializer {
@@ -418,7 +450,11 @@ impl TypeSerializer for GeneralFieldsSerializer {
             }
         }
         self.add_computed_fields_python(&model, &output_dict, state)?;
-        Ok(output_dict.into())
+        if sort_keys.enabled() {
+            Ok(sort_dict(&output_dict)?.into())
+        } else {
+            Ok(output_dict.into())
+        }
     }
 
     fn json_key<'a, 'py>(
@@ -446,9 +482,11 @@ impl TypeSerializer for GeneralFieldsSerializer {
             FieldsMode::TypedDictAllow => main_dict.len() + self.computed_field_count(),
             _ => self.fields.len() + option_length!(extra_dict) + self.computed_field_count(),
         };
+        let sort_keys = state.config.sort_keys;
+        let state = &mut state.scoped_set(|s| &mut s.config.sort_keys, sort_keys.nested());
         // NOTE! As above, we maintain the order of the input dict assuming that's right
         // we don't both with `used_req_fields` here because on unions, `to_python(..., mode='json')` is used
-        let mut map = self.main_serde_serialize(dict_items(&main_dict), expected_len, serializer, state)?;
+        let mut map = self.main_serde_serialize(dict_items(&main_dict), expected_len, serializer, sort_keys, state)?;
 
         // this is used to include `__pydantic_extra__` in serialization on models
         if let Some(extra_dict) = extra_dict {
@@ -459,6 +497,9 @@ impl TypeSerializer for GeneralFieldsSerializer {
                 if value.is(missing_sentinel) {
                     continue;
                 }
+                if serialization_exclude_if(self.extras_exclude_if.as_ref(), &value).map_err(py_err_se_err)? {
+                    continue;
+                }
                 let filter = self.filter.key_filter(&key, state).map_err(py_err_se_err)?;
                 if let Some((next_include, next_exclude)) = filter {
                     let state = &mut state.scoped_include_exclude(next_include, next_exclude);
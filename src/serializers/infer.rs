@@ -16,17 +16,20 @@ use crate::serializers::shared::serialize_to_json;
 use crate::serializers::shared::serialize_to_python;
 use crate::serializers::shared::DoSerialize;
 use crate::serializers::type_serializers;
+use crate::serializers::type_serializers::decimal::{decimal_to_string, serialize_decimal};
 use crate::serializers::type_serializers::format::serialize_via_str;
+use crate::serializers::type_serializers::simple::{int_to_json_python, serialize_int};
 use crate::serializers::SerializationState;
 use crate::tools::{extract_int, py_err, safe_repr};
 
-use super::config::InfNanMode;
+use super::config::{InfNanMode, SerializationConfig};
 use super::errors::SERIALIZATION_ERR_MARKER;
 use super::errors::{py_err_se_err, PydanticSerializationError};
 use super::extra::SerMode;
 use super::filter::{AnyFilter, SchemaFilter};
 use super::ob_type::ObType;
-use super::shared::any_dataclass_iter;
+use super::shared::{any_dataclass_iter, serde_len_hint};
+use super::sorted_map::{sort_dict, SortedMap};
 use super::SchemaSerializer;
 
 pub(crate) fn infer_to_python<'py>(
@@ -92,11 +95,12 @@ pub(crate) fn infer_to_python_known<'py>(
     let value = match state.extra.mode {
         SerMode::Json => match ob_type {
             // `bool` and `None` can't be subclasses, `ObType::Int`, `ObType::Float`, `ObType::Str` refer to exact types
-            ObType::None | ObType::Bool | ObType::Int | ObType::Str => value.clone().unbind(),
+            ObType::None | ObType::Bool | ObType::Str => value.clone().unbind(),
+            ObType::Int => int_to_json_python(value, state)?,
             // have to do this to make sure subclasses of for example str are upcast to `str`
             ObType::IntSubclass => {
                 if let Some(i) = extract_int(value) {
-                    i.into_py_any(py)?
+                    int_to_json_python(i.into_pyobject(py)?.as_any(), state)?
                 } else {
                     return py_err!(PyTypeError; "Expected int, got {}", safe_repr(value));
                 }
@@ -106,9 +110,9 @@ pub(crate) fn infer_to_python_known<'py>(
                 if (v.is_nan() || v.is_infinite()) && state.config.inf_nan_mode == InfNanMode::Null {
                     return Ok(py.None());
                 }
-                v.into_py_any(py)?
+                type_serializers::float::float_to_json_python(py, v, state.config.float_format)?
             }
-            ObType::Decimal => value.to_string().into_py_any(py)?,
+            ObType::Decimal => decimal_to_string(value, state.config.float_format)?.into_py_any(py)?,
             ObType::StrSubclass => PyString::new(py, value.downcast::<PyString>()?.to_str()?).into(),
             ObType::Bytes => state
                 .config
@@ -343,7 +347,8 @@ pub(crate) fn infer_serialize_known<'py, S: Serializer>(
     macro_rules! serialize_seq_filter {
         ($t:ty) => {{
             let py_seq = value.downcast::<$t>().map_err(py_err_se_err)?;
-            let mut seq = serializer.serialize_seq(Some(py_seq.len()))?;
+            let len_hint = serde_len_hint(&serializer, py_seq.len());
+            let mut seq = serializer.serialize_seq(len_hint)?;
             let filter = AnyFilter::new();
             let len = value.len().ok();
 
@@ -361,7 +366,10 @@ pub(crate) fn infer_serialize_known<'py, S: Serializer>(
 
     let ser_result = match ob_type {
         ObType::None => serializer.serialize_none(),
-        ObType::Int | ObType::IntSubclass => serialize!(Int),
+        ObType::Int | ObType::IntSubclass => match value.extract::<Int>() {
+            Ok(int) => serialize_int(&int, serializer, state),
+            Err(e) => Err(py_err_se_err(e)),
+        },
         ObType::Bool => serialize!(bool),
         ObType::Complex => {
             let v = value.downcast::<PyComplex>().map_err(py_err_se_err)?;
@@ -370,9 +378,9 @@ pub(crate) fn infer_serialize_known<'py, S: Serializer>(
         }
         ObType::Float | ObType::FloatSubclass => {
             let v = value.extract::<f64>().map_err(py_err_se_err)?;
-            type_serializers::float::serialize_f64(v, serializer, state.config.inf_nan_mode)
+            type_serializers::float::serialize_f64(v, serializer, state.config.inf_nan_mode, state.config.float_format)
         }
-        ObType::Decimal => value.to_string().serialize(serializer),
+        ObType::Decimal => serialize_decimal(value, serializer, state.config.float_format, &state.config),
         ObType::Str | ObType::StrSubclass => {
             let py_str = value.downcast::<PyString>().map_err(py_err_se_err)?;
             serialize_to_json(serializer)
@@ -522,10 +530,10 @@ pub(crate) fn infer_json_key_known<'a, 'py>(
             if (v.is_nan() || v.is_infinite()) && state.config.inf_nan_mode == InfNanMode::Null {
                 super::type_serializers::simple::none_json_key()
             } else {
-                super::type_serializers::simple::to_str_json_key(key)
+                super::type_serializers::float::float_json_key(key, state.config.float_format)
             }
         }
-        ObType::Decimal => Ok(Cow::Owned(key.to_string())),
+        ObType::Decimal => Ok(Cow::Owned(decimal_to_string(key, state.config.float_format)?)),
         ObType::Bool => super::type_serializers::simple::bool_json_key(key),
         ObType::Str | ObType::StrSubclass => key.downcast::<PyString>()?.to_cow(),
         ObType::Bytes => state
@@ -622,7 +630,11 @@ pub(crate) fn call_pydantic_serializer<'py, T, E: From<PyErr>>(
     let mut state = SerializationState {
         warnings: state.warnings.clone(),
         rec_guard: state.rec_guard.clone(),
-        config: extracted_serializer.config,
+        config: SerializationConfig {
+            // `sort_keys` is set per call rather than by the model's config
+            sort_keys: state.config.sort_keys,
+            ..extracted_serializer.config
+        },
         model: state.model.clone(),
         field_name: state.field_name.clone(),
         include_exclude: state.include_exclude.clone(),
@@ -643,6 +655,8 @@ fn serialize_pairs_python<'py>(
 ) -> PyResult<Py<PyAny>> {
     let new_dict = PyDict::new(py);
     let filter = AnyFilter::new();
+    let sort_keys = state.config.sort_keys;
+    let state = &mut state.scoped_set(|s| &mut s.config.sort_keys, sort_keys.nested());
 
     for result in pairs_iter {
         let (k, v) = result?;
@@ -654,7 +668,11 @@ fn serialize_pairs_python<'py>(
             new_dict.set_item(k, v)?;
         }
     }
-    Ok(new_dict.into())
+    if sort_keys.enabled() {
+        Ok(sort_dict(&new_dict)?.into())
+    } else {
+        Ok(new_dict.into())
+    }
 }
 
 fn serialize_pairs_json<'py, S: Serializer>(
@@ -663,7 +681,10 @@ fn serialize_pairs_json<'py, S: Serializer>(
     serializer: S,
     state: &mut SerializationState<'_, 'py>,
 ) -> Result<S::Ok, S::Error> {
-    let mut map = serializer.serialize_map(Some(iter_size))?;
+    let len_hint = serde_len_hint(&serializer, iter_size);
+    let sort_keys = state.config.sort_keys;
+    let state = &mut state.scoped_set(|s| &mut s.config.sort_keys, sort_keys.nested());
+    let mut map = SortedMap::start(serializer, len_hint, sort_keys)?;
     let filter = AnyFilter::new();
 
     for result in pairs_iter {
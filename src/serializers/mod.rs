@@ -1,7 +1,9 @@
+use std::collections::HashSet;
 use std::fmt::Debug;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
+use jiter::PartialMode;
 use pyo3::prelude::*;
 use pyo3::types::{PyBytes, PyDict, PyTuple, PyType};
 use pyo3::{PyTraverseError, PyVisit};
@@ -9,26 +11,37 @@ use type_serializers::any::AnySerializer;
 
 use crate::definitions::{Definitions, DefinitionsBuilder};
 use crate::py_gc::PyGcTraverse;
+use crate::validators::SchemaValidator;
 
+pub use config::SortKeys;
 pub(crate) use config::{BytesMode, SerializationConfig};
 pub use errors::{PydanticSerializationError, PydanticSerializationUnexpectedValue};
 pub(crate) use extra::{Extra, SerMode, SerializationState, WarningsMode};
-use shared::to_json_bytes;
+pub(crate) use infer::infer_to_python;
+use roundtrip::first_divergence;
 pub use shared::CombinedSerializer;
+use shared::{to_cbor_bytes, to_json_bytes, to_json_fp, to_json_lines_bytes, to_msgpack_bytes, JsonFormat};
 
+mod cbor;
 mod computed_fields;
 mod config;
 mod errors;
+mod exclude_if;
 mod extra;
 mod fields;
 mod filter;
 mod infer;
 mod ob_type;
 mod prebuilt;
+mod roundtrip;
 pub mod ser;
 mod shared;
+mod sorted_map;
 mod type_serializers;
 
+/// Number of bytes buffered before each `fp.write()` call when serializing to a file-like object.
+const DEFAULT_FP_BUFFER_SIZE: usize = 65536;
+
 #[derive(FromPyObject)]
 pub enum WarningsArg {
     Bool(bool),
@@ -78,7 +91,8 @@ impl SchemaSerializer {
     #[allow(clippy::too_many_arguments)]
     #[pyo3(signature = (value, *, mode = None, include = None, exclude = None, by_alias = None,
         exclude_unset = false, exclude_defaults = false, exclude_none = false, exclude_computed_fields = false,
-        round_trip = false, warnings = WarningsArg::Bool(true), fallback = None, serialize_as_any = false, context = None))]
+        round_trip = false, warnings = WarningsArg::Bool(true), fallback = None, serialize_as_any = false, context = None,
+        sort_keys = None, groups = None))]
     pub fn to_python(
         &self,
         py: Python,
@@ -96,13 +110,15 @@ impl SchemaSerializer {
         fallback: Option<&Bound<'_, PyAny>>,
         serialize_as_any: bool,
         context: Option<&Bound<'_, PyAny>>,
+        sort_keys: Option<SortKeys>,
+        groups: Option<HashSet<String>>,
     ) -> PyResult<Py<PyAny>> {
         let mode: SerMode = mode.into();
         let warnings_mode = match warnings {
             WarningsArg::Bool(b) => b.into(),
             WarningsArg::Literal(mode) => mode,
         };
-        let extra = Extra::new(
+        let mut extra = Extra::new(
             py,
             &mode,
             by_alias,
@@ -116,7 +132,15 @@ impl SchemaSerializer {
             serialize_as_any,
             context,
         );
-        let mut state = SerializationState::new(self.config, warnings_mode, include, exclude, extra)?;
+        extra.groups = groups.as_ref();
+        let mut config = self.config;
+        // `ser_json_sort_keys` only applies when serializing to JSON
+        config.sort_keys = sort_keys.unwrap_or(if mode.is_json() {
+            config.sort_keys
+        } else {
+            SortKeys::Off
+        });
+        let mut state = SerializationState::new(config, warnings_mode, include, exclude, extra)?;
         let v = self.serializer.to_python(value, &mut state)?;
         state.warnings.final_check(py)?;
         Ok(v)
@@ -125,7 +149,9 @@ impl SchemaSerializer {
     #[allow(clippy::too_many_arguments)]
     #[pyo3(signature = (value, *, indent = None, ensure_ascii = false, include = None, exclude = None, by_alias = None,
         exclude_unset = false, exclude_defaults = false, exclude_none = false, exclude_computed_fields = false,
-        round_trip = false, warnings = WarningsArg::Bool(true), fallback = None, serialize_as_any = false, context = None))]
+        round_trip = false, warnings = WarningsArg::Bool(true), fallback = None, serialize_as_any = false, context = None,
+        fp = None, buffer_size = DEFAULT_FP_BUFFER_SIZE, canonical = false, sort_keys = None, separators = None,
+        trailing_newline = false, inline_scalar_arrays = false, groups = None))]
     pub fn to_json(
         &self,
         py: Python,
@@ -144,12 +170,20 @@ impl SchemaSerializer {
         fallback: Option<&Bound<'_, PyAny>>,
         serialize_as_any: bool,
         context: Option<&Bound<'_, PyAny>>,
+        fp: Option<&Bound<'_, PyAny>>,
+        buffer_size: usize,
+        canonical: bool,
+        sort_keys: Option<SortKeys>,
+        separators: Option<(String, String)>,
+        trailing_newline: bool,
+        inline_scalar_arrays: bool,
+        groups: Option<HashSet<String>>,
     ) -> PyResult<Py<PyAny>> {
         let warnings_mode = match warnings {
             WarningsArg::Bool(b) => b.into(),
             WarningsArg::Literal(mode) => mode,
         };
-        let extra = Extra::new(
+        let mut extra = Extra::new(
             py,
             &SerMode::Json,
             by_alias,
@@ -163,13 +197,28 @@ impl SchemaSerializer {
             serialize_as_any,
             context,
         );
-        let mut state = SerializationState::new(self.config, warnings_mode, include, exclude, extra)?;
+        extra.groups = groups.as_ref();
+        let mut config = self.config;
+        config.sort_keys = sort_keys.unwrap_or(config.sort_keys);
+        let mut state = SerializationState::new(config, warnings_mode, include, exclude, extra)?;
+        let format = JsonFormat {
+            indent,
+            ensure_ascii: ensure_ascii.unwrap_or(false),
+            canonical,
+            separators,
+            trailing_newline,
+            inline_scalar_arrays,
+        };
+        if let Some(fp) = fp {
+            to_json_fp(value, &self.serializer, &mut state, &format, fp, buffer_size)?;
+            state.warnings.final_check(py)?;
+            return Ok(py.None());
+        }
         let bytes = to_json_bytes(
             value,
             &self.serializer,
             &mut state,
-            indent,
-            ensure_ascii.unwrap_or(false),
+            &format,
             self.expected_json_size.load(Ordering::Relaxed),
         )?;
 
@@ -180,6 +229,226 @@ impl SchemaSerializer {
         Ok(py_bytes.into())
     }
 
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (values, *, ensure_ascii = false, include = None, exclude = None, by_alias = None,
+        exclude_unset = false, exclude_defaults = false, exclude_none = false, exclude_computed_fields = false,
+        round_trip = false, warnings = WarningsArg::Bool(true), fallback = None, serialize_as_any = false, context = None))]
+    pub fn to_json_lines(
+        &self,
+        py: Python,
+        values: &Bound<'_, PyAny>,
+        ensure_ascii: Option<bool>,
+        include: Option<Bound<'_, PyAny>>,
+        exclude: Option<Bound<'_, PyAny>>,
+        by_alias: Option<bool>,
+        exclude_unset: bool,
+        exclude_defaults: bool,
+        exclude_none: bool,
+        exclude_computed_fields: bool,
+        round_trip: bool,
+        warnings: WarningsArg,
+        fallback: Option<&Bound<'_, PyAny>>,
+        serialize_as_any: bool,
+        context: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<Py<PyAny>> {
+        let warnings_mode = match warnings {
+            WarningsArg::Bool(b) => b.into(),
+            WarningsArg::Literal(mode) => mode,
+        };
+        let extra = Extra::new(
+            py,
+            &SerMode::Json,
+            by_alias,
+            exclude_unset,
+            exclude_defaults,
+            exclude_none,
+            exclude_computed_fields,
+            round_trip,
+            false,
+            fallback,
+            serialize_as_any,
+            context,
+        );
+        let mut state = SerializationState::new(self.config, warnings_mode, include, exclude, extra)?;
+        let bytes = to_json_lines_bytes(
+            values,
+            &self.serializer,
+            &mut state,
+            ensure_ascii.unwrap_or(false),
+            self.expected_json_size.load(Ordering::Relaxed),
+        )?;
+
+        state.warnings.final_check(py)?;
+
+        let py_bytes = PyBytes::new(py, &bytes);
+        Ok(py_bytes.into())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (value, *, include = None, exclude = None, by_alias = None, exclude_unset = false,
+        exclude_defaults = false, exclude_none = false, exclude_computed_fields = false, round_trip = false,
+        warnings = WarningsArg::Bool(true), fallback = None, serialize_as_any = false, context = None))]
+    pub fn to_msgpack(
+        &self,
+        py: Python,
+        value: &Bound<'_, PyAny>,
+        include: Option<Bound<'_, PyAny>>,
+        exclude: Option<Bound<'_, PyAny>>,
+        by_alias: Option<bool>,
+        exclude_unset: bool,
+        exclude_defaults: bool,
+        exclude_none: bool,
+        exclude_computed_fields: bool,
+        round_trip: bool,
+        warnings: WarningsArg,
+        fallback: Option<&Bound<'_, PyAny>>,
+        serialize_as_any: bool,
+        context: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<Py<PyAny>> {
+        let warnings_mode = match warnings {
+            WarningsArg::Bool(b) => b.into(),
+            WarningsArg::Literal(mode) => mode,
+        };
+        let extra = Extra::new(
+            py,
+            &SerMode::Json,
+            by_alias,
+            exclude_unset,
+            exclude_defaults,
+            exclude_none,
+            exclude_computed_fields,
+            round_trip,
+            false,
+            fallback,
+            serialize_as_any,
+            context,
+        );
+        let mut state = SerializationState::new(self.config, warnings_mode, include, exclude, extra)?;
+        let bytes = to_msgpack_bytes(
+            value,
+            &self.serializer,
+            &mut state,
+            self.expected_json_size.load(Ordering::Relaxed),
+        )?;
+
+        state.warnings.final_check(py)?;
+
+        let py_bytes = PyBytes::new(py, &bytes);
+        Ok(py_bytes.into())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (value, *, include = None, exclude = None, by_alias = None, exclude_unset = false,
+        exclude_defaults = false, exclude_none = false, exclude_computed_fields = false, round_trip = false,
+        warnings = WarningsArg::Bool(true), fallback = None, serialize_as_any = false, context = None))]
+    pub fn to_cbor(
+        &self,
+        py: Python,
+        value: &Bound<'_, PyAny>,
+        include: Option<Bound<'_, PyAny>>,
+        exclude: Option<Bound<'_, PyAny>>,
+        by_alias: Option<bool>,
+        exclude_unset: bool,
+        exclude_defaults: bool,
+        exclude_none: bool,
+        exclude_computed_fields: bool,
+        round_trip: bool,
+        warnings: WarningsArg,
+        fallback: Option<&Bound<'_, PyAny>>,
+        serialize_as_any: bool,
+        context: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<Py<PyAny>> {
+        let warnings_mode = match warnings {
+            WarningsArg::Bool(b) => b.into(),
+            WarningsArg::Literal(mode) => mode,
+        };
+        let extra = Extra::new(
+            py,
+            &SerMode::Json,
+            by_alias,
+            exclude_unset,
+            exclude_defaults,
+            exclude_none,
+            exclude_computed_fields,
+            round_trip,
+            false,
+            fallback,
+            serialize_as_any,
+            context,
+        );
+        let mut state = SerializationState::new(self.config, warnings_mode, include, exclude, extra)?;
+        let bytes = to_cbor_bytes(
+            value,
+            &self.serializer,
+            &mut state,
+            self.expected_json_size.load(Ordering::Relaxed),
+        )?;
+
+        state.warnings.final_check(py)?;
+
+        let py_bytes = PyBytes::new(py, &bytes);
+        Ok(py_bytes.into())
+    }
+
+    /// Serialize `value` to JSON, re-validate it with `validator` and compare the result with `value`,
+    /// returns `None` if they're equal, otherwise the location of the first difference.
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (value, validator, *, by_alias = None, round_trip = true, serialize_as_any = false,
+        context = None, strict = None))]
+    pub fn check_roundtrip<'py>(
+        &self,
+        py: Python<'py>,
+        value: &Bound<'py, PyAny>,
+        validator: &Bound<'py, SchemaValidator>,
+        by_alias: Option<bool>,
+        round_trip: bool,
+        serialize_as_any: bool,
+        context: Option<&Bound<'py, PyAny>>,
+        strict: Option<bool>,
+    ) -> PyResult<Option<Bound<'py, PyTuple>>> {
+        let extra = Extra::new(
+            py,
+            &SerMode::Json,
+            by_alias,
+            false,
+            false,
+            false,
+            false,
+            round_trip,
+            false,
+            None,
+            serialize_as_any,
+            context,
+        );
+        let mut state = SerializationState::new(self.config, WarningsMode::Error, None, None, extra)?;
+        let bytes = to_json_bytes(
+            value,
+            &self.serializer,
+            &mut state,
+            &JsonFormat::default(),
+            self.expected_json_size.load(Ordering::Relaxed),
+        )?;
+        state.warnings.final_check(py)?;
+
+        let revalidated = validator.get().validate_json(
+            py,
+            PyBytes::new(py, &bytes).as_any(),
+            strict,
+            None,
+            context,
+            None,
+            PartialMode::Off,
+            by_alias,
+            // when serializing by field name, validate by field name too
+            by_alias.map(|by_alias| !by_alias),
+            false,
+            None,
+        )?;
+        first_divergence(value, revalidated.bind(py))?
+            .map(|location| location.into_pyobject(py))
+            .transpose()
+    }
+
     pub fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
         let init_args = (&slf.get().py_schema, &slf.get().py_config).into_pyobject(slf.py())?;
         Ok((slf.get_type(), init_args))
@@ -201,8 +470,9 @@ impl SchemaSerializer {
 #[pyfunction]
 #[pyo3(signature = (value, *, indent = None, ensure_ascii = false, include = None, exclude = None, by_alias = true,
     exclude_none = false, round_trip = false, timedelta_mode = "iso8601", temporal_mode = "iso8601",
-    bytes_mode = "utf8",  inf_nan_mode = "constants", serialize_unknown = false, fallback = None,
-    serialize_as_any = false, context = None))]
+    bytes_mode = "utf8",  inf_nan_mode = "constants", int_mode = "number", serialize_unknown = false,
+    fallback = None, serialize_as_any = false, context = None, fp = None, buffer_size = DEFAULT_FP_BUFFER_SIZE, canonical = false,
+    sort_keys = SortKeys::Off, separators = None, trailing_newline = false, inline_scalar_arrays = false))]
 pub fn to_json(
     py: Python,
     value: &Bound<'_, PyAny>,
@@ -217,12 +487,27 @@ pub fn to_json(
     temporal_mode: &str,
     bytes_mode: &str,
     inf_nan_mode: &str,
+    int_mode: &str,
     serialize_unknown: bool,
     fallback: Option<&Bound<'_, PyAny>>,
     serialize_as_any: bool,
     context: Option<&Bound<'_, PyAny>>,
+    fp: Option<&Bound<'_, PyAny>>,
+    buffer_size: usize,
+    canonical: bool,
+    sort_keys: SortKeys,
+    separators: Option<(String, String)>,
+    trailing_newline: bool,
+    inline_scalar_arrays: bool,
 ) -> PyResult<Py<PyAny>> {
-    let config = SerializationConfig::from_args(timedelta_mode, temporal_mode, bytes_mode, inf_nan_mode)?;
+    let config = SerializationConfig::from_args(
+        timedelta_mode,
+        temporal_mode,
+        bytes_mode,
+        inf_nan_mode,
+        int_mode,
+        sort_keys,
+    )?;
     let extra = Extra::new(
         py,
         &SerMode::Json,
@@ -238,14 +523,20 @@ pub fn to_json(
         context,
     );
     let mut state = SerializationState::new(config, WarningsMode::None, include, exclude, extra)?;
-    let bytes = to_json_bytes(
-        value,
-        AnySerializer::get(),
-        &mut state,
+    let format = JsonFormat {
         indent,
-        ensure_ascii.unwrap_or(false),
-        1024,
-    )?;
+        ensure_ascii: ensure_ascii.unwrap_or(false),
+        canonical,
+        separators,
+        trailing_newline,
+        inline_scalar_arrays,
+    };
+    if let Some(fp) = fp {
+        to_json_fp(value, AnySerializer::get(), &mut state, &format, fp, buffer_size)?;
+        state.final_check(py)?;
+        return Ok(py.None());
+    }
+    let bytes = to_json_bytes(value, AnySerializer::get(), &mut state, &format, 1024)?;
     state.final_check(py)?;
     let py_bytes = PyBytes::new(py, &bytes);
     Ok(py_bytes.into())
@@ -255,7 +546,7 @@ pub fn to_json(
 #[pyfunction]
 #[pyo3(signature = (value, *, include = None, exclude = None, by_alias = true, exclude_none = false, round_trip = false,
     timedelta_mode = "iso8601", temporal_mode = "iso8601", bytes_mode = "utf8", inf_nan_mode = "constants",
-    serialize_unknown = false, fallback = None, serialize_as_any = false, context = None))]
+    int_mode = "number", serialize_unknown = false, fallback = None, serialize_as_any = false, context = None, sort_keys = SortKeys::Off))]
 pub fn to_jsonable_python(
     py: Python,
     value: &Bound<'_, PyAny>,
@@ -268,12 +559,21 @@ pub fn to_jsonable_python(
     temporal_mode: &str,
     bytes_mode: &str,
     inf_nan_mode: &str,
+    int_mode: &str,
     serialize_unknown: bool,
     fallback: Option<&Bound<'_, PyAny>>,
     serialize_as_any: bool,
     context: Option<&Bound<'_, PyAny>>,
+    sort_keys: SortKeys,
 ) -> PyResult<Py<PyAny>> {
-    let config = SerializationConfig::from_args(timedelta_mode, temporal_mode, bytes_mode, inf_nan_mode)?;
+    let config = SerializationConfig::from_args(
+        timedelta_mode,
+        temporal_mode,
+        bytes_mode,
+        inf_nan_mode,
+        int_mode,
+        sort_keys,
+    )?;
     let extra = Extra::new(
         py,
         &SerMode::Json,
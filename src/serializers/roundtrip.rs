@@ -0,0 +1,80 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyTuple};
+
+use crate::errors::{LocItem, Location};
+
+/// Find the location of the first difference between `original` and `revalidated`, `None` means they're equal.
+///
+/// Dicts, lists, tuples and objects with a `__dict__` (e.g. models and dataclasses) are compared item by item
+/// so the location points at the innermost value which differs, otherwise the location is that of the value itself.
+pub(super) fn first_divergence(
+    original: &Bound<'_, PyAny>,
+    revalidated: &Bound<'_, PyAny>,
+) -> PyResult<Option<Location>> {
+    if original.eq(revalidated)? {
+        return Ok(None);
+    }
+
+    if let (Ok(original), Ok(revalidated)) = (original.downcast::<PyDict>(), revalidated.downcast::<PyDict>()) {
+        return dict_divergence(original, revalidated);
+    }
+    if let (Ok(original), Ok(revalidated)) = (original.downcast::<PyList>(), revalidated.downcast::<PyList>()) {
+        return items_divergence(original.iter(), revalidated.iter());
+    }
+    if let (Ok(original), Ok(revalidated)) = (original.downcast::<PyTuple>(), revalidated.downcast::<PyTuple>()) {
+        return items_divergence(original.iter(), revalidated.iter());
+    }
+
+    let py = original.py();
+    if original.get_type().is(revalidated.get_type()) {
+        let dict_attr = intern!(py, "__dict__");
+        if let (Ok(original), Ok(revalidated)) = (original.getattr(dict_attr), revalidated.getattr(dict_attr)) {
+            if let (Ok(original), Ok(revalidated)) = (original.downcast::<PyDict>(), revalidated.downcast::<PyDict>()) {
+                return dict_divergence(original, revalidated);
+            }
+        }
+    }
+    Ok(Some(Location::Empty))
+}
+
+fn dict_divergence(original: &Bound<'_, PyDict>, revalidated: &Bound<'_, PyDict>) -> PyResult<Option<Location>> {
+    for (key, original_value) in original {
+        let location = match revalidated.get_item(&key)? {
+            Some(revalidated_value) => first_divergence(&original_value, &revalidated_value)?,
+            None => Some(Location::Empty),
+        };
+        if let Some(mut location) = location {
+            location.with_outer(LocItem::from(key));
+            return Ok(Some(location));
+        }
+    }
+    for key in revalidated.keys() {
+        if !original.contains(&key)? {
+            return Ok(Some(Location::new_some(key.into())));
+        }
+    }
+    // all items are equal, but the dicts themselves are not, e.g. because of a custom `__eq__`
+    Ok(Some(Location::Empty))
+}
+
+fn items_divergence<'py>(
+    mut original: impl Iterator<Item = Bound<'py, PyAny>>,
+    mut revalidated: impl Iterator<Item = Bound<'py, PyAny>>,
+) -> PyResult<Option<Location>> {
+    let mut index: usize = 0;
+    loop {
+        match (original.next(), revalidated.next()) {
+            (Some(original_item), Some(revalidated_item)) => {
+                if let Some(mut location) = first_divergence(&original_item, &revalidated_item)? {
+                    location.with_outer(index.into());
+                    return Ok(Some(location));
+                }
+            }
+            // one sequence is longer than the other, the first missing item is where they diverge
+            (Some(_), None) | (None, Some(_)) => return Ok(Some(Location::new_some(index.into()))),
+            (None, None) => return Ok(Some(Location::Empty)),
+        }
+        index += 1;
+    }
+}
@@ -15,7 +15,8 @@ macro_rules! tri {
 }
 
 type Result<T> = std::result::Result<T, PythonSerializerError>;
-const TOKEN: &str = "$serde_json::private::Number";
+/// Struct name and field serde_json uses to serialize a `Number` as its raw text
+pub(crate) const TOKEN: &str = "$serde_json::private::Number";
 pub struct PythonSerializer<W, F = CompactFormatter> {
     writer: W,
     formatter: F,
@@ -1283,3 +1284,186 @@ impl<W: io::Write, F: Formatter> serde::ser::Serializer for NumberStrEmitter<'_,
         Err(invalid_number())
     }
 }
+
+/// Formatter with configurable separators which can optionally keep arrays of scalars on one line
+/// when indenting, `scalar` writes everything other than the structure of arrays and objects.
+pub struct ConfigurableFormatter<'a, S = CompactFormatter> {
+    scalar: S,
+    indent: Option<&'a [u8]>,
+    item_separator: &'a str,
+    key_separator: &'a str,
+    inline_scalar_arrays: bool,
+    current_indent: usize,
+    has_value: bool,
+    /// Elements of the innermost array, buffered until we know whether they're all scalars
+    pending_array: Option<Vec<Vec<u8>>>,
+}
+
+impl<'a, S: Formatter> ConfigurableFormatter<'a, S> {
+    /// `inline_scalar_arrays` writes arrays which only contain scalars on one line, it only has an effect
+    /// when indenting.
+    pub fn new(
+        scalar: S,
+        indent: Option<&'a [u8]>,
+        item_separator: &'a str,
+        key_separator: &'a str,
+        inline_scalar_arrays: bool,
+    ) -> Self {
+        Self {
+            scalar,
+            indent,
+            item_separator,
+            key_separator,
+            inline_scalar_arrays,
+            current_indent: 0,
+            has_value: false,
+            pending_array: None,
+        }
+    }
+
+    /// Write the separator before an array element or object key, trailing whitespace in the
+    /// separator is dropped before a newline.
+    fn write_item_separator<W: ?Sized + io::Write>(&self, writer: &mut W, first: bool) -> io::Result<()> {
+        match self.indent {
+            Some(indent) => {
+                if !first {
+                    tri!(writer.write_all(self.item_separator.trim_end().as_bytes()));
+                }
+                tri!(writer.write_all(b"\n"));
+                write_indent(writer, self.current_indent, indent)
+            }
+            None if first => Ok(()),
+            None => writer.write_all(self.item_separator.as_bytes()),
+        }
+    }
+
+    /// An array or object is starting inside the pending array, so it has to be written over multiple lines,
+    /// write the elements so far, the last of which is the container that's starting.
+    fn flush_pending_array<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        let Some(elements) = self.pending_array.take() else {
+            return Ok(());
+        };
+        tri!(writer.write_all(b"["));
+        for (index, element) in elements.iter().enumerate() {
+            tri!(self.write_item_separator(writer, index == 0));
+            tri!(writer.write_all(element));
+        }
+        Ok(())
+    }
+}
+
+fn write_indent<W: ?Sized + io::Write>(writer: &mut W, n: usize, indent: &[u8]) -> io::Result<()> {
+    for _ in 0..n {
+        tri!(writer.write_all(indent));
+    }
+    Ok(())
+}
+
+/// Write a scalar via `self.scalar`, into the current element of the pending array if there is one.
+macro_rules! scalar {
+    ($fun:ident $(, $arg:ident: $ty:ty)?) => {
+        fn $fun<W: ?Sized + io::Write>(&mut self, writer: &mut W $(, $arg: $ty)?) -> io::Result<()> {
+            match self.pending_array.as_mut().and_then(|elements| elements.last_mut()) {
+                Some(element) => self.scalar.$fun(element $(, $arg)?),
+                None => self.scalar.$fun(writer $(, $arg)?),
+            }
+        }
+    };
+}
+
+impl<S: Formatter> Formatter for ConfigurableFormatter<'_, S> {
+    scalar!(write_null);
+    scalar!(write_bool, value: bool);
+    scalar!(write_i8, value: i8);
+    scalar!(write_i16, value: i16);
+    scalar!(write_i32, value: i32);
+    scalar!(write_i64, value: i64);
+    scalar!(write_i128, value: i128);
+    scalar!(write_u8, value: u8);
+    scalar!(write_u16, value: u16);
+    scalar!(write_u32, value: u32);
+    scalar!(write_u64, value: u64);
+    scalar!(write_u128, value: u128);
+    scalar!(write_f32, value: f32);
+    scalar!(write_f64, value: f64);
+    scalar!(write_number_str, value: &str);
+    scalar!(begin_string);
+    scalar!(end_string);
+    scalar!(write_string_fragment, fragment: &str);
+    scalar!(write_char_escape, char_escape: serde_json::ser::CharEscape);
+    scalar!(write_byte_array, value: &[u8]);
+    scalar!(write_raw_fragment, fragment: &str);
+
+    fn begin_array<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        tri!(self.flush_pending_array(writer));
+        self.current_indent += 1;
+        self.has_value = false;
+        if self.inline_scalar_arrays && self.indent.is_some() {
+            self.pending_array = Some(Vec::new());
+            Ok(())
+        } else {
+            writer.write_all(b"[")
+        }
+    }
+
+    fn end_array<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.current_indent -= 1;
+        if let Some(elements) = self.pending_array.take() {
+            tri!(writer.write_all(b"["));
+            for (index, element) in elements.iter().enumerate() {
+                if index > 0 {
+                    tri!(writer.write_all(self.item_separator.as_bytes()));
+                }
+                tri!(writer.write_all(element));
+            }
+        } else if let (Some(indent), true) = (self.indent, self.has_value) {
+            tri!(writer.write_all(b"\n"));
+            tri!(write_indent(writer, self.current_indent, indent));
+        }
+        writer.write_all(b"]")
+    }
+
+    fn begin_array_value<W: ?Sized + io::Write>(&mut self, writer: &mut W, first: bool) -> io::Result<()> {
+        match self.pending_array.as_mut() {
+            Some(elements) => {
+                elements.push(Vec::new());
+                Ok(())
+            }
+            None => self.write_item_separator(writer, first),
+        }
+    }
+
+    fn end_array_value<W: ?Sized + io::Write>(&mut self, _writer: &mut W) -> io::Result<()> {
+        self.has_value = true;
+        Ok(())
+    }
+
+    fn begin_object<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        tri!(self.flush_pending_array(writer));
+        self.current_indent += 1;
+        self.has_value = false;
+        writer.write_all(b"{")
+    }
+
+    fn end_object<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.current_indent -= 1;
+        if let (Some(indent), true) = (self.indent, self.has_value) {
+            tri!(writer.write_all(b"\n"));
+            tri!(write_indent(writer, self.current_indent, indent));
+        }
+        writer.write_all(b"}")
+    }
+
+    fn begin_object_key<W: ?Sized + io::Write>(&mut self, writer: &mut W, first: bool) -> io::Result<()> {
+        self.write_item_separator(writer, first)
+    }
+
+    fn begin_object_value<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(self.key_separator.as_bytes())
+    }
+
+    fn end_object_value<W: ?Sized + io::Write>(&mut self, _writer: &mut W) -> io::Result<()> {
+        self.has_value = true;
+        Ok(())
+    }
+}
@@ -4,26 +4,28 @@ use std::fmt::Debug;
 use std::io::{self, Write};
 use std::sync::Arc;
 
-use pyo3::exceptions::PyTypeError;
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::sync::PyOnceLock;
-use pyo3::types::{PyDict, PyString};
+use pyo3::types::{PyBytes, PyDict, PyString};
 use pyo3::{intern, PyTraverseError, PyVisit};
 use pyo3::{prelude::*, IntoPyObjectExt};
 
 use enum_dispatch::enum_dispatch;
 use serde::{Serialize, Serializer};
-use serde_json::ser::{Formatter, PrettyFormatter};
+use serde_json::ser::{CompactFormatter, Formatter, PrettyFormatter};
 
 use crate::build_tools::py_schema_err;
 use crate::build_tools::py_schema_error_type;
 use crate::definitions::DefinitionsBuilder;
 use crate::py_gc::PyGcTraverse;
 use crate::serializers::errors::WrappedSerError;
-use crate::serializers::ser::PythonSerializer;
+use crate::serializers::ser::{ConfigurableFormatter, PythonSerializer};
 use crate::serializers::type_serializers::any::AnySerializer;
 use crate::tools::{py_err, SchemaDict};
 
-use super::errors::se_err_py_err;
+use super::cbor::CborSerializer;
+use super::config::SortKeys;
+use super::errors::{cbor_se_err_py_err, msgpack_se_err_py_err, se_err_py_err};
 use super::extra::SerializationState;
 use super::infer::{infer_json_key, infer_serialize, infer_to_python};
 use super::ob_type::{IsType, ObType};
@@ -534,47 +536,342 @@ impl Formatter for EscapeNonAsciiPrettyFormatter<'_> {
     defer!(pretty, end_object_value);
 }
 
+/// Formatter for [RFC 8785](https://www.rfc-editor.org/rfc/rfc8785) canonical JSON, numbers are written
+/// as ECMAScript would write the equivalent IEEE 754 double, combine with sorted keys and compact output.
+struct CanonicalFormatter;
+
+/// Integers beyond this can't be represented exactly by a double
+const MAX_SAFE_INTEGER: u64 = 1 << 53;
+
+impl CanonicalFormatter {
+    fn write_int<W: ?Sized + Write>(writer: &mut W, value: i128) -> io::Result<()> {
+        if value.unsigned_abs() <= u128::from(MAX_SAFE_INTEGER) {
+            write!(writer, "{value}")
+        } else {
+            #[allow(clippy::cast_precision_loss)]
+            writer.write_all(ecmascript_number(value as f64).as_bytes())
+        }
+    }
+}
+
+impl Formatter for CanonicalFormatter {
+    fn write_i64<W: ?Sized + Write>(&mut self, writer: &mut W, value: i64) -> io::Result<()> {
+        Self::write_int(writer, i128::from(value))
+    }
+
+    fn write_i128<W: ?Sized + Write>(&mut self, writer: &mut W, value: i128) -> io::Result<()> {
+        Self::write_int(writer, value)
+    }
+
+    fn write_u64<W: ?Sized + Write>(&mut self, writer: &mut W, value: u64) -> io::Result<()> {
+        Self::write_int(writer, i128::from(value))
+    }
+
+    fn write_u128<W: ?Sized + Write>(&mut self, writer: &mut W, value: u128) -> io::Result<()> {
+        if value <= u128::from(MAX_SAFE_INTEGER) {
+            write!(writer, "{value}")
+        } else {
+            #[allow(clippy::cast_precision_loss)]
+            writer.write_all(ecmascript_number(value as f64).as_bytes())
+        }
+    }
+
+    fn write_f32<W: ?Sized + Write>(&mut self, writer: &mut W, value: f32) -> io::Result<()> {
+        writer.write_all(ecmascript_number(f64::from(value)).as_bytes())
+    }
+
+    fn write_f64<W: ?Sized + Write>(&mut self, writer: &mut W, value: f64) -> io::Result<()> {
+        writer.write_all(ecmascript_number(value).as_bytes())
+    }
+
+    fn write_number_str<W: ?Sized + Write>(&mut self, writer: &mut W, value: &str) -> io::Result<()> {
+        // big ints, and the `NaN`/`Infinity` constants which canonical JSON has no representation for
+        match value.parse::<f64>() {
+            Ok(f) if f.is_finite() => writer.write_all(ecmascript_number(f).as_bytes()),
+            _ => Err(io::Error::other(format!(
+                "`{value}` is not permitted in canonical JSON"
+            ))),
+        }
+    }
+}
+
+/// Format a finite double as ECMAScript's `Number.prototype.toString()` would, see
+/// [RFC 8785 section 3.2.2.3](https://www.rfc-editor.org/rfc/rfc8785#section-3.2.2.3).
+fn ecmascript_number(value: f64) -> String {
+    if value == 0.0 {
+        // including negative zero
+        return "0".to_string();
+    }
+    // rust's exponent formatting gives the shortest digits which round trip, e.g. `-1.2345e-7`
+    let scientific = format!("{value:e}");
+    let (mantissa, exponent) = scientific.split_once('e').unwrap_or((&scientific, "0"));
+    let exponent: i32 = exponent.parse().unwrap_or(0);
+    let (sign, mantissa) = match mantissa.strip_prefix('-') {
+        Some(mantissa) => ("-", mantissa),
+        None => ("", mantissa),
+    };
+    let digits: String = mantissa.chars().filter(char::is_ascii_digit).collect();
+    // in the terms of the ECMAScript spec, value = digits × 10^(n - k)
+    let k = i32::try_from(digits.len()).unwrap_or(i32::MAX);
+    let n = exponent + 1;
+
+    let body = if k <= n && n <= 21 {
+        format!("{digits}{}", "0".repeat((n - k).unsigned_abs() as usize))
+    } else if 0 < n && n <= 21 {
+        let (int_part, frac_part) = digits.split_at(n.unsigned_abs() as usize);
+        format!("{int_part}.{frac_part}")
+    } else if -6 < n && n <= 0 {
+        format!("0.{}{digits}", "0".repeat(n.unsigned_abs() as usize))
+    } else {
+        let exponent_sign = if n - 1 < 0 { '-' } else { '+' };
+        let (first, rest) = digits.split_at(1);
+        let mantissa = if rest.is_empty() {
+            first.to_string()
+        } else {
+            format!("{first}.{rest}")
+        };
+        format!("{mantissa}e{exponent_sign}{}", (n - 1).unsigned_abs())
+    };
+    format!("{sign}{body}")
+}
+
+/// Length to pass to `serialize_seq` or `serialize_map` when include/exclude filtering might drop items.
+/// Binary formats like MessagePack write the length before the items, so there we pass `None` and let
+/// them count the items actually written.
+pub(crate) fn serde_len_hint<S: Serializer>(serializer: &S, len: usize) -> Option<usize> {
+    serializer.is_human_readable().then_some(len)
+}
+
+/// Options controlling how JSON output is written, as opposed to which data is serialized.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct JsonFormat {
+    pub indent: Option<usize>,
+    pub ensure_ascii: bool,
+    /// RFC 8785 canonical output, see `CanonicalFormatter`
+    pub canonical: bool,
+    /// `(item_separator, key_separator)`, defaults to `(", ", ": ")` with `indent` and `(",", ":")` without
+    pub separators: Option<(String, String)>,
+    pub trailing_newline: bool,
+    /// with `indent`, write arrays which only contain scalars on one line
+    pub inline_scalar_arrays: bool,
+}
+
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn to_json_bytes<'py>(
     value: &Bound<'py, PyAny>,
     serializer: &CombinedSerializer,
     state: &mut SerializationState<'_, 'py>,
-    indent: Option<usize>,
-    ensure_ascii: bool,
+    format: &JsonFormat,
     expected_json_size: usize,
 ) -> PyResult<Vec<u8>> {
-    let serializer = PydanticSerializer::new(value, serializer, state);
-
     let writer: Vec<u8> = Vec::with_capacity(expected_json_size);
+    to_json_writer(value, serializer, state, format, writer)
+}
+
+/// Serialize `value` as JSON to the Python file-like object `fp`, calling `fp.write()` each time `buffer_size`
+/// bytes have been buffered rather than building the whole output in memory.
+pub(crate) fn to_json_fp<'py>(
+    value: &Bound<'py, PyAny>,
+    serializer: &CombinedSerializer,
+    state: &mut SerializationState<'_, 'py>,
+    format: &JsonFormat,
+    fp: &Bound<'py, PyAny>,
+    buffer_size: usize,
+) -> PyResult<()> {
+    let mut writer = PyWriter::new(fp, buffer_size)?;
+    let result = to_json_writer(value, serializer, state, format, &mut writer).map(|_| ());
+    // an error raised by `fp.write()` takes precedence over the generic IO error it caused
+    if let Some(err) = writer.error.take() {
+        return Err(err);
+    }
+    result?;
+    writer.write_buffer()
+}
+
+fn to_json_writer<'py, W: Write>(
+    value: &Bound<'py, PyAny>,
+    serializer: &CombinedSerializer,
+    state: &mut SerializationState<'_, 'py>,
+    format: &JsonFormat,
+    writer: W,
+) -> PyResult<W> {
+    if format.canonical {
+        if format.indent.is_some() || format.ensure_ascii {
+            return py_err!(PyValueError; "`canonical` cannot be combined with `indent` or `ensure_ascii`");
+        }
+        if format.separators.is_some() {
+            return py_err!(PyValueError; "`canonical` cannot be combined with `separators`");
+        }
+        state.config.sort_keys = SortKeys::Canonical;
+    }
+    let serializer = PydanticSerializer::new(value, serializer, state);
 
-    let bytes = match (indent, ensure_ascii) {
-        (Some(indent), true) => {
-            let indent = vec![b' '; indent];
-            let formatter = EscapeNonAsciiPrettyFormatter::with_indent(&indent);
+    let mut writer = if format.canonical {
+        let mut ser = PythonSerializer::with_formatter(writer, CanonicalFormatter);
+        serializer.serialize(&mut ser).map_err(se_err_py_err)?;
+        ser.into_inner()
+    } else if format.separators.is_some() || format.inline_scalar_arrays {
+        let indent = format.indent.map(|indent| vec![b' '; indent]);
+        let (item_separator, key_separator) = match (&format.separators, indent.is_some()) {
+            (Some((item, key)), _) => (item.as_str(), key.as_str()),
+            (None, true) => (", ", ": "),
+            (None, false) => (",", ":"),
+        };
+        let indent = indent.as_deref();
+        let inline = format.inline_scalar_arrays;
+        if format.ensure_ascii {
+            let formatter =
+                ConfigurableFormatter::new(EscapeNonAsciiFormatter, indent, item_separator, key_separator, inline);
             let mut ser = PythonSerializer::with_formatter(writer, formatter);
             serializer.serialize(&mut ser).map_err(se_err_py_err)?;
             ser.into_inner()
-        }
-        (Some(indent), false) => {
-            let indent = vec![b' '; indent];
-            let formatter = PrettyFormatter::with_indent(&indent);
+        } else {
+            let formatter = ConfigurableFormatter::new(CompactFormatter, indent, item_separator, key_separator, inline);
             let mut ser = PythonSerializer::with_formatter(writer, formatter);
             serializer.serialize(&mut ser).map_err(se_err_py_err)?;
             ser.into_inner()
         }
-        (None, true) => {
+    } else {
+        match (format.indent, format.ensure_ascii) {
+            (Some(indent), true) => {
+                let indent = vec![b' '; indent];
+                let formatter = EscapeNonAsciiPrettyFormatter::with_indent(&indent);
+                let mut ser = PythonSerializer::with_formatter(writer, formatter);
+                serializer.serialize(&mut ser).map_err(se_err_py_err)?;
+                ser.into_inner()
+            }
+            (Some(indent), false) => {
+                let indent = vec![b' '; indent];
+                let formatter = PrettyFormatter::with_indent(&indent);
+                let mut ser = PythonSerializer::with_formatter(writer, formatter);
+                serializer.serialize(&mut ser).map_err(se_err_py_err)?;
+                ser.into_inner()
+            }
+            (None, true) => {
+                let mut ser = PythonSerializer::with_formatter(writer, EscapeNonAsciiFormatter);
+                serializer.serialize(&mut ser).map_err(se_err_py_err)?;
+                ser.into_inner()
+            }
+            (None, false) => {
+                let mut ser = PythonSerializer::new(writer);
+                serializer.serialize(&mut ser).map_err(se_err_py_err)?;
+                ser.into_inner()
+            }
+        }
+    };
+
+    if format.trailing_newline {
+        writer.write_all(b"\n")?;
+    }
+    Ok(writer)
+}
+
+/// Buffers output and passes it to a Python file-like object's `write()` method in chunks,
+/// as `str` for text files (`io.TextIOBase` instances) and `bytes` otherwise.
+struct PyWriter<'a, 'py> {
+    fp: &'a Bound<'py, PyAny>,
+    text: bool,
+    buffer: Vec<u8>,
+    buffer_size: usize,
+    error: Option<PyErr>,
+}
+
+impl<'a, 'py> PyWriter<'a, 'py> {
+    fn new(fp: &'a Bound<'py, PyAny>, buffer_size: usize) -> PyResult<Self> {
+        let py = fp.py();
+        let text_io_base = py.import(intern!(py, "io"))?.getattr(intern!(py, "TextIOBase"))?;
+        Ok(Self {
+            fp,
+            text: fp.is_instance(&text_io_base)?,
+            buffer: Vec::with_capacity(buffer_size),
+            buffer_size,
+            error: None,
+        })
+    }
+
+    fn write_buffer(&mut self) -> PyResult<()> {
+        let py = self.fp.py();
+        let written = if self.text {
+            // don't split a multi-byte character between writes, the rest of it is still to come
+            let valid_up_to = std::str::from_utf8(&self.buffer).map_or_else(|e| e.valid_up_to(), str::len);
+            let chunk = String::from_utf8_lossy(&self.buffer[..valid_up_to]);
+            self.fp.call_method1(intern!(py, "write"), (chunk,))?;
+            valid_up_to
+        } else {
+            self.fp
+                .call_method1(intern!(py, "write"), (PyBytes::new(py, &self.buffer),))?;
+            self.buffer.len()
+        };
+        self.buffer.drain(..written);
+        Ok(())
+    }
+}
+
+impl Write for PyWriter<'_, '_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buffer.extend_from_slice(buf);
+        if self.buffer.len() >= self.buffer_size {
+            if let Err(err) = self.write_buffer() {
+                self.error = Some(err);
+                return Err(io::Error::other("error calling `fp.write()`"));
+            }
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Serialize each item of `values` as compact JSON followed by a newline, i.e. as [JSON Lines](https://jsonlines.org/).
+pub(crate) fn to_json_lines_bytes<'py>(
+    values: &Bound<'py, PyAny>,
+    serializer: &CombinedSerializer,
+    state: &mut SerializationState<'_, 'py>,
+    ensure_ascii: bool,
+    expected_json_size: usize,
+) -> PyResult<Vec<u8>> {
+    let mut writer: Vec<u8> = Vec::with_capacity(expected_json_size);
+    for value in values.try_iter()? {
+        let value = value?;
+        let serializer = PydanticSerializer::new(&value, serializer, state);
+        writer = if ensure_ascii {
             let mut ser = PythonSerializer::with_formatter(writer, EscapeNonAsciiFormatter);
             serializer.serialize(&mut ser).map_err(se_err_py_err)?;
             ser.into_inner()
-        }
-        (None, false) => {
+        } else {
             let mut ser = PythonSerializer::new(writer);
             serializer.serialize(&mut ser).map_err(se_err_py_err)?;
             ser.into_inner()
-        }
-    };
+        };
+        writer.push(b'\n');
+    }
+    Ok(writer)
+}
 
-    Ok(bytes)
+pub(crate) fn to_msgpack_bytes<'py>(
+    value: &Bound<'py, PyAny>,
+    serializer: &CombinedSerializer,
+    state: &mut SerializationState<'_, 'py>,
+    expected_size: usize,
+) -> PyResult<Vec<u8>> {
+    let serializer = PydanticSerializer::new(value, serializer, state);
+    let mut ser = rmp_serde::Serializer::new(Vec::with_capacity(expected_size));
+    serializer.serialize(&mut ser).map_err(msgpack_se_err_py_err)?;
+    Ok(ser.into_inner())
+}
+
+pub(crate) fn to_cbor_bytes<'py>(
+    value: &Bound<'py, PyAny>,
+    serializer: &CombinedSerializer,
+    state: &mut SerializationState<'_, 'py>,
+    expected_size: usize,
+) -> PyResult<Vec<u8>> {
+    let serializer = PydanticSerializer::new(value, serializer, state);
+    let mut ser = CborSerializer::new(Vec::with_capacity(expected_size));
+    serializer.serialize(&mut ser).map_err(cbor_se_err_py_err)?;
+    Ok(ser.into_inner())
 }
 
 #[allow(clippy::type_complexity)]
@@ -0,0 +1,648 @@
+use std::marker::PhantomData;
+
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+use serde::ser::{
+    Error, Serialize, SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTuple,
+    SerializeTupleStruct, SerializeTupleVariant, Serializer,
+};
+
+use super::config::SortKeys;
+
+/// Return a copy of `dict` with its keys in sorted order, used for `sort_keys` in python mode.
+///
+/// Keys which can't be compared with each other, e.g. `1` and `'a'`, are instead ordered by the name
+/// of their type, then by `str()`.
+pub(crate) fn sort_dict<'py>(dict: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+    let py = dict.py();
+    let keys: Bound<'_, PyList> = dict.keys();
+    let keys: Vec<Bound<'_, PyAny>> = match keys.sort() {
+        Ok(()) => keys.iter().collect(),
+        Err(err) if err.is_instance_of::<PyTypeError>(py) => {
+            let mut keyed = keys
+                .iter()
+                .map(|key| Ok(((key.get_type().name()?.to_string(), key.str()?.to_string()), key)))
+                .collect::<PyResult<Vec<_>>>()?;
+            keyed.sort_by(|(a, _), (b, _)| a.cmp(b));
+            keyed.into_iter().map(|(_, key)| key).collect()
+        }
+        Err(err) => return Err(err),
+    };
+    let sorted_dict = PyDict::new(py);
+    for key in keys {
+        if let Some(value) = dict.get_item(&key)? {
+            sorted_dict.set_item(key, value)?;
+        }
+    }
+    Ok(sorted_dict)
+}
+
+/// A `SerializeMap` which, when sorting is enabled, buffers its entries and writes them ordered by key
+/// when the map ends.
+///
+/// Values are buffered as `Content` rather than as output bytes so this works with any serializer,
+/// entries with string keys are sorted by code point to match python's ordering of `str`, or by UTF-16
+/// code unit for canonical JSON.
+pub(crate) enum SortedMap<M> {
+    Unsorted(M),
+    Sorted {
+        map: M,
+        entries: Vec<(Content, Content)>,
+        human_readable: bool,
+        canonical: bool,
+    },
+}
+
+impl<M: SerializeMap> SortedMap<M> {
+    pub fn start<S>(serializer: S, len: Option<usize>, sort_keys: SortKeys) -> Result<Self, S::Error>
+    where
+        S: Serializer<SerializeMap = M, Ok = M::Ok, Error = M::Error>,
+    {
+        let human_readable = serializer.is_human_readable();
+        let map = serializer.serialize_map(len)?;
+        if sort_keys.enabled() {
+            Ok(Self::Sorted {
+                map,
+                entries: Vec::with_capacity(len.unwrap_or_default()),
+                human_readable,
+                canonical: sort_keys == SortKeys::Canonical,
+            })
+        } else {
+            Ok(Self::Unsorted(map))
+        }
+    }
+}
+
+impl<M: SerializeMap> SerializeMap for SortedMap<M> {
+    type Ok = M::Ok;
+    type Error = M::Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Self::Error> {
+        match self {
+            Self::Unsorted(map) => map.serialize_key(key),
+            Self::Sorted {
+                entries,
+                human_readable,
+                ..
+            } => {
+                let key = key.serialize(ContentSerializer::new(*human_readable))?;
+                entries.push((key, Content::Unit));
+                Ok(())
+            }
+        }
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        match self {
+            Self::Unsorted(map) => map.serialize_value(value),
+            Self::Sorted {
+                entries,
+                human_readable,
+                ..
+            } => {
+                let content = value.serialize(ContentSerializer::new(*human_readable))?;
+                match entries.last_mut() {
+                    Some((_, slot)) => *slot = content,
+                    None => return Err(Error::custom("serialize_value called before serialize_key")),
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn serialize_entry<K, V>(&mut self, key: &K, value: &V) -> Result<(), Self::Error>
+    where
+        K: Serialize + ?Sized,
+        V: Serialize + ?Sized,
+    {
+        match self {
+            Self::Unsorted(map) => map.serialize_entry(key, value),
+            Self::Sorted {
+                entries,
+                human_readable,
+                ..
+            } => {
+                let key = key.serialize(ContentSerializer::new(*human_readable))?;
+                let value = value.serialize(ContentSerializer::new(*human_readable))?;
+                entries.push((key, value));
+                Ok(())
+            }
+        }
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        match self {
+            Self::Unsorted(map) => map.end(),
+            Self::Sorted {
+                mut map,
+                mut entries,
+                canonical,
+                ..
+            } => {
+                if canonical {
+                    // RFC 8785 compares keys as UTF-16 code units
+                    entries.sort_by(|(a, _), (b, _)| {
+                        let a = a.as_str().unwrap_or_default().encode_utf16();
+                        a.cmp(b.as_str().unwrap_or_default().encode_utf16())
+                    });
+                    // distinct python keys can serialize to the same JSON key, e.g. `1` and `'1'`
+                    if let Some(pair) = entries.windows(2).find(|pair| pair[0].0.as_str() == pair[1].0.as_str()) {
+                        return Err(Error::custom(format!(
+                            "Duplicate key `{}` is not permitted in canonical JSON",
+                            pair[0].0.as_str().unwrap_or_default()
+                        )));
+                    }
+                } else {
+                    entries.sort_by(|(a, _), (b, _)| a.as_str().cmp(&b.as_str()));
+                }
+                for (key, value) in &entries {
+                    map.serialize_entry(key, value)?;
+                }
+                map.end()
+            }
+        }
+    }
+}
+
+/// An owned copy of the calls made to a `Serializer`, which can be replayed later by serializing it.
+pub(crate) enum Content {
+    Bool(bool),
+    I64(i64),
+    I128(i128),
+    U64(u64),
+    U128(u128),
+    F32(f32),
+    F64(f64),
+    Char(char),
+    String(String),
+    Bytes(Vec<u8>),
+    None,
+    Some(Box<Content>),
+    Unit,
+    UnitStruct(&'static str),
+    UnitVariant(&'static str, u32, &'static str),
+    NewtypeStruct(&'static str, Box<Content>),
+    NewtypeVariant(&'static str, u32, &'static str, Box<Content>),
+    Seq(Option<usize>, Vec<Content>),
+    Tuple(Vec<Content>),
+    TupleStruct(&'static str, Vec<Content>),
+    TupleVariant(&'static str, u32, &'static str, Vec<Content>),
+    Map(Option<usize>, Vec<(Content, Content)>),
+    Struct(&'static str, Vec<(&'static str, Content)>),
+    StructVariant(&'static str, u32, &'static str, Vec<(&'static str, Content)>),
+}
+
+impl Content {
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+impl Serialize for Content {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Bool(v) => serializer.serialize_bool(*v),
+            Self::I64(v) => serializer.serialize_i64(*v),
+            Self::I128(v) => serializer.serialize_i128(*v),
+            Self::U64(v) => serializer.serialize_u64(*v),
+            Self::U128(v) => serializer.serialize_u128(*v),
+            Self::F32(v) => serializer.serialize_f32(*v),
+            Self::F64(v) => serializer.serialize_f64(*v),
+            Self::Char(v) => serializer.serialize_char(*v),
+            Self::String(v) => serializer.serialize_str(v),
+            Self::Bytes(v) => serializer.serialize_bytes(v),
+            Self::None => serializer.serialize_none(),
+            Self::Some(v) => serializer.serialize_some(v),
+            Self::Unit => serializer.serialize_unit(),
+            Self::UnitStruct(name) => serializer.serialize_unit_struct(name),
+            Self::UnitVariant(name, index, variant) => serializer.serialize_unit_variant(name, *index, variant),
+            Self::NewtypeStruct(name, v) => serializer.serialize_newtype_struct(name, v),
+            Self::NewtypeVariant(name, index, variant, v) => {
+                serializer.serialize_newtype_variant(name, *index, variant, v)
+            }
+            Self::Seq(len, items) => {
+                let mut seq = serializer.serialize_seq(*len)?;
+                for item in items {
+                    seq.serialize_element(item)?;
+                }
+                seq.end()
+            }
+            Self::Tuple(items) => {
+                let mut tuple = serializer.serialize_tuple(items.len())?;
+                for item in items {
+                    tuple.serialize_element(item)?;
+                }
+                tuple.end()
+            }
+            Self::TupleStruct(name, items) => {
+                let mut tuple = serializer.serialize_tuple_struct(name, items.len())?;
+                for item in items {
+                    tuple.serialize_field(item)?;
+                }
+                tuple.end()
+            }
+            Self::TupleVariant(name, index, variant, items) => {
+                let mut tuple = serializer.serialize_tuple_variant(name, *index, variant, items.len())?;
+                for item in items {
+                    tuple.serialize_field(item)?;
+                }
+                tuple.end()
+            }
+            Self::Map(len, entries) => {
+                let mut map = serializer.serialize_map(*len)?;
+                for (key, value) in entries {
+                    map.serialize_entry(key, value)?;
+                }
+                map.end()
+            }
+            Self::Struct(name, fields) => {
+                let mut s = serializer.serialize_struct(name, fields.len())?;
+                for (key, value) in fields {
+                    s.serialize_field(key, value)?;
+                }
+                s.end()
+            }
+            Self::StructVariant(name, index, variant, fields) => {
+                let mut s = serializer.serialize_struct_variant(name, *index, variant, fields.len())?;
+                for (key, value) in fields {
+                    s.serialize_field(key, value)?;
+                }
+                s.end()
+            }
+        }
+    }
+}
+
+/// Serializer which records everything it's given as `Content`, `E` is the error type of the
+/// serializer the content will eventually be written to.
+struct ContentSerializer<E> {
+    human_readable: bool,
+    error: PhantomData<E>,
+}
+
+impl<E> ContentSerializer<E> {
+    fn new(human_readable: bool) -> Self {
+        Self {
+            human_readable,
+            error: PhantomData,
+        }
+    }
+
+    fn content<T: Serialize + ?Sized>(&self, value: &T) -> Result<Box<Content>, E>
+    where
+        E: Error,
+    {
+        value.serialize(Self::new(self.human_readable)).map(Box::new)
+    }
+}
+
+impl<E: Error> Serializer for ContentSerializer<E> {
+    type Ok = Content;
+    type Error = E;
+    type SerializeSeq = SeqBuilder<E>;
+    type SerializeTuple = SeqBuilder<E>;
+    type SerializeTupleStruct = SeqBuilder<E>;
+    type SerializeTupleVariant = SeqBuilder<E>;
+    type SerializeMap = MapBuilder<E>;
+    type SerializeStruct = StructBuilder<E>;
+    type SerializeStructVariant = StructBuilder<E>;
+
+    fn is_human_readable(&self) -> bool {
+        self.human_readable
+    }
+
+    fn serialize_bool(self, v: bool) -> Result<Content, E> {
+        Ok(Content::Bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Content, E> {
+        Ok(Content::I64(v.into()))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Content, E> {
+        Ok(Content::I64(v.into()))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Content, E> {
+        Ok(Content::I64(v.into()))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Content, E> {
+        Ok(Content::I64(v))
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<Content, E> {
+        Ok(Content::I128(v))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Content, E> {
+        Ok(Content::U64(v.into()))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Content, E> {
+        Ok(Content::U64(v.into()))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Content, E> {
+        Ok(Content::U64(v.into()))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Content, E> {
+        Ok(Content::U64(v))
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Content, E> {
+        Ok(Content::U128(v))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Content, E> {
+        Ok(Content::F32(v))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Content, E> {
+        Ok(Content::F64(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Content, E> {
+        Ok(Content::Char(v))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Content, E> {
+        Ok(Content::String(v.to_owned()))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Content, E> {
+        Ok(Content::Bytes(v.to_owned()))
+    }
+
+    fn serialize_none(self) -> Result<Content, E> {
+        Ok(Content::None)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Content, E> {
+        Ok(Content::Some(self.content(value)?))
+    }
+
+    fn serialize_unit(self) -> Result<Content, E> {
+        Ok(Content::Unit)
+    }
+
+    fn serialize_unit_struct(self, name: &'static str) -> Result<Content, E> {
+        Ok(Content::UnitStruct(name))
+    }
+
+    fn serialize_unit_variant(self, name: &'static str, index: u32, variant: &'static str) -> Result<Content, E> {
+        Ok(Content::UnitVariant(name, index, variant))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(self, name: &'static str, value: &T) -> Result<Content, E> {
+        Ok(Content::NewtypeStruct(name, self.content(value)?))
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Content, E> {
+        Ok(Content::NewtypeVariant(name, index, variant, self.content(value)?))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SeqBuilder<E>, E> {
+        Ok(SeqBuilder::new(self.human_readable, SeqKind::Seq(len)))
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<SeqBuilder<E>, E> {
+        Ok(SeqBuilder::new(self.human_readable, SeqKind::Tuple))
+    }
+
+    fn serialize_tuple_struct(self, name: &'static str, _len: usize) -> Result<SeqBuilder<E>, E> {
+        Ok(SeqBuilder::new(self.human_readable, SeqKind::TupleStruct(name)))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<SeqBuilder<E>, E> {
+        Ok(SeqBuilder::new(
+            self.human_readable,
+            SeqKind::TupleVariant(name, index, variant),
+        ))
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<MapBuilder<E>, E> {
+        Ok(MapBuilder {
+            human_readable: self.human_readable,
+            len,
+            entries: Vec::new(),
+            error: PhantomData,
+        })
+    }
+
+    fn serialize_struct(self, name: &'static str, _len: usize) -> Result<StructBuilder<E>, E> {
+        Ok(StructBuilder {
+            human_readable: self.human_readable,
+            name,
+            variant: None,
+            fields: Vec::new(),
+            error: PhantomData,
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<StructBuilder<E>, E> {
+        Ok(StructBuilder {
+            human_readable: self.human_readable,
+            name,
+            variant: Some((index, variant)),
+            fields: Vec::new(),
+            error: PhantomData,
+        })
+    }
+}
+
+enum SeqKind {
+    Seq(Option<usize>),
+    Tuple,
+    TupleStruct(&'static str),
+    TupleVariant(&'static str, u32, &'static str),
+}
+
+struct SeqBuilder<E> {
+    human_readable: bool,
+    kind: SeqKind,
+    items: Vec<Content>,
+    error: PhantomData<E>,
+}
+
+impl<E: Error> SeqBuilder<E> {
+    fn new(human_readable: bool, kind: SeqKind) -> Self {
+        Self {
+            human_readable,
+            kind,
+            items: Vec::new(),
+            error: PhantomData,
+        }
+    }
+
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), E> {
+        self.items
+            .push(value.serialize(ContentSerializer::new(self.human_readable))?);
+        Ok(())
+    }
+
+    fn finish(self) -> Content {
+        match self.kind {
+            SeqKind::Seq(len) => Content::Seq(len, self.items),
+            SeqKind::Tuple => Content::Tuple(self.items),
+            SeqKind::TupleStruct(name) => Content::TupleStruct(name, self.items),
+            SeqKind::TupleVariant(name, index, variant) => Content::TupleVariant(name, index, variant, self.items),
+        }
+    }
+}
+
+impl<E: Error> SerializeSeq for SeqBuilder<E> {
+    type Ok = Content;
+    type Error = E;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), E> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Content, E> {
+        Ok(self.finish())
+    }
+}
+
+impl<E: Error> SerializeTuple for SeqBuilder<E> {
+    type Ok = Content;
+    type Error = E;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), E> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Content, E> {
+        Ok(self.finish())
+    }
+}
+
+impl<E: Error> SerializeTupleStruct for SeqBuilder<E> {
+    type Ok = Content;
+    type Error = E;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), E> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Content, E> {
+        Ok(self.finish())
+    }
+}
+
+impl<E: Error> SerializeTupleVariant for SeqBuilder<E> {
+    type Ok = Content;
+    type Error = E;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), E> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Content, E> {
+        Ok(self.finish())
+    }
+}
+
+struct MapBuilder<E> {
+    human_readable: bool,
+    len: Option<usize>,
+    entries: Vec<(Content, Content)>,
+    error: PhantomData<E>,
+}
+
+impl<E: Error> SerializeMap for MapBuilder<E> {
+    type Ok = Content;
+    type Error = E;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), E> {
+        let key = key.serialize(ContentSerializer::new(self.human_readable))?;
+        self.entries.push((key, Content::Unit));
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), E> {
+        let content = value.serialize(ContentSerializer::new(self.human_readable))?;
+        match self.entries.last_mut() {
+            Some((_, slot)) => *slot = content,
+            None => return Err(E::custom("serialize_value called before serialize_key")),
+        }
+        Ok(())
+    }
+
+    fn end(self) -> Result<Content, E> {
+        Ok(Content::Map(self.len, self.entries))
+    }
+}
+
+struct StructBuilder<E> {
+    human_readable: bool,
+    name: &'static str,
+    variant: Option<(u32, &'static str)>,
+    fields: Vec<(&'static str, Content)>,
+    error: PhantomData<E>,
+}
+
+impl<E: Error> StructBuilder<E> {
+    fn push<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Result<(), E> {
+        let value = value.serialize(ContentSerializer::new(self.human_readable))?;
+        self.fields.push((key, value));
+        Ok(())
+    }
+
+    fn finish(self) -> Content {
+        match self.variant {
+            Some((index, variant)) => Content::StructVariant(self.name, index, variant, self.fields),
+            None => Content::Struct(self.name, self.fields),
+        }
+    }
+}
+
+impl<E: Error> SerializeStruct for StructBuilder<E> {
+    type Ok = Content;
+    type Error = E;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Result<(), E> {
+        self.push(key, value)
+    }
+
+    fn end(self) -> Result<Content, E> {
+        Ok(self.finish())
+    }
+}
+
+impl<E: Error> SerializeStructVariant for StructBuilder<E> {
+    type Ok = Content;
+    type Error = E;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Result<(), E> {
+        self.push(key, value)
+    }
+
+    fn end(self) -> Result<Content, E> {
+        Ok(self.finish())
+    }
+}
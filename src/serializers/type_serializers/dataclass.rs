@@ -13,8 +13,8 @@ use crate::serializers::SerializationState;
 use crate::tools::SchemaDict;
 
 use super::{
-    infer_json_key, infer_json_key_known, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer,
-    CombinedSerializer, ComputedFields, FieldsMode, GeneralFieldsSerializer, ObType, SerCheck, SerField,
+    infer_json_key, infer_json_key_known, infer_serialize, infer_to_python, py_err_se_err, sort_dict, BuildSerializer,
+    CombinedSerializer, ComputedFields, ExcludeIf, FieldsMode, GeneralFieldsSerializer, ObType, SerCheck, SerField,
     TypeSerializer,
 };
 
@@ -49,7 +49,7 @@ impl BuildSerializer for DataclassArgsBuilder {
                 if field_info.get_as(intern!(py, "serialization_exclude"))? == Some(true) {
                     fields.insert(
                         name,
-                        SerField::new(py, key_py, None, None, true, serialize_by_alias, None),
+                        SerField::new(py, key_py, None, None, true, serialize_by_alias, None, None),
                     );
                 } else {
                     let schema = field_info.get_as_req(intern!(py, "schema"))?;
@@ -57,8 +57,10 @@ impl BuildSerializer for DataclassArgsBuilder {
                         .map_err(|e| py_schema_error_type!("Field `{}`:\n  {}", index, e))?;
 
                     let alias = field_info.get_as(intern!(py, "serialization_alias"))?;
-                    let serialization_exclude_if: Option<Py<PyAny>> =
+                    let serialization_exclude_if: Option<ExcludeIf> =
                         field_info.get_as(intern!(py, "serialization_exclude_if"))?;
+                    let serialization_groups: Option<Vec<String>> =
+                        field_info.get_as(intern!(py, "serialization_groups"))?;
                     fields.insert(
                         name,
                         SerField::new(
@@ -69,6 +71,7 @@ impl BuildSerializer for DataclassArgsBuilder {
                             true,
                             serialize_by_alias,
                             serialization_exclude_if,
+                            serialization_groups,
                         ),
                     );
                 }
@@ -76,7 +79,14 @@ impl BuildSerializer for DataclassArgsBuilder {
         }
         let computed_fields = ComputedFields::new(schema, config, definitions)?;
 
-        Ok(CombinedSerializer::Fields(GeneralFieldsSerializer::new(fields, fields_mode, None, computed_fields)).into())
+        Ok(CombinedSerializer::Fields(GeneralFieldsSerializer::new(
+            fields,
+            fields_mode,
+            None,
+            None,
+            computed_fields,
+        ))
+        .into())
     }
 }
 
@@ -155,11 +165,17 @@ impl TypeSerializer for DataclassSerializer {
             let state = &mut state.scoped_set(|s| &mut s.model, Some(value.clone()));
             let py = value.py();
             if let CombinedSerializer::Fields(ref fields_serializer) = *self.serializer {
+                let sort_keys = state.config.sort_keys;
+                let state = &mut state.scoped_set(|s| &mut s.config.sort_keys, sort_keys.nested());
                 let output_dict: Bound<PyDict> =
                     fields_serializer.main_to_python(py, model, known_dataclass_iter(&self.fields, model), state)?;
 
                 fields_serializer.add_computed_fields_python(model, &output_dict, state)?;
-                Ok(output_dict.into())
+                if sort_keys.enabled() {
+                    Ok(sort_dict(&output_dict)?.into())
+                } else {
+                    Ok(output_dict.into())
+                }
             } else {
                 let inner_value = self.get_inner_value(value)?;
                 self.serializer.to_python(&inner_value, state)
@@ -193,10 +209,13 @@ impl TypeSerializer for DataclassSerializer {
             let state = &mut state.scoped_set(|s| &mut s.model, Some(value.clone()));
             if let CombinedSerializer::Fields(ref fields_serializer) = *self.serializer {
                 let expected_len = self.fields.len() + fields_serializer.computed_field_count();
+                let sort_keys = state.config.sort_keys;
+                let state = &mut state.scoped_set(|s| &mut s.config.sort_keys, sort_keys.nested());
                 let mut map = fields_serializer.main_serde_serialize(
                     known_dataclass_iter(&self.fields, value),
                     expected_len,
                     serializer,
+                    sort_keys,
                     state,
                 )?;
                 fields_serializer.add_computed_fields_json::<S>(value, &mut map, state)?;
@@ -3,29 +3,113 @@ use std::sync::Arc;
 
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
+use pyo3::{intern, IntoPyObjectExt};
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
 
 use crate::build_tools::LazyLock;
 use crate::definitions::DefinitionsBuilder;
-use crate::serializers::infer::{infer_json_key_known, infer_serialize_known, infer_to_python_known};
+use crate::serializers::config::{DecimalMode, FloatFormat, FloatFormatMode, SerializationConfig};
+use crate::serializers::errors::py_err_se_err;
+use crate::serializers::infer::infer_to_python_known;
 use crate::serializers::ob_type::{IsType, ObType};
+use crate::serializers::ser::TOKEN;
 use crate::serializers::SerializationState;
 
+use super::float::serialize_f64;
 use super::{infer_json_key, infer_serialize, infer_to_python, BuildSerializer, CombinedSerializer, TypeSerializer};
 
 #[derive(Debug)]
-pub struct DecimalSerializer {}
+pub struct DecimalSerializer {
+    float_format: FloatFormat,
+}
+
+static DECIMAL_SERIALIZER: LazyLock<Arc<CombinedSerializer>> = LazyLock::new(|| {
+    Arc::new(
+        DecimalSerializer {
+            float_format: FloatFormat::default(),
+        }
+        .into(),
+    )
+});
+
+/// The JSON string for a decimal, formatted according to `float_format`.
+pub(crate) fn decimal_to_string(value: &Bound<'_, PyAny>, float_format: FloatFormat) -> PyResult<String> {
+    let py = value.py();
+    let precision = float_format.precision.unwrap_or(6);
+    let spec = match float_format.mode {
+        FloatFormatMode::Shortest => {
+            let Some(precision) = float_format.precision else {
+                return Ok(value.to_string());
+            };
+            // only round decimals with more than `precision` places, `Decimal('1.10')` stays as it is
+            if value.call_method0(intern!(py, "is_finite"))?.is_truthy()? {
+                let exponent: i64 = value
+                    .call_method0(intern!(py, "as_tuple"))?
+                    .getattr(intern!(py, "exponent"))?
+                    .extract()?;
+                if exponent < -i64::try_from(precision).unwrap_or(i64::MAX) {
+                    // unlike `round()`, formatting isn't limited by the precision of the decimal context
+                    return value
+                        .call_method1(intern!(py, "__format__"), (format!(".{precision}f"),))?
+                        .extract();
+                }
+            }
+            return Ok(value.to_string());
+        }
+        FloatFormatMode::Fixed => format!(".{precision}f"),
+        FloatFormatMode::Scientific => format!(".{precision}e"),
+    };
+    value.call_method1(intern!(py, "__format__"), (spec,))?.extract()
+}
 
-static DECIMAL_SERIALIZER: LazyLock<Arc<CombinedSerializer>> = LazyLock::new(|| Arc::new(DecimalSerializer {}.into()));
+/// Serialize a decimal as a string, or with `DecimalMode::Number` as a JSON number token with its exact digits,
+/// non-finite decimals are then written like non-finite floats according to `inf_nan_mode`.
+pub(crate) fn serialize_decimal<S: Serializer>(
+    value: &Bound<'_, PyAny>,
+    serializer: S,
+    float_format: FloatFormat,
+    config: &SerializationConfig,
+) -> Result<S::Ok, S::Error> {
+    let text = decimal_to_string(value, float_format).map_err(py_err_se_err)?;
+    if config.decimal_mode == DecimalMode::Str || !serializer.is_human_readable() {
+        return text.serialize(serializer);
+    }
+    match text.parse::<serde_json::Number>() {
+        // write the decimal's own text as the number token, `serde_json::Number` would turn `-0` into `0`
+        Ok(_) => {
+            let mut number = serializer.serialize_struct(TOKEN, 1)?;
+            number.serialize_field(TOKEN, &text)?;
+            number.end()
+        }
+        Err(_) => {
+            // `NaN`, `sNaN`, `Infinity` and `-Infinity`
+            let v = if text.contains("NaN") {
+                f64::NAN
+            } else if text.starts_with('-') {
+                f64::NEG_INFINITY
+            } else {
+                f64::INFINITY
+            };
+            serialize_f64(v, serializer, config.inf_nan_mode, FloatFormat::default())
+        }
+    }
+}
 
 impl BuildSerializer for DecimalSerializer {
     const EXPECTED_TYPE: &'static str = "decimal";
 
     fn build(
-        _schema: &Bound<'_, PyDict>,
-        _config: Option<&Bound<'_, PyDict>>,
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
         _definitions: &mut DefinitionsBuilder<Arc<CombinedSerializer>>,
     ) -> PyResult<Arc<CombinedSerializer>> {
-        Ok(DECIMAL_SERIALIZER.clone())
+        let float_format = FloatFormat::from_schema_or_config(schema, config)?;
+        if float_format.is_shortest() {
+            Ok(DECIMAL_SERIALIZER.clone())
+        } else {
+            Ok(Arc::new(Self { float_format }.into()))
+        }
     }
 }
 
@@ -37,8 +121,10 @@ impl TypeSerializer for DecimalSerializer {
         value: &Bound<'py, PyAny>,
         state: &mut SerializationState<'_, 'py>,
     ) -> PyResult<Py<PyAny>> {
-        let _py = value.py();
         match state.extra.ob_type_lookup.is_type(value, ObType::Decimal) {
+            IsType::Exact | IsType::Subclass if state.extra.mode.is_json() => {
+                decimal_to_string(value, self.float_format)?.into_py_any(value.py())
+            }
             IsType::Exact | IsType::Subclass => infer_to_python_known(ObType::Decimal, value, state),
             IsType::False => {
                 state.warn_fallback_py(self.get_name(), value)?;
@@ -53,7 +139,7 @@ impl TypeSerializer for DecimalSerializer {
         state: &mut SerializationState<'_, 'py>,
     ) -> PyResult<Cow<'a, str>> {
         match state.extra.ob_type_lookup.is_type(key, ObType::Decimal) {
-            IsType::Exact | IsType::Subclass => infer_json_key_known(ObType::Decimal, key, state),
+            IsType::Exact | IsType::Subclass => Ok(Cow::Owned(decimal_to_string(key, self.float_format)?)),
             IsType::False => {
                 state.warn_fallback_py(self.get_name(), key)?;
                 infer_json_key(key, state)
@@ -68,7 +154,7 @@ impl TypeSerializer for DecimalSerializer {
         state: &mut SerializationState<'_, 'py>,
     ) -> Result<S::Ok, S::Error> {
         match state.extra.ob_type_lookup.is_type(value, ObType::Decimal) {
-            IsType::Exact | IsType::Subclass => infer_serialize_known(ObType::Decimal, value, serializer, state),
+            IsType::Exact | IsType::Subclass => serialize_decimal(value, serializer, self.float_format, &state.config),
             IsType::False => {
                 state.warn_fallback_ser::<S>(self.get_name(), value)?;
                 infer_serialize(value, serializer, state)
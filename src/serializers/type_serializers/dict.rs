@@ -14,14 +14,15 @@ use crate::tools::SchemaDict;
 
 use super::any::AnySerializer;
 use super::{
-    infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, PydanticSerializer,
-    SchemaFilter, SerMode, TypeSerializer,
+    infer_serialize, infer_to_python, py_err_se_err, serde_len_hint, sort_dict, BuildSerializer, CombinedSerializer,
+    ExcludeIf, PydanticSerializer, SchemaFilter, SerMode, SortedMap, TypeSerializer,
 };
 
 #[derive(Debug)]
 pub struct DictSerializer {
     key_serializer: Arc<CombinedSerializer>,
     value_serializer: Arc<CombinedSerializer>,
+    values_exclude_if: Option<ExcludeIf>,
     // isize because we look up include exclude via `.hash()` which returns an isize
     filter: SchemaFilter<isize>,
     name: String,
@@ -44,6 +45,7 @@ impl BuildSerializer for DictSerializer {
             Some(items_schema) => CombinedSerializer::build(&items_schema, config, definitions)?,
             None => AnySerializer::build(schema, config, definitions)?,
         };
+        let values_exclude_if = schema.get_as(intern!(py, "values_exclude_if"))?;
         let filter = match schema.get_as::<Bound<'_, PyDict>>(intern!(py, "serialization"))? {
             Some(ser) => {
                 let include = ser.get_item(intern!(py, "include"))?;
@@ -61,6 +63,7 @@ impl BuildSerializer for DictSerializer {
         Ok(CombinedSerializer::Dict(Self {
             key_serializer,
             value_serializer,
+            values_exclude_if,
             filter,
             name,
         })
@@ -68,6 +71,15 @@ impl BuildSerializer for DictSerializer {
     }
 }
 
+impl DictSerializer {
+    fn exclude_value(&self, value: &Bound<'_, PyAny>) -> PyResult<bool> {
+        match self.values_exclude_if {
+            Some(ref exclude_if) => exclude_if.exclude(value),
+            None => Ok(false),
+        }
+    }
+}
+
 impl_py_gc_traverse!(DictSerializer {
     key_serializer,
     value_serializer
@@ -83,9 +95,14 @@ impl TypeSerializer for DictSerializer {
         match value.downcast::<PyDict>() {
             Ok(py_dict) => {
                 let value_serializer = self.value_serializer.as_ref();
+                let sort_keys = state.config.sort_keys;
+                let state = &mut state.scoped_set(|s| &mut s.config.sort_keys, sort_keys.nested());
 
                 let new_dict = PyDict::new(py);
                 for (key, value) in py_dict.iter() {
+                    if self.exclude_value(&value)? {
+                        continue;
+                    }
                     let op_next = self.filter.key_filter(&key, state)?;
                     if let Some((next_include, next_exclude)) = op_next {
                         let key = {
@@ -101,7 +118,11 @@ impl TypeSerializer for DictSerializer {
                         new_dict.set_item(key, value)?;
                     }
                 }
-                Ok(new_dict.into())
+                if sort_keys.enabled() {
+                    Ok(sort_dict(&new_dict)?.into())
+                } else {
+                    Ok(new_dict.into())
+                }
             }
             Err(_) => {
                 state.warn_fallback_py(self.get_name(), value)?;
@@ -126,11 +147,17 @@ impl TypeSerializer for DictSerializer {
     ) -> Result<S::Ok, S::Error> {
         match value.downcast::<PyDict>() {
             Ok(py_dict) => {
-                let mut map = serializer.serialize_map(Some(py_dict.len()))?;
+                let len_hint = serde_len_hint(&serializer, py_dict.len());
+                let sort_keys = state.config.sort_keys;
+                let state = &mut state.scoped_set(|s| &mut s.config.sort_keys, sort_keys.nested());
+                let mut map = SortedMap::start(serializer, len_hint, sort_keys)?;
                 let key_serializer = self.key_serializer.as_ref();
                 let value_serializer = self.value_serializer.as_ref();
 
                 for (key, value) in py_dict.iter() {
+                    if self.exclude_value(&value).map_err(py_err_se_err)? {
+                        continue;
+                    }
                     let op_next = self.filter.key_filter(&key, state).map_err(py_err_se_err)?;
                     if let Some((next_include, next_exclude)) = op_next {
                         let state = &mut state.scoped_include_exclude(next_include, next_exclude);
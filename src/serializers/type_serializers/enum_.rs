@@ -36,7 +36,7 @@ impl BuildSerializer for EnumSerializer {
         let serializer = match sub_type.as_deref() {
             Some("int") => Some(IntSerializer::get().clone()),
             Some("str") => Some(StrSerializer::get().clone()),
-            Some("float") => Some(FloatSerializer::get(schema.py(), config)?.clone()),
+            Some("float") => Some(FloatSerializer::get(schema.py(), config)?),
             Some(_) => return py_schema_err!("`sub_type` must be one of: 'int', 'str', 'float' or None"),
             None => None,
         };
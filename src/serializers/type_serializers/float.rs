@@ -1,14 +1,15 @@
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyFloat};
 use pyo3::{intern, prelude::*, IntoPyObjectExt};
 
 use std::borrow::Cow;
 use std::sync::Arc;
 
-use serde::Serializer;
+use serde::ser::Error;
+use serde::{Serialize, Serializer};
 
 use crate::build_tools::LazyLock;
 use crate::definitions::DefinitionsBuilder;
-use crate::serializers::config::InfNanMode;
+use crate::serializers::config::{FloatFormat, FromConfig, InfNanMode};
 use crate::serializers::SerializationState;
 use crate::tools::SchemaDict;
 
@@ -22,42 +23,66 @@ use crate::serializers::errors::PydanticSerializationUnexpectedValue;
 #[derive(Debug)]
 pub struct FloatSerializer {
     inf_nan_mode: InfNanMode,
+    float_format: FloatFormat,
 }
 
 static FLOAT_SERIALIZER_NULL: LazyLock<Arc<CombinedSerializer>> = LazyLock::new(|| {
     Arc::new(CombinedSerializer::Float(FloatSerializer {
         inf_nan_mode: InfNanMode::Null,
+        float_format: FloatFormat::default(),
     }))
 });
 
 static FLOAT_SERIALIZER_CONSTANTS: LazyLock<Arc<CombinedSerializer>> = LazyLock::new(|| {
     Arc::new(CombinedSerializer::Float(FloatSerializer {
         inf_nan_mode: InfNanMode::Constants,
+        float_format: FloatFormat::default(),
     }))
 });
 
 static FLOAT_SERIALIZER_STRINGS: LazyLock<Arc<CombinedSerializer>> = LazyLock::new(|| {
     Arc::new(CombinedSerializer::Float(FloatSerializer {
         inf_nan_mode: InfNanMode::Strings,
+        float_format: FloatFormat::default(),
     }))
 });
 
 impl FloatSerializer {
-    pub fn get(py: Python, config: Option<&Bound<'_, PyDict>>) -> PyResult<&'static Arc<CombinedSerializer>> {
-        let inf_nan_mode = config
-            .and_then(|c| c.get_as(intern!(py, "ser_json_inf_nan")).transpose())
-            .transpose()?
-            .unwrap_or_default();
+    pub fn get(py: Python, config: Option<&Bound<'_, PyDict>>) -> PyResult<Arc<CombinedSerializer>> {
+        Ok(Self::with_format(
+            inf_nan_mode(py, config)?,
+            FloatFormat::from_config(config)?,
+        ))
+    }
 
+    fn with_format(inf_nan_mode: InfNanMode, float_format: FloatFormat) -> Arc<CombinedSerializer> {
+        if !float_format.is_shortest() {
+            return Arc::new(CombinedSerializer::Float(Self {
+                inf_nan_mode,
+                float_format,
+            }));
+        }
         match inf_nan_mode {
-            InfNanMode::Null => Ok(&FLOAT_SERIALIZER_NULL),
-            InfNanMode::Constants => Ok(&FLOAT_SERIALIZER_CONSTANTS),
-            InfNanMode::Strings => Ok(&FLOAT_SERIALIZER_STRINGS),
+            InfNanMode::Null => FLOAT_SERIALIZER_NULL.clone(),
+            InfNanMode::Constants => FLOAT_SERIALIZER_CONSTANTS.clone(),
+            InfNanMode::Strings => FLOAT_SERIALIZER_STRINGS.clone(),
         }
     }
 }
 
-pub fn serialize_f64<S: Serializer>(v: f64, serializer: S, inf_nan_mode: InfNanMode) -> Result<S::Ok, S::Error> {
+fn inf_nan_mode(py: Python, config: Option<&Bound<'_, PyDict>>) -> PyResult<InfNanMode> {
+    Ok(config
+        .and_then(|c| c.get_as(intern!(py, "ser_json_inf_nan")).transpose())
+        .transpose()?
+        .unwrap_or_default())
+}
+
+pub fn serialize_f64<S: Serializer>(
+    v: f64,
+    serializer: S,
+    inf_nan_mode: InfNanMode,
+    float_format: FloatFormat,
+) -> Result<S::Ok, S::Error> {
     if v.is_nan() || v.is_infinite() {
         match inf_nan_mode {
             InfNanMode::Null => serializer.serialize_none(),
@@ -70,8 +95,36 @@ pub fn serialize_f64<S: Serializer>(v: f64, serializer: S, inf_nan_mode: InfNanM
                 }
             }
         }
+    } else if let Some(text) = float_format.format_f64(v).filter(|_| serializer.is_human_readable()) {
+        // write the formatted text as a JSON number token rather than via `f64`, to keep trailing zeros
+        let number: serde_json::Number = text.parse().map_err(S::Error::custom)?;
+        number.serialize(serializer)
     } else {
-        serializer.serialize_f64(v)
+        serializer.serialize_f64(float_format.round(v))
+    }
+}
+
+/// A float in JSON mode of `to_python`, rounded according to `float_format`.
+pub fn float_to_json_python(py: Python, v: f64, float_format: FloatFormat) -> PyResult<Py<PyAny>> {
+    if v.is_finite() {
+        float_format.round(v).into_py_any(py)
+    } else {
+        v.into_py_any(py)
+    }
+}
+
+/// A float dict key in JSON, formatted according to `float_format`.
+pub fn float_json_key<'a>(key: &'a Bound<'_, PyAny>, float_format: FloatFormat) -> PyResult<Cow<'a, str>> {
+    if float_format.is_shortest() {
+        return to_str_json_key(key);
+    }
+    let v = key.extract::<f64>()?;
+    if !v.is_finite() {
+        return to_str_json_key(key);
+    }
+    match float_format.format_f64(v) {
+        Some(text) => Ok(Cow::Owned(text)),
+        None => to_str_json_key(&PyFloat::new(key.py(), float_format.round(v))).map(|key| Cow::Owned(key.into_owned())),
     }
 }
 
@@ -83,7 +136,9 @@ impl BuildSerializer for FloatSerializer {
         config: Option<&Bound<'_, PyDict>>,
         _definitions: &mut DefinitionsBuilder<Arc<CombinedSerializer>>,
     ) -> PyResult<Arc<CombinedSerializer>> {
-        Self::get(schema.py(), config).cloned()
+        let inf_nan_mode = inf_nan_mode(schema.py(), config)?;
+        let float_format = FloatFormat::from_schema_or_config(schema, config)?;
+        Ok(Self::with_format(inf_nan_mode, float_format))
     }
 }
 
@@ -97,11 +152,14 @@ impl TypeSerializer for FloatSerializer {
     ) -> PyResult<Py<PyAny>> {
         let py = value.py();
         match state.extra.ob_type_lookup.is_type(value, ObType::Float) {
+            IsType::Exact if state.extra.mode.is_json() && !self.float_format.is_shortest() => {
+                float_to_json_python(py, value.extract()?, self.float_format)
+            }
             IsType::Exact => Ok(value.clone().unbind()),
             IsType::Subclass => match state.check {
                 SerCheck::Strict => Err(PydanticSerializationUnexpectedValue::new_from_msg(None).to_py_err()),
                 SerCheck::Lax | SerCheck::None => match state.extra.mode {
-                    SerMode::Json => value.extract::<f64>()?.into_py_any(py),
+                    SerMode::Json => float_to_json_python(py, value.extract()?, self.float_format),
                     _ => infer_to_python(value, state),
                 },
             },
@@ -118,7 +176,7 @@ impl TypeSerializer for FloatSerializer {
         state: &mut SerializationState<'_, 'py>,
     ) -> PyResult<Cow<'a, str>> {
         match state.extra.ob_type_lookup.is_type(key, ObType::Float) {
-            IsType::Exact | IsType::Subclass => to_str_json_key(key),
+            IsType::Exact | IsType::Subclass => float_json_key(key, self.float_format),
             IsType::False => {
                 state.warn_fallback_py(self.get_name(), key)?;
                 infer_json_key(key, state)
@@ -133,7 +191,7 @@ impl TypeSerializer for FloatSerializer {
         state: &mut SerializationState<'_, 'py>,
     ) -> Result<S::Ok, S::Error> {
         match value.extract::<f64>() {
-            Ok(v) => serialize_f64(v, serializer, self.inf_nan_mode),
+            Ok(v) => serialize_f64(v, serializer, self.inf_nan_mode, self.float_format),
             Err(_) => {
                 state.warn_fallback_ser::<S>(self.get_name(), value)?;
                 infer_serialize(value, serializer, state)
@@ -17,7 +17,7 @@ use crate::tools::SchemaDict;
 
 use super::any::AnySerializer;
 use super::{
-    infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, ExtraOwned,
+    infer_serialize, infer_to_python, py_err_se_err, serde_len_hint, BuildSerializer, CombinedSerializer, ExtraOwned,
     PydanticSerializer, SchemaFilter, SerMode, TypeSerializer,
 };
 
@@ -108,7 +108,8 @@ impl TypeSerializer for GeneratorSerializer {
         match value.downcast::<PyIterator>() {
             Ok(py_iter) => {
                 let len = value.len().ok();
-                let mut seq = serializer.serialize_seq(len)?;
+                let len_hint = len.and_then(|len| serde_len_hint(&serializer, len));
+                let mut seq = serializer.serialize_seq(len_hint)?;
                 let item_serializer = self.item_serializer.as_ref();
 
                 for (index, iter_result) in py_iter.clone().enumerate() {
@@ -14,8 +14,8 @@ use crate::tools::SchemaDict;
 
 use super::any::AnySerializer;
 use super::{
-    infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, PydanticSerializer,
-    SchemaFilter, TypeSerializer,
+    infer_serialize, infer_to_python, py_err_se_err, serde_len_hint, BuildSerializer, CombinedSerializer,
+    PydanticSerializer, SchemaFilter, TypeSerializer,
 };
 
 #[derive(Debug)]
@@ -96,7 +96,8 @@ impl TypeSerializer for ListSerializer {
     ) -> Result<S::Ok, S::Error> {
         match value.downcast::<PyList>() {
             Ok(py_list) => {
-                let mut seq = serializer.serialize_seq(Some(py_list.len()))?;
+                let len_hint = serde_len_hint(&serializer, py_list.len());
+                let mut seq = serializer.serialize_seq(len_hint)?;
                 let item_serializer = self.item_serializer.as_ref();
 
                 for (index, element) in py_list.iter().enumerate() {
@@ -9,8 +9,8 @@ use ahash::AHashMap;
 use pyo3::IntoPyObjectExt;
 
 use super::{
-    infer_json_key, infer_json_key_known, BuildSerializer, CombinedSerializer, ComputedFields, Extra, FieldsMode,
-    GeneralFieldsSerializer, ObType, SerCheck, SerField, TypeSerializer,
+    infer_json_key, infer_json_key_known, BuildSerializer, CombinedSerializer, ComputedFields, ExcludeIf, Extra,
+    FieldsMode, GeneralFieldsSerializer, ObType, SerCheck, SerField, TypeSerializer,
 };
 use crate::build_tools::py_schema_err;
 use crate::build_tools::{py_schema_error_type, ExtraBehavior};
@@ -52,6 +52,11 @@ impl BuildSerializer for ModelFieldsBuilder {
             (Some(_), _) => return py_schema_err!("extras_schema can only be used if extra_behavior=allow"),
             (_, _) => None,
         };
+        let extras_exclude_if = match (schema.get_item(intern!(py, "extras_exclude_if"))?, &fields_mode) {
+            (Some(v), FieldsMode::ModelExtra) => Some(v.extract()?),
+            (Some(_), _) => return py_schema_err!("extras_exclude_if can only be used if extra_behavior=allow"),
+            (_, _) => None,
+        };
 
         let serialize_by_alias = config.get_as(intern!(py, "serialize_by_alias"))?;
 
@@ -65,12 +70,14 @@ impl BuildSerializer for ModelFieldsBuilder {
             if field_info.get_as(intern!(py, "serialization_exclude"))? == Some(true) {
                 fields.insert(
                     key,
-                    SerField::new(py, key_py, None, None, true, serialize_by_alias, None),
+                    SerField::new(py, key_py, None, None, true, serialize_by_alias, None, None),
                 );
             } else {
                 let alias: Option<String> = field_info.get_as(intern!(py, "serialization_alias"))?;
-                let serialization_exclude_if: Option<Py<PyAny>> =
+                let serialization_exclude_if: Option<ExcludeIf> =
                     field_info.get_as(intern!(py, "serialization_exclude_if"))?;
+                let serialization_groups: Option<Vec<String>> =
+                    field_info.get_as(intern!(py, "serialization_groups"))?;
                 let schema = field_info.get_as_req(intern!(py, "schema"))?;
                 let serializer = CombinedSerializer::build(&schema, config, definitions)
                     .map_err(|e| py_schema_error_type!("Field `{}`:\n  {}", key, e))?;
@@ -85,6 +92,7 @@ impl BuildSerializer for ModelFieldsBuilder {
                         true,
                         serialize_by_alias,
                         serialization_exclude_if,
+                        serialization_groups,
                     ),
                 );
             }
@@ -93,7 +101,14 @@ impl BuildSerializer for ModelFieldsBuilder {
         let computed_fields = ComputedFields::new(schema, config, definitions)?;
 
         Ok(Arc::new(
-            GeneralFieldsSerializer::new(fields, fields_mode, extra_serializer, computed_fields).into(),
+            GeneralFieldsSerializer::new(
+                fields,
+                fields_mode,
+                extra_serializer,
+                extras_exclude_if,
+                computed_fields,
+            )
+            .into(),
         ))
     }
 }
@@ -1,6 +1,5 @@
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
-use pyo3::IntoPyObjectExt;
 
 use std::borrow::Cow;
 use std::sync::Arc;
@@ -8,6 +7,7 @@ use std::sync::Arc;
 use serde::Serialize;
 
 use crate::build_tools::LazyLock;
+use crate::serializers::config::IntMode;
 use crate::PydanticSerializationUnexpectedValue;
 use crate::{definitions::DefinitionsBuilder, input::Int};
 
@@ -94,6 +94,27 @@ impl TypeSerializer for NoneSerializer {
 
 macro_rules! build_simple_serializer {
     ($struct_name:ident, $expected_type:literal, $rust_type:ty, $ob_type:expr, $key_method:ident, $subtypes_allowed:expr) => {
+        build_simple_serializer!(
+            $struct_name,
+            $expected_type,
+            $rust_type,
+            $ob_type,
+            $key_method,
+            $subtypes_allowed,
+            unchanged_json_python,
+            serialize_unchanged
+        );
+    };
+    (
+        $struct_name:ident,
+        $expected_type:literal,
+        $rust_type:ty,
+        $ob_type:expr,
+        $key_method:ident,
+        $subtypes_allowed:expr,
+        $json_python:ident,
+        $serialize:ident
+    ) => {
         #[derive(Debug)]
         pub struct $struct_name;
 
@@ -127,11 +148,17 @@ macro_rules! build_simple_serializer {
             ) -> PyResult<Py<PyAny>> {
                 let py = value.py();
                 match state.extra.ob_type_lookup.is_type(value, $ob_type) {
-                    IsType::Exact => Ok(value.clone().unbind()),
+                    IsType::Exact => match state.extra.mode {
+                        SerMode::Json => $json_python(value, state),
+                        _ => Ok(value.clone().unbind()),
+                    },
                     IsType::Subclass => match state.check {
                         SerCheck::Strict => Err(PydanticSerializationUnexpectedValue::new_from_msg(None).to_py_err()),
                         SerCheck::Lax | SerCheck::None => match state.extra.mode {
-                            SerMode::Json => value.extract::<$rust_type>()?.into_py_any(py),
+                            SerMode::Json => {
+                                let exact = value.extract::<$rust_type>()?.into_pyobject(py)?;
+                                $json_python(exact.as_any(), state)
+                            }
                             _ => infer_to_python(value, state),
                         },
                     },
@@ -163,7 +190,7 @@ macro_rules! build_simple_serializer {
                 state: &mut SerializationState<'_, 'py>,
             ) -> Result<S::Ok, S::Error> {
                 match value.extract::<$rust_type>() {
-                    Ok(v) => v.serialize(serializer),
+                    Ok(v) => $serialize(&v, serializer, state),
                     Err(_) => {
                         state.warn_fallback_ser::<S>(self.get_name(), value)?;
                         infer_serialize(value, serializer, state)
@@ -182,11 +209,72 @@ macro_rules! build_simple_serializer {
     };
 }
 
+fn unchanged_json_python(value: &Bound<'_, PyAny>, _state: &SerializationState<'_, '_>) -> PyResult<Py<PyAny>> {
+    Ok(value.clone().unbind())
+}
+
+fn serialize_unchanged<T: Serialize, S: serde::ser::Serializer>(
+    v: &T,
+    serializer: S,
+    _state: &SerializationState<'_, '_>,
+) -> Result<S::Ok, S::Error> {
+    v.serialize(serializer)
+}
+
+/// `Number.MAX_SAFE_INTEGER`, the largest integer JavaScript represents exactly
+const MAX_SAFE_INTEGER: u64 = (1 << 53) - 1;
+
+/// Whether `int` is written as a JSON string rather than a number.
+fn int_as_string(int: &Int, int_mode: IntMode) -> bool {
+    match int_mode {
+        IntMode::Number => false,
+        IntMode::String => true,
+        IntMode::Safe => match int {
+            Int::I64(i) => i.unsigned_abs() > MAX_SAFE_INTEGER,
+            Int::Big(_) => true,
+        },
+    }
+}
+
+/// An exact `int` in JSON mode of `to_python`, converted to `str` according to `ser_json_int`.
+pub(crate) fn int_to_json_python(value: &Bound<'_, PyAny>, state: &SerializationState<'_, '_>) -> PyResult<Py<PyAny>> {
+    if state.config.int_mode != IntMode::Number && int_as_string(&value.extract()?, state.config.int_mode) {
+        Ok(value.str()?.into_any().unbind())
+    } else {
+        Ok(value.clone().unbind())
+    }
+}
+
+/// Serialize an int, to JSON as a string according to `ser_json_int`.
+pub(crate) fn serialize_int<S: serde::ser::Serializer>(
+    int: &Int,
+    serializer: S,
+    state: &SerializationState<'_, '_>,
+) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() && int_as_string(int, state.config.int_mode) {
+        match int {
+            Int::I64(i) => serializer.collect_str(i),
+            Int::Big(b) => serializer.collect_str(b),
+        }
+    } else {
+        int.serialize(serializer)
+    }
+}
+
 pub(crate) fn to_str_json_key<'a>(key: &'a Bound<'_, PyAny>) -> PyResult<Cow<'a, str>> {
     Ok(Cow::Owned(key.str()?.to_string_lossy().into_owned()))
 }
 
-build_simple_serializer!(IntSerializer, "int", Int, ObType::Int, to_str_json_key, true);
+build_simple_serializer!(
+    IntSerializer,
+    "int",
+    Int,
+    ObType::Int,
+    to_str_json_key,
+    true,
+    int_to_json_python,
+    serialize_int
+);
 
 pub(crate) fn bool_json_key<'a>(key: &'a Bound<'_, PyAny>) -> PyResult<Cow<'a, str>> {
     let v = if key.is_truthy().unwrap_or(false) {
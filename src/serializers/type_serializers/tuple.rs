@@ -15,8 +15,8 @@ use crate::tools::SchemaDict;
 use crate::PydanticSerializationUnexpectedValue;
 
 use super::{
-    infer_json_key, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer,
-    PydanticSerializer, SchemaFilter, SerMode, TypeSerializer,
+    infer_json_key, infer_serialize, infer_to_python, py_err_se_err, serde_len_hint, BuildSerializer,
+    CombinedSerializer, PydanticSerializer, SchemaFilter, SerMode, TypeSerializer,
 };
 
 #[derive(Debug)]
@@ -130,7 +130,8 @@ impl TypeSerializer for TupleSerializer {
                 let py_tuple = py_tuple.downcast::<PyTuple>().map_err(py_err_se_err)?;
 
                 let n_items = py_tuple.len();
-                let mut seq = serializer.serialize_seq(Some(n_items))?;
+                let len_hint = serde_len_hint(&serializer, n_items);
+                let mut seq = serializer.serialize_seq(len_hint)?;
 
                 self.for_each_tuple_item_and_serializer(py_tuple, state, |entry| {
                     seq.serialize_element(&PydanticSerializer::new(&entry.item, entry.serializer, entry.state))
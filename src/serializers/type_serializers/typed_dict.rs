@@ -14,7 +14,9 @@ use crate::serializers::shared::TypeSerializer;
 use crate::serializers::SerializationState;
 use crate::tools::SchemaDict;
 
-use super::{BuildSerializer, CombinedSerializer, ComputedFields, FieldsMode, GeneralFieldsSerializer, SerField};
+use super::{
+    BuildSerializer, CombinedSerializer, ComputedFields, ExcludeIf, FieldsMode, GeneralFieldsSerializer, SerField,
+};
 
 #[derive(Debug)]
 pub struct TypedDictSerializer {
@@ -53,6 +55,11 @@ impl BuildSerializer for TypedDictSerializer {
             (Some(_), _) => return py_schema_err!("extras_schema can only be used if extra_behavior=allow"),
             (_, _) => None,
         };
+        let extras_exclude_if = match (schema.get_item(intern!(py, "extras_exclude_if"))?, &fields_mode) {
+            (Some(v), FieldsMode::TypedDictAllow) => Some(v.extract()?),
+            (Some(_), _) => return py_schema_err!("extras_exclude_if can only be used if extra_behavior=allow"),
+            (_, _) => None,
+        };
 
         for (key, value) in fields_dict {
             let key_py = key.downcast_into::<PyString>()?;
@@ -65,12 +72,14 @@ impl BuildSerializer for TypedDictSerializer {
             if field_info.get_as(intern!(py, "serialization_exclude"))? == Some(true) {
                 fields.insert(
                     key,
-                    SerField::new(py, key_py, None, None, required, serialize_by_alias, None),
+                    SerField::new(py, key_py, None, None, required, serialize_by_alias, None, None),
                 );
             } else {
                 let alias: Option<String> = field_info.get_as(intern!(py, "serialization_alias"))?;
-                let serialization_exclude_if: Option<Py<PyAny>> =
+                let serialization_exclude_if: Option<ExcludeIf> =
                     field_info.get_as(intern!(py, "serialization_exclude_if"))?;
+                let serialization_groups: Option<Vec<String>> =
+                    field_info.get_as(intern!(py, "serialization_groups"))?;
                 let schema = field_info.get_as_req(intern!(py, "schema"))?;
                 let serializer = CombinedSerializer::build(&schema, config, definitions)
                     .map_err(|e| py_schema_error_type!("Field `{}`:\n  {}", key, e))?;
@@ -84,6 +93,7 @@ impl BuildSerializer for TypedDictSerializer {
                         required,
                         serialize_by_alias,
                         serialization_exclude_if,
+                        serialization_groups,
                     ),
                 );
             }
@@ -95,7 +105,13 @@ impl BuildSerializer for TypedDictSerializer {
 
         Ok(Arc::new(
             Self {
-                serializer: GeneralFieldsSerializer::new(fields, fields_mode, extra_serializer, computed_fields),
+                serializer: GeneralFieldsSerializer::new(
+                    fields,
+                    fields_mode,
+                    extra_serializer,
+                    extras_exclude_if,
+                    computed_fields,
+                ),
             }
             .into(),
         ))
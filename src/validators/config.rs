@@ -112,3 +112,39 @@ impl ValBytesMode {
         }
     }
 }
+
+/// How strings are parsed into collections when validating with `validate_strings`
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StrCollectionParsing {
+    #[default]
+    Off,
+    Json,
+    Csv,
+}
+
+impl FromStr for StrCollectionParsing {
+    type Err = PyErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "off" => Ok(Self::Off),
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            s => py_schema_err!(
+                "Invalid str_collection_parsing mode: `{}`, expected off, json or csv",
+                s
+            ),
+        }
+    }
+}
+
+impl StrCollectionParsing {
+    pub fn from_config(config: Option<&Bound<'_, PyDict>>) -> PyResult<Self> {
+        let Some(config_dict) = config else {
+            return Ok(Self::default());
+        };
+        let raw_mode =
+            config_dict.get_as::<Bound<'_, PyString>>(intern!(config_dict.py(), "str_collection_parsing"))?;
+        raw_mode.map_or_else(|| Ok(Self::default()), |raw| Self::from_str(&raw.to_cow()?))
+    }
+}
@@ -155,6 +155,7 @@ impl Validator for DataclassArgsValidator {
 
         let mut errors: Vec<ValLineError> = Vec::new();
         let mut used_keys: AHashSet<&str> = AHashSet::with_capacity(self.fields.len());
+        let errors_before = state.error_count;
 
         let state = &mut state.rebind_extra(|extra| extra.data = Some(output_dict.clone()));
         let state = &mut state.scoped_set(|state| &mut state.has_field_error, false);
@@ -181,6 +182,10 @@ impl Validator for DataclassArgsValidator {
 
         // go through fields getting the value from args or kwargs and validating it
         for (index, field) in self.fields.iter().enumerate() {
+            if state.reached_max_errors(errors_before, errors.len()) {
+                // stop early, the remaining fields and any extra arguments aren't checked
+                return Err(ValError::LineErrors(errors));
+            }
             if !field.init {
                 match field.validator.default_value(py, Some(field.name.as_str()), state) {
                     Ok(Some(value)) => {
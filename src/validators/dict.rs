@@ -13,6 +13,7 @@ use crate::input::{Input, ValidatedDict};
 use crate::tools::SchemaDict;
 
 use super::any::AnyValidator;
+use super::config::StrCollectionParsing;
 use super::list::length_check;
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
@@ -25,6 +26,7 @@ pub struct DictValidator {
     max_length: Option<usize>,
     fail_fast: bool,
     name: String,
+    str_collection_parsing: StrCollectionParsing,
 }
 
 impl BuildValidator for DictValidator {
@@ -58,6 +60,7 @@ impl BuildValidator for DictValidator {
             max_length: schema.get_as(intern!(py, "max_length"))?,
             fail_fast: schema.get_as(intern!(py, "fail_fast"))?.unwrap_or(false),
             name,
+            str_collection_parsing: StrCollectionParsing::from_config(config)?,
         })
         .into())
     }
@@ -75,6 +78,9 @@ impl Validator for DictValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Py<PyAny>> {
+        if let Some(mapping) = input.parse_str_mapping(self.str_collection_parsing)? {
+            return self.validate(py, &mapping, state);
+        }
         let strict = state.strict_or(self.strict);
         let dict = input.validate_dict(strict)?;
         dict.iterate(ValidateToDict {
@@ -116,10 +122,11 @@ where
         let output = PyDict::new(self.py);
         let mut errors: Vec<ValLineError> = Vec::new();
         let allow_partial = self.state.allow_partial;
+        let errors_before = self.state.error_count;
 
         macro_rules! should_fail_fast {
             () => {
-                self.fail_fast && !errors.is_empty()
+                (self.fail_fast && !errors.is_empty()) || self.state.reached_max_errors(errors_before, errors.len())
             };
         }
 
@@ -0,0 +1,243 @@
+use pyo3::exceptions::PyTypeError;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::pyclass::CompareOp;
+use pyo3::types::{PyDict, PyList};
+
+use crate::build_tools::{py_schema_err, py_schema_error_type};
+use crate::errors::{ErrorType, ValError, ValLineError, ValResult};
+use crate::input::Input;
+use crate::lookup_key::{LookupKey, LookupPath};
+use crate::tools::SchemaDict;
+
+/// A relationship between fields of a `model-fields` or `typed-dict` schema, checked once all fields
+/// have validated successfully.
+#[derive(Debug, Clone)]
+enum FieldConstraint {
+    /// `fields[0] <op> fields[1]`, only checked when both fields are provided
+    Compare {
+        op: CompareOp,
+        field: String,
+        other_field: String,
+    },
+    /// exactly one of the fields must be provided
+    OneOf(Vec<String>),
+    /// if the first field is provided, all the others must be too
+    Requires { field: String, required: Vec<String> },
+    /// at most one of the fields may be provided
+    MutuallyExclusive(Vec<String>),
+}
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FieldConstraints(Vec<FieldConstraint>);
+
+/// Where each field was looked up, so errors at a field are located like the field's own errors: at the path
+/// its value was found at, or at its lookup key if it wasn't provided. Fields which weren't looked up, e.g.
+/// when validating assignment, are located by name.
+pub(crate) struct FieldLocs<'a> {
+    /// nothing is recorded when there are no constraints to report errors for
+    enabled: bool,
+    loc_by_alias: bool,
+    fields: Vec<(&'a str, &'a LookupKey, Option<&'a LookupPath>)>,
+}
+
+impl<'a> FieldLocs<'a> {
+    pub fn by_name() -> Self {
+        Self {
+            enabled: false,
+            loc_by_alias: false,
+            fields: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, field_name: &'a str, lookup_key: &'a LookupKey, lookup_path: Option<&'a LookupPath>) {
+        if self.enabled {
+            self.fields.push((field_name, lookup_key, lookup_path));
+        }
+    }
+
+    fn locate(&self, line_error: ValLineError, field_name: &str) -> ValLineError {
+        match self.fields.iter().find(|(name, ..)| *name == field_name) {
+            Some((_, _, Some(lookup_path))) => lookup_path.apply_error_loc(line_error, self.loc_by_alias, field_name),
+            Some((_, lookup_key, None)) => lookup_key.apply_error_loc(line_error, self.loc_by_alias, field_name),
+            None => line_error.with_outer_location(field_name),
+        }
+    }
+}
+
+impl FieldConstraints {
+    pub fn from_schema(schema: &Bound<'_, PyDict>, field_names: &[&str]) -> PyResult<Self> {
+        let py = schema.py();
+        let Some(constraints) = schema.get_as::<Bound<'_, PyList>>(intern!(py, "field_constraints"))? else {
+            return Ok(Self::default());
+        };
+
+        let mut out = Vec::with_capacity(constraints.len());
+        for constraint in constraints.iter() {
+            let constraint = constraint.downcast::<PyDict>()?;
+            let constraint_type: String = constraint.get_as_req(intern!(py, "type"))?;
+            let fields: Vec<String> = constraint.get_as_req(intern!(py, "fields"))?;
+            if let Some(unknown) = fields.iter().find(|f| !field_names.contains(&f.as_str())) {
+                return py_schema_err!("Field constraint '{}': unknown field '{}'", constraint_type, unknown);
+            }
+
+            let op = match constraint_type.as_str() {
+                "lt" => Some(CompareOp::Lt),
+                "le" => Some(CompareOp::Le),
+                "gt" => Some(CompareOp::Gt),
+                "ge" => Some(CompareOp::Ge),
+                "eq" => Some(CompareOp::Eq),
+                "ne" => Some(CompareOp::Ne),
+                _ => None,
+            };
+            let constraint = if let Some(op) = op {
+                let [field, other_field] = <[String; 2]>::try_from(fields).map_err(|_| {
+                    py_schema_error_type!("Field constraint '{}' requires exactly two fields", constraint_type)
+                })?;
+                FieldConstraint::Compare { op, field, other_field }
+            } else {
+                if fields.len() < 2 {
+                    return py_schema_err!("Field constraint '{}' requires at least two fields", constraint_type);
+                }
+                match constraint_type.as_str() {
+                    "one_of" => FieldConstraint::OneOf(fields),
+                    "mutually_exclusive" => FieldConstraint::MutuallyExclusive(fields),
+                    "requires" => {
+                        let mut fields = fields.into_iter();
+                        let field = fields.next().unwrap_or_default();
+                        FieldConstraint::Requires {
+                            field,
+                            required: fields.collect(),
+                        }
+                    }
+                    _ => return py_schema_err!("Invalid field constraint type: '{}'", constraint_type),
+                }
+            };
+            out.push(constraint);
+        }
+        Ok(Self(out))
+    }
+
+    pub fn field_locs<'a>(&self, loc_by_alias: bool) -> FieldLocs<'a> {
+        FieldLocs {
+            enabled: !self.0.is_empty(),
+            loc_by_alias,
+            fields: Vec::new(),
+        }
+    }
+
+    /// Check every constraint against the validated `data`, errors are located at the offending field,
+    /// or at the object itself (with `input` as the input value) when no single field is at fault.
+    pub fn validate<'py>(
+        &self,
+        data: &Bound<'py, PyDict>,
+        input: &(impl Input<'py> + ?Sized),
+        field_locs: &FieldLocs<'_>,
+    ) -> ValResult<()> {
+        let mut errors: Vec<ValLineError> = Vec::new();
+        for constraint in &self.0 {
+            match constraint {
+                FieldConstraint::Compare { op, field, other_field } => {
+                    let (Some(value), Some(other_value)) = (provided(data, field)?, provided(data, other_field)?)
+                    else {
+                        continue;
+                    };
+                    let error_type = match value.rich_compare(&other_value, *op).and_then(|r| r.is_truthy()) {
+                        Ok(true) => continue,
+                        Ok(false) => ErrorType::FieldComparison {
+                            comparison: comparison_str(*op).to_string(),
+                            other_field: other_field.clone(),
+                            context: None,
+                        },
+                        // values which don't support the comparison, e.g. a date and a string, are invalid input
+                        Err(err) if err.is_instance_of::<PyTypeError>(data.py()) => ErrorType::FieldNotComparable {
+                            other_field: other_field.clone(),
+                            error: err.value(data.py()).to_string(),
+                            context: None,
+                        },
+                        Err(err) => return Err(err.into()),
+                    };
+                    errors.push(field_locs.locate(ValLineError::new(error_type, &value), field));
+                }
+                FieldConstraint::OneOf(fields) => {
+                    if !check_mutually_exclusive(data, fields, field_locs, &mut errors)? {
+                        let error_type = ErrorType::FieldsOneOf {
+                            fields: fields_repr(fields),
+                            context: None,
+                        };
+                        errors.push(ValLineError::new(error_type, input));
+                    }
+                }
+                FieldConstraint::MutuallyExclusive(fields) => {
+                    check_mutually_exclusive(data, fields, field_locs, &mut errors)?;
+                }
+                FieldConstraint::Requires { field, required } => {
+                    if provided(data, field)?.is_none() {
+                        continue;
+                    }
+                    for required_field in required {
+                        if provided(data, required_field)?.is_none() {
+                            let error_type = ErrorType::FieldRequires {
+                                other_field: field.clone(),
+                                context: None,
+                            };
+                            errors.push(field_locs.locate(ValLineError::new(error_type, input), required_field));
+                        }
+                    }
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ValError::LineErrors(errors))
+        }
+    }
+}
+
+/// A field counts as provided if it's present in the validated data and isn't `None`.
+fn provided<'py>(data: &Bound<'py, PyDict>, field: &str) -> PyResult<Option<Bound<'py, PyAny>>> {
+    Ok(data.get_item(field)?.filter(|value| !PyAnyMethods::is_none(value)))
+}
+
+/// Add an error for every provided field after the first, returns whether any field was provided.
+fn check_mutually_exclusive(
+    data: &Bound<'_, PyDict>,
+    fields: &[String],
+    field_locs: &FieldLocs<'_>,
+    errors: &mut Vec<ValLineError>,
+) -> PyResult<bool> {
+    let mut first: Option<&String> = None;
+    for field in fields {
+        let Some(value) = provided(data, field)? else {
+            continue;
+        };
+        match first {
+            Some(first_field) => {
+                let error_type = ErrorType::FieldsMutuallyExclusive {
+                    other_field: first_field.clone(),
+                    context: None,
+                };
+                errors.push(field_locs.locate(ValLineError::new(error_type, &value), field));
+            }
+            None => first = Some(field),
+        }
+    }
+    Ok(first.is_some())
+}
+
+fn comparison_str(op: CompareOp) -> &'static str {
+    match op {
+        CompareOp::Lt => "less than",
+        CompareOp::Le => "less than or equal to",
+        CompareOp::Gt => "greater than",
+        CompareOp::Ge => "greater than or equal to",
+        CompareOp::Eq => "equal to",
+        CompareOp::Ne => "not equal to",
+    }
+}
+
+fn fields_repr(fields: &[String]) -> String {
+    fields.iter().map(|f| format!("'{f}'")).collect::<Vec<_>>().join(", ")
+}
@@ -7,6 +7,7 @@ use crate::errors::ValResult;
 use crate::input::{validate_iter_to_set, BorrowInput, ConsumeIterator, Input, ValidatedSet};
 use crate::tools::SchemaDict;
 
+use super::config::StrCollectionParsing;
 use super::list::min_length_check;
 use super::set::set_build;
 use super::validation_state::ValidationState;
@@ -20,6 +21,7 @@ pub struct FrozenSetValidator {
     max_length: Option<usize>,
     name: String,
     fail_fast: bool,
+    str_collection_parsing: StrCollectionParsing,
 }
 
 impl BuildValidator for FrozenSetValidator {
@@ -36,6 +38,9 @@ impl Validator for FrozenSetValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Py<PyAny>> {
+        if let Some(sequence) = input.parse_str_sequence(self.str_collection_parsing)? {
+            return self.validate(py, &sequence, state);
+        }
         let collection = input.validate_frozenset(state.strict_or(self.strict))?.unpack(state);
         let f_set = PyFrozenSet::empty(py)?;
         collection.iterate(ValidateToFrozenSet {
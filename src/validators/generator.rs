@@ -1,12 +1,13 @@
 use std::fmt;
+use std::str::FromStr;
 use std::sync::Arc;
 
 use pyo3::types::{PyDict, PyString};
 use pyo3::{prelude::*, IntoPyObjectExt, PyTraverseError, PyVisit};
 
 use crate::build_tools::ExtraBehavior;
-use crate::errors::{ErrorType, LocItem, ValError, ValResult};
-use crate::input::{BorrowInput, GenericIterator, Input};
+use crate::errors::{ErrorType, InputValue, LocItem, ValError, ValLineError, ValResult};
+use crate::input::{BorrowInput, GenericIterator, Input, JsonStream};
 use crate::py_gc::PyGcTraverse;
 use crate::recursion_guard::RecursionState;
 use crate::tools::SchemaDict;
@@ -187,6 +188,8 @@ impl ValidatorIterator {
         match iterator {
             GenericIterator::PyIterator(ref mut iter) => next!(iter),
             GenericIterator::JsonArray(ref mut iter) => next!(iter),
+            GenericIterator::MsgPackArray(ref mut iter) => next!(iter),
+            GenericIterator::CborArray(ref mut iter) => next!(iter),
         }
     }
 
@@ -195,6 +198,8 @@ impl ValidatorIterator {
         match self.iterator {
             GenericIterator::PyIterator(ref iter) => iter.index(),
             GenericIterator::JsonArray(ref iter) => iter.index(),
+            GenericIterator::MsgPackArray(ref iter) => iter.index(),
+            GenericIterator::CborArray(ref iter) => iter.index(),
         }
     }
 
@@ -213,6 +218,239 @@ impl ValidatorIterator {
     }
 }
 
+/// What to do when an item fails validation in `SchemaValidator.iter_validate_json`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum OnItemError {
+    /// raise a `ValidationError` for the item and stop iterating
+    Raise,
+    /// skip the item
+    Skip,
+    /// skip the item, then raise a `ValidationError` with the errors of all skipped items once the array ends
+    Collect,
+}
+
+impl FromStr for OnItemError {
+    type Err = PyErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "raise" => Ok(Self::Raise),
+            "skip" => Ok(Self::Skip),
+            "collect" => Ok(Self::Collect),
+            s => Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Invalid on_error: `{s}`, expected `raise`, `skip` or `collect`"
+            ))),
+        }
+    }
+}
+
+/// Iterator returned by `SchemaValidator.iter_validate_json`, reads a top-level JSON array from a
+/// `JsonStream` and validates each item as it's requested.
+#[pyclass(module = "pydantic_core._pydantic_core")]
+pub struct JsonArrayValidatorIterator {
+    stream: JsonStream,
+    item_validator: Option<Arc<CombinedValidator>>,
+    min_length: Option<usize>,
+    max_length: Option<usize>,
+    on_error: OnItemError,
+    strict: Option<bool>,
+    extra_behavior: Option<ExtraBehavior>,
+    context: Option<Py<PyAny>>,
+    cache_str: jiter::StringCacheMode,
+    by_alias: Option<bool>,
+    by_name: Option<bool>,
+    recursion_guard: RecursionState,
+    title: Py<PyAny>,
+    hide_input_in_errors: bool,
+    validation_error_cause: bool,
+    // number of items read from the array, and how many of them were valid
+    index: usize,
+    valid_count: usize,
+    errors: Vec<ValLineError>,
+    done: bool,
+}
+
+impl JsonArrayValidatorIterator {
+    /// `stream` must already be positioned after the opening `[` of the array.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        stream: JsonStream,
+        item_validator: Option<Arc<CombinedValidator>>,
+        min_length: Option<usize>,
+        max_length: Option<usize>,
+        on_error: OnItemError,
+        extra: &Extra,
+        title: Py<PyAny>,
+        hide_input_in_errors: bool,
+        validation_error_cause: bool,
+    ) -> Self {
+        Self {
+            stream,
+            item_validator,
+            min_length,
+            max_length,
+            on_error,
+            strict: extra.strict,
+            extra_behavior: extra.extra_behavior,
+            context: extra.context.map(|c| c.clone().unbind()),
+            cache_str: extra.cache_str,
+            by_alias: extra.by_alias,
+            by_name: extra.by_name,
+            recursion_guard: RecursionState::default(),
+            title,
+            hide_input_in_errors,
+            validation_error_cause,
+            index: 0,
+            valid_count: 0,
+            errors: Vec::new(),
+            done: false,
+        }
+    }
+
+    fn next_item(&mut self, py: Python) -> ValResult<Option<Py<PyAny>>> {
+        while !self.done {
+            let Some(item) = self.stream.next_array_item(py, self.index == 0)? else {
+                self.done = true;
+                self.stream.check_end(py)?;
+                if let Some(min_length) = self.min_length {
+                    if self.valid_count < min_length {
+                        return Err(self.collected_with(ValError::new_custom_input(
+                            ErrorType::TooShort {
+                                field_type: "List".to_string(),
+                                min_length,
+                                actual_length: self.valid_count,
+                                context: None,
+                            },
+                            InputValue::Python(self.stream.input(py).clone().unbind()),
+                        )));
+                    }
+                }
+                return match self.errors.is_empty() {
+                    true => Ok(None),
+                    false => Err(ValError::LineErrors(std::mem::take(&mut self.errors))),
+                };
+            };
+            let index = self.index;
+            self.index += 1;
+            if let Some(max_length) = self.max_length {
+                if self.valid_count >= max_length {
+                    self.done = true;
+                    return Err(self.collected_with(ValError::new_custom_input(
+                        ErrorType::TooLong {
+                            field_type: "List".to_string(),
+                            max_length,
+                            actual_length: None,
+                            context: None,
+                        },
+                        InputValue::Python(self.stream.input(py).clone().unbind()),
+                    )));
+                }
+            }
+
+            let Some(ref validator) = self.item_validator else {
+                self.valid_count += 1;
+                return Ok(Some(item.to_object(py)?.unbind()));
+            };
+            let context = self.context.as_ref().map(|c| c.bind(py));
+            let extra = Extra::new(
+                self.strict,
+                self.extra_behavior,
+                None,
+                context,
+                None,
+                InputType::Json,
+                self.cache_str,
+                self.by_alias,
+                self.by_name,
+            );
+            let mut state = ValidationState::new(extra, &mut self.recursion_guard, false.into());
+            match validator.validate(py, &item, &mut state) {
+                Ok(output) => {
+                    self.valid_count += 1;
+                    return Ok(Some(output));
+                }
+                Err(ValError::LineErrors(line_errors)) => {
+                    let line_errors = line_errors.into_iter().map(|err| err.with_outer_location(index));
+                    match self.on_error {
+                        OnItemError::Raise => {
+                            self.done = true;
+                            return Err(ValError::LineErrors(line_errors.collect()));
+                        }
+                        OnItemError::Skip => (),
+                        OnItemError::Collect => self.errors.extend(line_errors),
+                    }
+                }
+                Err(ValError::Omit) => (),
+                Err(err) => {
+                    self.done = true;
+                    return Err(err);
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Errors collected from invalid items followed by `error`.
+    fn collected_with(&mut self, error: ValError) -> ValError {
+        match error {
+            ValError::LineErrors(line_errors) => {
+                let mut errors = std::mem::take(&mut self.errors);
+                errors.extend(line_errors);
+                ValError::LineErrors(errors)
+            }
+            err => err,
+        }
+    }
+}
+
+#[pymethods]
+impl JsonArrayValidatorIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        self.next_item(py).map_err(|e| {
+            // syntax errors stop iteration as well as validation errors
+            self.done = true;
+            ValidationError::from_val_error(
+                py,
+                self.title.clone_ref(py),
+                InputType::Json,
+                e,
+                None,
+                self.hide_input_in_errors,
+                self.validation_error_cause,
+            )
+        })
+    }
+
+    /// Number of items read from the array so far, including any which failed validation.
+    #[getter]
+    fn index(&self) -> usize {
+        self.index
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "JsonArrayValidatorIterator(index={}, schema={:?})",
+            self.index, self.item_validator
+        )
+    }
+
+    fn __str__(&self) -> String {
+        self.__repr__()
+    }
+
+    fn __traverse__(&self, visit: PyVisit<'_>) -> Result<(), PyTraverseError> {
+        self.stream.py_gc_traverse(&visit)?;
+        self.item_validator.py_gc_traverse(&visit)?;
+        self.context.py_gc_traverse(&visit)?;
+        self.title.py_gc_traverse(&visit)?;
+        Ok(())
+    }
+}
+
 /// Owned validator wrapper for use in generators in functions, this can be passed back to python
 /// mid-validation
 pub struct InternalValidator {
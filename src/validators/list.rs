@@ -5,10 +5,12 @@ use pyo3::{prelude::*, IntoPyObjectExt};
 
 use crate::errors::ValResult;
 use crate::input::{
-    no_validator_iter_to_vec, validate_iter_to_vec, BorrowInput, ConsumeIterator, Input, MaxLengthCheck, ValidatedList,
+    no_validator_iter_to_vec, validate_iter_to_vec, BorrowInput, ConsumeIterator, Input, JsonStream, JsonStreamItems,
+    MaxLengthCheck, ValidatedList,
 };
 use crate::tools::SchemaDict;
 
+use super::config::StrCollectionParsing;
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug)]
@@ -19,6 +21,7 @@ pub struct ListValidator {
     max_length: Option<usize>,
     name: OnceLock<String>,
     fail_fast: bool,
+    str_collection_parsing: StrCollectionParsing,
 }
 
 pub fn get_items_schema(
@@ -111,6 +114,7 @@ impl BuildValidator for ListValidator {
             max_length: schema.get_as(pyo3::intern!(py, "max_length"))?,
             name: OnceLock::new(),
             fail_fast: schema.get_as(pyo3::intern!(py, "fail_fast"))?.unwrap_or(false),
+            str_collection_parsing: StrCollectionParsing::from_config(config)?,
         })
         .into())
     }
@@ -118,6 +122,50 @@ impl BuildValidator for ListValidator {
 
 impl_py_gc_traverse!(ListValidator { item_validator });
 
+impl ListValidator {
+    pub fn item_validator(&self) -> Option<&Arc<CombinedValidator>> {
+        self.item_validator.as_ref()
+    }
+
+    pub fn min_length(&self) -> Option<usize> {
+        self.min_length
+    }
+
+    pub fn max_length(&self) -> Option<usize> {
+        self.max_length
+    }
+
+    /// Validate a top-level JSON array item by item as it's read from `stream`, the opening `[` must
+    /// already have been consumed.
+    pub(crate) fn validate_json_stream<'py>(
+        &self,
+        py: Python<'py>,
+        stream: &mut JsonStream,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<Py<PyAny>> {
+        let input = stream.input(py).clone();
+        let mut items = JsonStreamItems::new(py, stream);
+        let max_length_check = MaxLengthCheck::new(self.max_length, "List", &input, None);
+        let output = match self.item_validator {
+            Some(ref v) => validate_iter_to_vec(
+                py,
+                &mut items,
+                DEFAULT_CAPACITY,
+                max_length_check,
+                v,
+                state,
+                self.fail_fast,
+            ),
+            None => no_validator_iter_to_vec(py, &input, &mut items, max_length_check),
+        };
+        // syntax errors anywhere in the document take precedence over validation errors
+        items.finish()?;
+        let output = output?;
+        min_length_check!(input, "List", self.min_length, output);
+        Ok(output.into_py_any(py)?)
+    }
+}
+
 impl Validator for ListValidator {
     fn validate<'py>(
         &self,
@@ -125,6 +173,9 @@ impl Validator for ListValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Py<PyAny>> {
+        if let Some(sequence) = input.parse_str_sequence(self.str_collection_parsing)? {
+            return self.validate(py, &sequence, state);
+        }
         let seq = input.validate_list(state.strict_or(self.strict))?.unpack(state);
 
         let actual_length = seq.len();
@@ -6,18 +6,18 @@ use enum_dispatch::enum_dispatch;
 use jiter::{PartialMode, StringCacheMode};
 
 use pyo3::exceptions::{PyTypeError, PyValueError};
-use pyo3::types::{PyAny, PyDict, PyString, PyTuple, PyType};
+use pyo3::types::{PyAny, PyDict, PyList, PyString, PyTuple, PyType};
 use pyo3::{intern, PyTraverseError, PyVisit};
 use pyo3::{prelude::*, IntoPyObjectExt};
 
 use crate::build_tools::{py_schema_err, py_schema_error_type, ExtraBehavior};
 use crate::definitions::{Definitions, DefinitionsBuilder};
-use crate::errors::{LocItem, ValError, ValResult, ValidationError};
-use crate::input::{Input, InputType, StringMapping};
+use crate::errors::{ErrorType, LocItem, ValError, ValLineError, ValResult, ValidationError};
+use crate::input::{parse_cbor, parse_msgpack, Input, InputType, JsonStream, StringMapping, DEFAULT_CHUNK_SIZE};
 use crate::py_gc::PyGcTraverse;
 use crate::recursion_guard::RecursionState;
-use crate::tools::SchemaDict;
-pub(crate) use config::{TemporalUnitMode, ValBytesMode};
+use crate::tools::{py_err, SchemaDict};
+pub(crate) use config::{StrCollectionParsing, TemporalUnitMode, ValBytesMode};
 
 mod any;
 mod arguments;
@@ -37,6 +37,7 @@ pub(crate) mod decimal;
 mod definitions;
 mod dict;
 mod enum_;
+mod field_constraints;
 mod float;
 mod frozenset;
 mod function;
@@ -67,6 +68,7 @@ mod uuid;
 mod validation_state;
 mod with_default;
 
+use self::generator::{JsonArrayValidatorIterator, OnItemError};
 pub use self::validation_state::{Exactness, ValidationState};
 pub use with_default::DefaultType;
 
@@ -168,7 +170,7 @@ impl SchemaValidator {
     }
 
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (input, *, strict=None, extra=None, from_attributes=None, context=None, self_instance=None, allow_partial=PartialMode::Off, by_alias=None, by_name=None))]
+    #[pyo3(signature = (input, *, strict=None, extra=None, from_attributes=None, context=None, self_instance=None, allow_partial=PartialMode::Off, by_alias=None, by_name=None, max_errors=None))]
     pub fn validate_python(
         &self,
         py: Python,
@@ -181,7 +183,9 @@ impl SchemaValidator {
         allow_partial: PartialMode,
         by_alias: Option<bool>,
         by_name: Option<bool>,
+        max_errors: Option<i64>,
     ) -> PyResult<Py<PyAny>> {
+        let max_errors = check_max_errors(max_errors)?;
         let extra_behavior = extra
             .map(|e| ExtraBehavior::from_str(e.to_str()?).map_err(|err| PyValueError::new_err(err.to_string())))
             .transpose()?;
@@ -199,6 +203,7 @@ impl SchemaValidator {
             allow_partial,
             by_alias,
             by_name,
+            max_errors,
         )
         .map_err(|e| self.prepare_validation_err(py, e, InputType::Python))
     }
@@ -234,6 +239,7 @@ impl SchemaValidator {
             false.into(),
             by_alias,
             by_name,
+            None,
         ) {
             Ok(_) => Ok(true),
             Err(ValError::InternalErr(err)) => Err(err),
@@ -244,7 +250,7 @@ impl SchemaValidator {
     }
 
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (input, *, strict=None, extra=None, context=None, self_instance=None, allow_partial=PartialMode::Off, by_alias=None, by_name=None))]
+    #[pyo3(signature = (input, *, strict=None, extra=None, context=None, self_instance=None, allow_partial=PartialMode::Off, by_alias=None, by_name=None, track_positions=false, max_errors=None))]
     pub fn validate_json(
         &self,
         py: Python,
@@ -256,6 +262,116 @@ impl SchemaValidator {
         allow_partial: PartialMode,
         by_alias: Option<bool>,
         by_name: Option<bool>,
+        track_positions: bool,
+        max_errors: Option<i64>,
+    ) -> PyResult<Py<PyAny>> {
+        let max_errors = check_max_errors(max_errors)?;
+        let extra_behavior = extra
+            .map(|e| ExtraBehavior::from_str(e.to_str()?).map_err(|err| PyValueError::new_err(err.to_string())))
+            .transpose()?;
+
+        let json_either_bytes = match json::validate_json_bytes(input) {
+            Ok(v_match) => v_match.into_inner(),
+            Err(err) => return Err(self.prepare_validation_err(py, err, InputType::Json)),
+        };
+        let json_data = json_either_bytes.as_slice();
+        #[allow(clippy::used_underscore_items)]
+        self._validate_json(
+            py,
+            input,
+            json_data,
+            strict,
+            extra_behavior,
+            context,
+            self_instance,
+            allow_partial,
+            by_alias,
+            by_name,
+            max_errors,
+        )
+        .map_err(|e| {
+            let err = self.prepare_validation_err(py, e, InputType::Json);
+            if track_positions {
+                if let Ok(validation_error) = err.value(py).downcast::<ValidationError>() {
+                    validation_error.borrow_mut().add_json_positions(json_data);
+                }
+            }
+            err
+        })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (reader, *, strict=None, extra=None, context=None, self_instance=None, by_alias=None, by_name=None, chunk_size=DEFAULT_CHUNK_SIZE))]
+    pub fn validate_json_stream(
+        &self,
+        py: Python,
+        reader: &Bound<'_, PyAny>,
+        strict: Option<bool>,
+        extra: Option<&Bound<'_, PyString>>,
+        context: Option<&Bound<'_, PyAny>>,
+        self_instance: Option<&Bound<'_, PyAny>>,
+        by_alias: Option<bool>,
+        by_name: Option<bool>,
+        chunk_size: usize,
+    ) -> PyResult<Py<PyAny>> {
+        let extra_behavior = extra
+            .map(|e| ExtraBehavior::from_str(e.to_str()?).map_err(|err| PyValueError::new_err(err.to_string())))
+            .transpose()?;
+        let mut stream = JsonStream::new(reader, chunk_size)?;
+
+        let r = match self.validator.as_ref() {
+            // a top-level list is validated item by item so the whole document is never held in memory
+            CombinedValidator::List(list_validator) if stream.start_array(py)? => {
+                let mut recursion_guard = RecursionState::default();
+                let mut state = ValidationState::new(
+                    Extra::new(
+                        strict,
+                        extra_behavior,
+                        None,
+                        context,
+                        self_instance,
+                        InputType::Json,
+                        self.cache_str,
+                        by_alias,
+                        by_name,
+                    ),
+                    &mut recursion_guard,
+                    PartialMode::Off,
+                );
+                list_validator.validate_json_stream(py, &mut stream, &mut state)
+            }
+            #[allow(clippy::used_underscore_items)]
+            _ => {
+                let json_data = stream.read_to_end(py)?;
+                self._validate_json(
+                    py,
+                    stream.input(py),
+                    &json_data,
+                    strict,
+                    extra_behavior,
+                    context,
+                    self_instance,
+                    PartialMode::Off,
+                    by_alias,
+                    by_name,
+                    None,
+                )
+            }
+        };
+        r.map_err(|e| self.prepare_validation_err(py, e, InputType::Json))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (input, *, strict=None, extra=None, context=None, by_alias=None, by_name=None))]
+    pub fn validate_json_lines(
+        &self,
+        py: Python,
+        input: &Bound<'_, PyAny>,
+        strict: Option<bool>,
+        extra: Option<&Bound<'_, PyString>>,
+        context: Option<&Bound<'_, PyAny>>,
+        by_alias: Option<bool>,
+        by_name: Option<bool>,
     ) -> PyResult<Py<PyAny>> {
         let extra_behavior = extra
             .map(|e| ExtraBehavior::from_str(e.to_str()?).map_err(|err| PyValueError::new_err(err.to_string())))
@@ -263,15 +379,12 @@ impl SchemaValidator {
 
         let r = match json::validate_json_bytes(input) {
             #[allow(clippy::used_underscore_items)]
-            Ok(v_match) => self._validate_json(
+            Ok(v_match) => self._validate_json_lines(
                 py,
-                input,
                 v_match.into_inner().as_slice(),
                 strict,
                 extra_behavior,
                 context,
-                self_instance,
-                allow_partial,
                 by_alias,
                 by_name,
             ),
@@ -281,7 +394,151 @@ impl SchemaValidator {
     }
 
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (input, *, strict=None, extra=None, context=None, allow_partial=PartialMode::Off, by_alias=None, by_name=None))]
+    #[pyo3(signature = (data, *, strict=None, extra=None, context=None, by_alias=None, by_name=None, on_error="raise", chunk_size=DEFAULT_CHUNK_SIZE))]
+    pub fn iter_validate_json(
+        &self,
+        py: Python,
+        data: &Bound<'_, PyAny>,
+        strict: Option<bool>,
+        extra: Option<&Bound<'_, PyString>>,
+        context: Option<&Bound<'_, PyAny>>,
+        by_alias: Option<bool>,
+        by_name: Option<bool>,
+        on_error: &str,
+        chunk_size: usize,
+    ) -> PyResult<Py<PyAny>> {
+        let CombinedValidator::List(list_validator) = self.validator.as_ref() else {
+            return Err(PyTypeError::new_err("`iter_validate_json` requires a `list` schema"));
+        };
+        let on_error = OnItemError::from_str(on_error)?;
+        let extra_behavior = extra
+            .map(|e| ExtraBehavior::from_str(e.to_str()?).map_err(|err| PyValueError::new_err(err.to_string())))
+            .transpose()?;
+        let mut stream = JsonStream::new(data, chunk_size)?;
+
+        if !stream.start_array(py)? {
+            // not an array, validate the whole document to get the same error as `validate_json`
+            let json_data = stream.read_to_end(py)?;
+            #[allow(clippy::used_underscore_items)]
+            let output = self
+                ._validate_json(
+                    py,
+                    stream.input(py),
+                    &json_data,
+                    strict,
+                    extra_behavior,
+                    context,
+                    None,
+                    PartialMode::Off,
+                    by_alias,
+                    by_name,
+                    None,
+                )
+                .map_err(|e| self.prepare_validation_err(py, e, InputType::Json))?;
+            return Ok(output.bind(py).try_iter()?.into_any().unbind());
+        }
+
+        let extra = Extra::new(
+            strict,
+            extra_behavior,
+            None,
+            context,
+            None,
+            InputType::Json,
+            self.cache_str,
+            by_alias,
+            by_name,
+        );
+        let iterator = JsonArrayValidatorIterator::new(
+            stream,
+            list_validator.item_validator().cloned(),
+            list_validator.min_length(),
+            list_validator.max_length(),
+            on_error,
+            &extra,
+            self.title.clone_ref(py),
+            self.hide_input_in_errors,
+            self.validation_error_cause,
+        );
+        iterator.into_py_any(py)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (input, *, strict=None, extra=None, context=None, self_instance=None, by_alias=None, by_name=None))]
+    pub fn validate_msgpack(
+        &self,
+        py: Python,
+        input: &Bound<'_, PyAny>,
+        strict: Option<bool>,
+        extra: Option<&Bound<'_, PyString>>,
+        context: Option<&Bound<'_, PyAny>>,
+        self_instance: Option<&Bound<'_, PyAny>>,
+        by_alias: Option<bool>,
+        by_name: Option<bool>,
+    ) -> PyResult<Py<PyAny>> {
+        let extra_behavior = extra
+            .map(|e| ExtraBehavior::from_str(e.to_str()?).map_err(|err| PyValueError::new_err(err.to_string())))
+            .transpose()?;
+
+        #[allow(clippy::used_underscore_items)]
+        let r = parse_msgpack(input).and_then(|msgpack_value| {
+            self._validate(
+                py,
+                &msgpack_value,
+                InputType::MsgPack,
+                strict,
+                extra_behavior,
+                None,
+                context,
+                self_instance,
+                PartialMode::Off,
+                by_alias,
+                by_name,
+                None,
+            )
+        });
+        r.map_err(|e| self.prepare_validation_err(py, e, InputType::MsgPack))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (input, *, strict=None, extra=None, context=None, self_instance=None, by_alias=None, by_name=None))]
+    pub fn validate_cbor(
+        &self,
+        py: Python,
+        input: &Bound<'_, PyAny>,
+        strict: Option<bool>,
+        extra: Option<&Bound<'_, PyString>>,
+        context: Option<&Bound<'_, PyAny>>,
+        self_instance: Option<&Bound<'_, PyAny>>,
+        by_alias: Option<bool>,
+        by_name: Option<bool>,
+    ) -> PyResult<Py<PyAny>> {
+        let extra_behavior = extra
+            .map(|e| ExtraBehavior::from_str(e.to_str()?).map_err(|err| PyValueError::new_err(err.to_string())))
+            .transpose()?;
+
+        #[allow(clippy::used_underscore_items)]
+        let r = parse_cbor(input).and_then(|cbor_value| {
+            self._validate(
+                py,
+                &cbor_value,
+                InputType::Cbor,
+                strict,
+                extra_behavior,
+                None,
+                context,
+                self_instance,
+                PartialMode::Off,
+                by_alias,
+                by_name,
+                None,
+            )
+        });
+        r.map_err(|e| self.prepare_validation_err(py, e, InputType::Cbor))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (input, *, strict=None, extra=None, context=None, allow_partial=PartialMode::Off, by_alias=None, by_name=None, nested_delimiter=None, case_sensitive=true, max_errors=None))]
     pub fn validate_strings(
         &self,
         py: Python,
@@ -292,9 +549,24 @@ impl SchemaValidator {
         allow_partial: PartialMode,
         by_alias: Option<bool>,
         by_name: Option<bool>,
+        nested_delimiter: Option<&str>,
+        case_sensitive: bool,
+        max_errors: Option<i64>,
     ) -> PyResult<Py<PyAny>> {
+        let max_errors = check_max_errors(max_errors)?;
         let t = InputType::String;
-        let string_mapping = StringMapping::new_value(input).map_err(|e| self.prepare_validation_err(py, e, t))?;
+        if nested_delimiter == Some("") {
+            return py_err!(PyValueError; "`nested_delimiter` must not be empty");
+        }
+        let (string_mapping, flat_keys) = if nested_delimiter.is_some() || !case_sensitive {
+            let (string_mapping, flat_keys) = StringMapping::new_flat_mapping(input, nested_delimiter, case_sensitive)
+                .map_err(|e| self.prepare_validation_err(py, e, t))?;
+            (string_mapping, Some(flat_keys))
+        } else {
+            let string_mapping =
+                StringMapping::new_value(input, true).map_err(|e| self.prepare_validation_err(py, e, t))?;
+            (string_mapping, None)
+        };
         let extra_behavior = extra
             .map(|e| ExtraBehavior::from_str(e.to_str()?).map_err(|err| PyValueError::new_err(err.to_string())))
             .transpose()?;
@@ -312,9 +584,16 @@ impl SchemaValidator {
             allow_partial,
             by_alias,
             by_name,
+            max_errors,
         ) {
             Ok(r) => Ok(r),
-            Err(e) => Err(self.prepare_validation_err(py, e, t)),
+            Err(e) => {
+                let e = match flat_keys {
+                    Some(flat_keys) => flat_keys.restore_locations(e),
+                    None => e,
+                };
+                Err(self.prepare_validation_err(py, e, t))
+            }
         }
     }
 
@@ -429,6 +708,7 @@ impl SchemaValidator {
         allow_partial: PartialMode,
         by_alias: Option<bool>,
         by_name: Option<bool>,
+        max_errors: Option<usize>,
     ) -> ValResult<Py<PyAny>> {
         let mut recursion_guard = RecursionState::default();
         let mut state = ValidationState::new(
@@ -446,7 +726,22 @@ impl SchemaValidator {
             &mut recursion_guard,
             allow_partial,
         );
-        self.validator.validate(py, input, &mut state)
+        state.max_errors = max_errors;
+        match (self.validator.validate(py, input, &mut state), max_errors) {
+            // validators stop at the first error beyond the limit, it's replaced by the summary
+            (Err(ValError::LineErrors(mut line_errors)), Some(max_errors)) if line_errors.len() > max_errors => {
+                line_errors.truncate(max_errors);
+                line_errors.push(ValLineError::new(
+                    ErrorType::TooManyErrors {
+                        max_errors,
+                        context: None,
+                    },
+                    input,
+                ));
+                Err(ValError::LineErrors(line_errors))
+            }
+            (r, _) => r,
+        }
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -462,6 +757,7 @@ impl SchemaValidator {
         allow_partial: PartialMode,
         by_alias: Option<bool>,
         by_name: Option<bool>,
+        max_errors: Option<usize>,
     ) -> ValResult<Py<PyAny>> {
         let json_value = jiter::JsonValue::parse_with_config(json_data, true, allow_partial)
             .map_err(|e| json::map_json_err(input, e, json_data))?;
@@ -478,9 +774,79 @@ impl SchemaValidator {
             allow_partial,
             by_alias,
             by_name,
+            max_errors,
         )
     }
 
+    /// Validate each non-blank line of `json_data` as a separate JSON document, errors from all lines are
+    /// collected with the line number (starting at 1) as the first item of their location.
+    #[allow(clippy::too_many_arguments)]
+    fn _validate_json_lines(
+        &self,
+        py: Python,
+        json_data: &[u8],
+        strict: Option<bool>,
+        extra_behavior: Option<ExtraBehavior>,
+        context: Option<&Bound<'_, PyAny>>,
+        by_alias: Option<bool>,
+        by_name: Option<bool>,
+    ) -> ValResult<Py<PyAny>> {
+        let mut output: Vec<Py<PyAny>> = Vec::new();
+        let mut errors: Vec<ValLineError> = Vec::new();
+        for (index, line) in json_data.split(|b| *b == b'\n').enumerate() {
+            let line = line.strip_suffix(b"\r").unwrap_or(line);
+            if line.iter().all(u8::is_ascii_whitespace) {
+                continue;
+            }
+            #[allow(clippy::used_underscore_items)]
+            let result = match jiter::JsonValue::parse_with_config(line, true, PartialMode::Off) {
+                Ok(json_value) => self._validate(
+                    py,
+                    &json_value,
+                    InputType::Json,
+                    strict,
+                    extra_behavior,
+                    None,
+                    context,
+                    None,
+                    PartialMode::Off,
+                    by_alias,
+                    by_name,
+                    None,
+                ),
+                Err(e) => {
+                    // report the position in the whole document rather than in the line
+                    let position = jiter::LinePosition::find(line, e.index);
+                    let line_input = PyString::new(py, &String::from_utf8_lossy(line)).into_any();
+                    Err(ValError::new(
+                        ErrorType::JsonInvalid {
+                            error: format!(
+                                "{} at {}",
+                                e.error_type,
+                                jiter::LinePosition::new(index + 1, position.column)
+                            ),
+                            context: None,
+                        },
+                        &line_input,
+                    ))
+                }
+            };
+            match result {
+                Ok(item) => output.push(item),
+                Err(ValError::LineErrors(line_errors)) => {
+                    errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index + 1)));
+                }
+                Err(ValError::Omit) => (),
+                Err(err) => return Err(err),
+            }
+        }
+        if errors.is_empty() {
+            Ok(PyList::new(py, output)?.into_any().unbind())
+        } else {
+            Err(ValError::LineErrors(errors))
+        }
+    }
+
     fn prepare_validation_err(&self, py: Python, error: ValError, input_type: InputType) -> PyErr {
         ValidationError::from_val_error(
             py,
@@ -494,6 +860,15 @@ impl SchemaValidator {
     }
 }
 
+/// `max_errors` has to allow at least one error, otherwise there'd be nothing to report.
+fn check_max_errors(max_errors: Option<i64>) -> PyResult<Option<usize>> {
+    match max_errors.map(usize::try_from) {
+        Some(Ok(max_errors)) if max_errors > 0 => Ok(Some(max_errors)),
+        Some(_) => py_err!(PyValueError; "`max_errors` should be at least 1, got {}", max_errors.unwrap_or_default()),
+        None => Ok(None),
+    }
+}
+
 pub trait BuildValidator: Sized {
     const EXPECTED_TYPE: &'static str;
 
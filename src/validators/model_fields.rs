@@ -17,6 +17,7 @@ use crate::input::{BorrowInput, Input, ValidatedDict, ValidationMatch};
 use crate::lookup_key::LookupKeyCollection;
 use crate::tools::SchemaDict;
 
+use super::field_constraints::{FieldConstraints, FieldLocs};
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug)]
@@ -33,6 +34,7 @@ impl_py_gc_traverse!(Field { validator });
 #[derive(Debug)]
 pub struct ModelFieldsValidator {
     fields: Vec<Field>,
+    field_constraints: FieldConstraints,
     model_name: String,
     extra_behavior: ExtraBehavior,
     extras_validator: Option<Arc<CombinedValidator>>,
@@ -101,8 +103,12 @@ impl BuildValidator for ModelFieldsValidator {
             });
         }
 
+        let field_names: Vec<&str> = fields.iter().map(|f| f.name.as_str()).collect();
+        let field_constraints = FieldConstraints::from_schema(schema, &field_names)?;
+
         Ok(CombinedValidator::ModelFields(Self {
             fields,
+            field_constraints,
             model_name,
             extra_behavior,
             extras_validator,
@@ -177,11 +183,17 @@ impl Validator for ModelFieldsValidator {
             Some(AHashSet::with_capacity(self.fields.len()))
         };
 
+        let errors_before = state.error_count;
+        let mut field_locs = self.field_constraints.field_locs(self.loc_by_alias);
         {
             let state = &mut state.rebind_extra(|extra| extra.data = Some(model_dict.clone()));
             let state = &mut state.scoped_set(|state| &mut state.has_field_error, false);
 
             for field in &self.fields {
+                if state.reached_max_errors(errors_before, errors.len()) {
+                    // stop early, the remaining fields and any extra keys aren't checked
+                    return Err(ValError::LineErrors(errors));
+                }
                 let lookup_key = field
                     .lookup_key_collection
                     .select(validate_by_alias, validate_by_name)?;
@@ -195,6 +207,7 @@ impl Validator for ModelFieldsValidator {
                     }
                     Err(err) => return Err(err),
                 };
+                field_locs.push(&field.name, lookup_key, op_key_value.as_ref().map(|(path, _)| *path));
 
                 let state = &mut state.rebind_extra(|extra| extra.field_name = Some(field.name_py.bind(py).clone()));
 
@@ -372,6 +385,10 @@ impl Validator for ModelFieldsValidator {
             }
         }
 
+        if errors.is_empty() {
+            self.field_constraints.validate(&model_dict, input, &field_locs)?;
+        }
+
         if !errors.is_empty() {
             Err(ValError::LineErrors(errors))
         } else {
@@ -465,6 +482,8 @@ impl Validator for ModelFieldsValidator {
             }
         };
 
+        self.field_constraints.validate(new_data, obj, &FieldLocs::by_name())?;
+
         let new_extra = match &extra_behavior {
             ExtraBehavior::Allow => {
                 let non_extra_data = PyDict::new(py);
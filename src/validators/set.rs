@@ -7,6 +7,7 @@ use crate::errors::ValResult;
 use crate::input::{validate_iter_to_set, BorrowInput, ConsumeIterator, Input, ValidatedSet};
 use crate::tools::SchemaDict;
 
+use super::config::StrCollectionParsing;
 use super::list::min_length_check;
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
@@ -18,6 +19,7 @@ pub struct SetValidator {
     max_length: Option<usize>,
     name: String,
     fail_fast: bool,
+    str_collection_parsing: StrCollectionParsing,
 }
 
 macro_rules! set_build {
@@ -43,6 +45,7 @@ macro_rules! set_build {
                     max_length,
                     name,
                     fail_fast: schema.get_as(pyo3::intern!(py, "fail_fast"))?.unwrap_or(false),
+                    str_collection_parsing: crate::validators::config::StrCollectionParsing::from_config(config)?,
                 }
                 .into(),
             ))
@@ -65,6 +68,9 @@ impl Validator for SetValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Py<PyAny>> {
+        if let Some(sequence) = input.parse_str_sequence(self.str_collection_parsing)? {
+            return self.validate(py, &sequence, state);
+        }
         let collection = input.validate_set(state.strict_or(self.strict))?.unpack(state);
         let set = PySet::empty(py)?;
         collection.iterate(ValidateToSet {
@@ -148,7 +148,7 @@ impl Validator for StrConstrainedValidator {
         }
 
         if let Some(pattern) = &self.pattern {
-            if !pattern.is_match(py, str)? {
+            if !pattern.is_match(py, str, input)? {
                 return Err(ValError::new(
                     ErrorType::StringPatternMismatch {
                         pattern: pattern.pattern.clone(),
@@ -251,11 +251,13 @@ struct Pattern {
 #[derive(Debug, Clone)]
 enum RegexEngine {
     RustRegex(Regex),
+    FancyRegex(fancy_regex::Regex),
     PythonRe(Py<PyAny>),
 }
 
 impl RegexEngine {
     const RUST_REGEX: &'static str = "rust-regex";
+    const FANCY_REGEX: &'static str = "fancy-regex";
     const PYTHON_RE: &'static str = "python-re";
 }
 
@@ -292,6 +294,9 @@ impl Pattern {
                 RegexEngine::RUST_REGEX => {
                     RegexEngine::RustRegex(Regex::new(&pattern_str).map_err(|e| py_schema_error_type!("{}", e))?)
                 }
+                RegexEngine::FANCY_REGEX => RegexEngine::FancyRegex(
+                    fancy_regex::Regex::new(&pattern_str).map_err(|e| py_schema_error_type!("{}", e))?,
+                ),
                 RegexEngine::PYTHON_RE => RegexEngine::PythonRe(re_compile.call1((pattern,))?.into()),
                 _ => return Err(py_schema_error_type!("Invalid regex engine: {}", engine)),
             };
@@ -303,9 +308,20 @@ impl Pattern {
         }
     }
 
-    fn is_match(&self, py: Python<'_>, target: &str) -> PyResult<bool> {
+    fn is_match<'py>(&self, py: Python<'py>, target: &str, input: &(impl Input<'py> + ?Sized)) -> ValResult<bool> {
         match &self.engine {
             RegexEngine::RustRegex(regex) => Ok(regex.is_match(target)),
+            // fancy-regex fails rather than backtracking forever, e.g. when it exceeds the backtrack limit
+            RegexEngine::FancyRegex(regex) => regex.is_match(target).map_err(|e| {
+                ValError::new(
+                    ErrorType::StringPatternError {
+                        pattern: self.pattern.clone(),
+                        error: e.to_string(),
+                        context: None,
+                    },
+                    input,
+                )
+            }),
             RegexEngine::PythonRe(py_regex) => {
                 Ok(!py_regex.call_method1(py, intern!(py, "search"), (target,))?.is_none(py))
             }
@@ -73,7 +73,7 @@ impl TupleValidator {
         item_validators: &[Arc<CombinedValidator>],
         collection_iter: &mut NextCountingIterator<impl Iterator<Item = I>>,
         actual_length: Option<usize>,
-        fail_fast: bool,
+        errors_before: usize,
     ) -> ValResult<()> {
         // Validate the head:
         for validator in item_validators {
@@ -95,7 +95,7 @@ impl TupleValidator {
                     }
                 }
             }
-            if fail_fast && !errors.is_empty() {
+            if self.stop_validating(state, errors_before, errors) {
                 return Ok(());
             }
         }
@@ -119,6 +119,7 @@ impl TupleValidator {
             self.validators.len()
         };
         let mut output = Vec::with_capacity(expected_length);
+        let errors_before = state.error_count;
         if let Some(variable_validator_index) = self.variadic_item_index {
             let (head_validators, [variable_validator, tail_validators @ ..]) =
                 self.validators.split_at(variable_validator_index)
@@ -136,10 +137,10 @@ impl TupleValidator {
                 head_validators,
                 collection_iter,
                 actual_length,
-                self.fail_fast,
+                errors_before,
             )?;
 
-            if self.fail_fast && !errors.is_empty() {
+            if self.stop_validating(state, errors_before, errors) {
                 return Ok(output);
             }
 
@@ -155,7 +156,7 @@ impl TupleValidator {
                         Err(err) => return Err(err),
                     }
 
-                    if self.fail_fast && !errors.is_empty() {
+                    if self.stop_validating(state, errors_before, errors) {
                         return Ok(output);
                     }
                 }
@@ -190,7 +191,7 @@ impl TupleValidator {
                         Err(err) => return Err(err),
                     }
 
-                    if self.fail_fast && !errors.is_empty() {
+                    if self.stop_validating(state, errors_before, errors) {
                         return Ok(output);
                     }
                 }
@@ -205,7 +206,7 @@ impl TupleValidator {
                     tail_validators,
                     &mut NextCountingIterator::new(tail_buffer.into_iter(), index),
                     actual_length,
-                    self.fail_fast,
+                    errors_before,
                 )?;
             }
         } else {
@@ -219,10 +220,10 @@ impl TupleValidator {
                 &self.validators,
                 collection_iter,
                 actual_length,
-                self.fail_fast,
+                errors_before,
             )?;
 
-            if self.fail_fast && !errors.is_empty() {
+            if self.stop_validating(state, errors_before, errors) {
                 return Ok(output);
             }
 
@@ -242,6 +243,16 @@ impl TupleValidator {
         Ok(output)
     }
 
+    /// Whether to stop validating items, either because of `fail_fast` or because `max_errors` has been reached.
+    fn stop_validating(
+        &self,
+        state: &mut ValidationState<'_, '_>,
+        errors_before: usize,
+        errors: &[ValLineError],
+    ) -> bool {
+        (self.fail_fast && !errors.is_empty()) || state.reached_max_errors(errors_before, errors.len())
+    }
+
     fn push_output_item<'py>(
         &self,
         input: &(impl Input<'py> + ?Sized),
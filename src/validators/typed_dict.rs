@@ -17,6 +17,7 @@ use crate::tools::SchemaDict;
 use ahash::AHashSet;
 use jiter::PartialMode;
 
+use super::field_constraints::FieldConstraints;
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug)]
@@ -33,6 +34,7 @@ impl_py_gc_traverse!(TypedDictField { validator });
 #[derive(Debug)]
 pub struct TypedDictValidator {
     fields: Vec<TypedDictField>,
+    field_constraints: FieldConstraints,
     extra_behavior: ExtraBehavior,
     extras_validator: Option<Arc<CombinedValidator>>,
     strict: bool,
@@ -131,8 +133,12 @@ impl BuildValidator for TypedDictValidator {
                 required,
             });
         }
+        let field_names: Vec<&str> = fields.iter().map(|f| f.name.as_str()).collect();
+        let field_constraints = FieldConstraints::from_schema(schema, &field_names)?;
+
         Ok(CombinedValidator::TypedDict(Self {
             fields,
+            field_constraints,
             extra_behavior,
             extras_validator,
             strict,
@@ -183,6 +189,8 @@ impl Validator for TypedDictValidator {
             Some(AHashSet::with_capacity(self.fields.len()))
         };
 
+        let errors_before = state.error_count;
+        let mut field_locs = self.field_constraints.field_locs(self.loc_by_alias);
         {
             let state = &mut state.rebind_extra(|extra| extra.data = Some(output_dict.clone()));
             let state = &mut state.scoped_set(|state| &mut state.has_field_error, false);
@@ -190,6 +198,10 @@ impl Validator for TypedDictValidator {
             let mut fields_set_count: usize = 0;
 
             for field in &self.fields {
+                if state.reached_max_errors(errors_before, errors.len()) {
+                    // stop early, the remaining fields and any extra keys aren't checked
+                    return Err(ValError::LineErrors(errors));
+                }
                 let lookup_key = field
                     .lookup_key_collection
                     .select(validate_by_alias, validate_by_name)?;
@@ -206,6 +218,7 @@ impl Validator for TypedDictValidator {
                     }
                     Err(err) => return Err(err),
                 };
+                field_locs.push(&field.name, lookup_key, op_key_value.as_ref().map(|(path, _)| *path));
                 if let Some((lookup_path, value)) = op_key_value {
                     if let Some(ref mut used_keys) = used_keys {
                         // key is "used" whether or not validation passes, since we want to skip this key in
@@ -386,6 +399,11 @@ impl Validator for TypedDictValidator {
             })??;
         }
 
+        // with partial validation the last field may be incomplete, so constraints between fields can't be checked
+        if errors.is_empty() && !allow_partial.is_active() {
+            self.field_constraints.validate(&output_dict, input, &field_locs)?;
+        }
+
         if errors.is_empty() {
             Ok(output_dict.into())
         } else {
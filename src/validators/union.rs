@@ -11,7 +11,7 @@ use smallvec::SmallVec;
 use crate::build_tools::py_schema_err;
 use crate::build_tools::schema_or_config;
 use crate::common::union::{Discriminator, SMALL_UNION_THRESHOLD};
-use crate::errors::{ErrorType, ToErrorValue, ValError, ValLineError, ValResult};
+use crate::errors::{ErrorType, Location, ToErrorValue, ValError, ValLineError, ValResult};
 use crate::input::{BorrowInput, Input, ValidatedDict};
 use crate::tools::SchemaDict;
 
@@ -159,7 +159,8 @@ impl UnionValidator {
                 Err(ValError::LineErrors(lines)) => {
                     // if we don't yet know this validation will succeed, record the error
                     if best_match.is_none() {
-                        errors.push(choice, label.as_deref(), lines);
+                        // exactness is reset for each member, so it's this member's own
+                        errors.push(choice, label.as_deref(), lines, state.exactness);
                     }
                 }
                 otherwise => return otherwise,
@@ -192,7 +193,7 @@ impl UnionValidator {
 
         for (validator, label) in &self.choices {
             match validator.validate(py, input, state) {
-                Err(ValError::LineErrors(lines)) => errors.push(validator, label.as_deref(), lines),
+                Err(ValError::LineErrors(lines)) => errors.push(validator, label.as_deref(), lines, None),
                 otherwise => return otherwise,
             }
         }
@@ -226,12 +227,20 @@ impl Validator for UnionValidator {
     }
 }
 
+/// How close a failed union member came to validating successfully, worked out from the member's own errors
+/// so nothing is read from validation state which other members could have changed: first whether the input
+/// itself was accepted, i.e. every error is nested inside it, then the exactness reached.
+type Closeness = (bool, Option<Exactness>);
+
 struct ChoiceLineErrors<'a> {
     choice: &'a CombinedValidator,
     label: Option<&'a str>,
     line_errors: Vec<ValLineError>,
+    closeness: Closeness,
 }
 
+// errors for small unions are deliberately kept inline to avoid an allocation
+#[allow(clippy::large_enum_variant)]
 enum MaybeErrors<'a> {
     Custom(&'a CustomError),
     Errors(SmallVec<[ChoiceLineErrors<'a>; SMALL_UNION_THRESHOLD]>),
@@ -245,13 +254,22 @@ impl<'a> MaybeErrors<'a> {
         }
     }
 
-    fn push(&mut self, choice: &'a CombinedValidator, label: Option<&'a str>, line_errors: Vec<ValLineError>) {
+    fn push(
+        &mut self,
+        choice: &'a CombinedValidator,
+        label: Option<&'a str>,
+        line_errors: Vec<ValLineError>,
+        exactness: Option<Exactness>,
+    ) {
+        let input_accepted = line_errors.iter().all(|err| !matches!(err.location, Location::Empty));
+        let closeness = (input_accepted, exactness);
         match self {
             Self::Custom(_) => {}
             Self::Errors(errors) => errors.push(ChoiceLineErrors {
                 choice,
                 label,
                 line_errors,
+                closeness,
             }),
         }
     }
@@ -259,23 +277,46 @@ impl<'a> MaybeErrors<'a> {
     fn into_val_error(self, input: impl ToErrorValue) -> ValError {
         match self {
             Self::Custom(custom_error) => custom_error.as_val_error(input),
-            Self::Errors(errors) => ValError::LineErrors(
-                errors
-                    .into_iter()
-                    .flat_map(
-                        |ChoiceLineErrors {
-                             choice,
-                             label,
-                             line_errors,
-                         }| {
-                            line_errors.into_iter().map(move |err| {
-                                let case_label = label.unwrap_or(choice.get_name());
-                                err.with_outer_location(case_label)
-                            })
-                        },
-                    )
-                    .collect(),
-            ),
+            Self::Errors(errors) => {
+                let union_input = input.to_error_value();
+                // the closest member accepted the input, then reached the highest exactness, then raised the
+                // fewest errors; ties go to the earliest member
+                let closest_index = errors
+                    .iter()
+                    .enumerate()
+                    .max_by_key(|(index, e)| {
+                        (
+                            e.closeness,
+                            std::cmp::Reverse(e.line_errors.len()),
+                            std::cmp::Reverse(*index),
+                        )
+                    })
+                    .map(|(index, _)| index);
+                ValError::LineErrors(
+                    errors
+                        .into_iter()
+                        .enumerate()
+                        .flat_map(
+                            |(
+                                member_index,
+                                ChoiceLineErrors {
+                                    choice,
+                                    label,
+                                    line_errors,
+                                    ..
+                                },
+                            )| {
+                                let closest = closest_index == Some(member_index);
+                                let union_input = &union_input;
+                                line_errors.into_iter().map(move |err| {
+                                    let case_label = label.unwrap_or(choice.get_name());
+                                    err.with_union_member(case_label, member_index, closest, union_input.clone())
+                                })
+                            },
+                        )
+                        .collect(),
+                )
+            }
         }
     }
 }
@@ -389,7 +430,8 @@ impl TaggedUnionValidator {
         if let Ok(Some((tag, validator))) = self.lookup.validate(py, tag) {
             return match validator.validate(py, input, state) {
                 Ok(res) => Ok(res),
-                Err(err) => Err(err.with_outer_location(tag)),
+                // only the member matching the tag is validated, so it's always the closest
+                Err(err) => Err(err.with_union_member(tag, 0, true, input)),
             };
         }
         match self.custom_error {
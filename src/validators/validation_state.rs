@@ -35,6 +35,11 @@ pub struct ValidationState<'a, 'py> {
     // TODO: this should probably be moved directly into the structured types which need it, but that
     // requires some refactoring to make them have knowledge of default (factories).
     pub has_field_error: bool,
+    // Stop collecting errors once more than this many have been found, see `max_errors` on
+    // `SchemaValidator.validate_python`.
+    pub max_errors: Option<usize>,
+    // The number of errors collected so far, kept up to date by validators which collect errors from several items.
+    pub error_count: usize,
     // deliberately make Extra readonly
     extra: Extra<'a, 'py>,
 }
@@ -47,6 +52,8 @@ impl<'a, 'py> ValidationState<'a, 'py> {
             fields_set_count: None,
             allow_partial,
             has_field_error: false,
+            max_errors: None,
+            error_count: 0,
             extra,
         }
     }
@@ -128,6 +135,17 @@ impl<'a, 'py> ValidationState<'a, 'py> {
         *self.fields_set_count.get_or_insert(0) += fields_set_count;
     }
 
+    /// Update `error_count` for a validator which started collecting errors when the count was `count_before`
+    /// and has now collected `collected` errors. Returns `true` if the validator should stop because
+    /// `max_errors` has been exceeded, the errors beyond the limit are dropped by `SchemaValidator`.
+    ///
+    /// Setting rather than incrementing the count means errors which are later discarded, e.g. by a union
+    /// member which fails, are forgotten once the validator collecting errors around them moves on.
+    pub fn reached_max_errors(&mut self, count_before: usize, collected: usize) -> bool {
+        self.error_count = count_before + collected;
+        collected > 0 && self.max_errors.is_some_and(|max_errors| self.error_count > max_errors)
+    }
+
     pub fn cache_str(&self) -> StringCacheMode {
         self.extra.cache_str
     }
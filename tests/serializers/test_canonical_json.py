@@ -0,0 +1,113 @@
+import json
+from decimal import Decimal
+
+import pytest
+
+from pydantic_core import PydanticSerializationError, SchemaSerializer, core_schema, to_json
+
+
+def test_rfc_example():
+    # https://www.rfc-editor.org/rfc/rfc8785#section-3.2.2
+    value = {
+        'numbers': [333333333.33333329, 1e30, 4.50, 2e-3, 0.000000000000000000000000001],
+        'string': '€$\x0f\nA\'B"\\\\"/',
+        'literals': [None, True, False],
+    }
+    assert to_json(value, canonical=True) == (
+        '{"literals":[null,true,false],"numbers":[333333333.3333333,1e+30,4.5,0.002,1e-27],'
+        '"string":"€$\\u000f\\nA\'B\\"\\\\\\\\\\"/"}'
+    ).encode()
+
+
+def test_key_order():
+    # https://www.rfc-editor.org/rfc/rfc8785#section-3.2.3, keys are compared as UTF-16 code units
+    value = {'€': 1, '\r': 2, 'דּ': 3, '1': 4, '\U0001f600': 5, '\u0080': 6, 'ö': 7}
+    output = json.loads(to_json(value, canonical=True))
+    assert list(output.values()) == [2, 4, 6, 7, 1, 5, 3]
+
+
+@pytest.mark.parametrize(
+    'value,expected',
+    [
+        (0.0, '0'),
+        (-0.0, '0'),
+        (3.0, '3'),
+        (-1.5, '-1.5'),
+        (1e20, '100000000000000000000'),
+        (1e21, '1e+21'),
+        (1e-6, '0.000001'),
+        (1e-7, '1e-7'),
+        (5e-324, '5e-324'),
+        (1.7976931348623157e308, '1.7976931348623157e+308'),
+        (123, '123'),
+        (2**53, '9007199254740992'),
+        (2**60, '1152921504606847000'),
+        (10**23, '1e+23'),
+        (-(10**30), '-1e+30'),
+    ],
+)
+def test_numbers(value, expected):
+    assert to_json(value, canonical=True) == expected.encode()
+
+
+def test_ignores_sort_keys():
+    assert to_json({'b': {'d': 1, 'c': 2}, 'a': 3}, canonical=True, sort_keys='top-level') == (
+        b'{"a":3,"b":{"c":2,"d":1}}'
+    )
+
+
+def test_insertion_order_independent():
+    a = {'x': {'b': [1, {'d': 1, 'c': 2}], 'a': None}, 'w': 1.0}
+    b = {'w': 1, 'x': {'a': None, 'b': [1, {'c': 2, 'd': 1}]}}
+    assert to_json(a, canonical=True) == to_json(b, canonical=True) == b'{"w":1,"x":{"a":null,"b":[1,{"c":2,"d":1}]}}'
+
+
+def test_model_fields():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'zeta': core_schema.typed_dict_field(core_schema.float_schema()),
+                'alpha': core_schema.typed_dict_field(core_schema.decimal_schema()),
+                'mid': core_schema.typed_dict_field(
+                    core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema())
+                ),
+            }
+        )
+    )
+    value = {'zeta': 2.50, 'alpha': Decimal('1.10'), 'mid': {'y': 1, 'x': 2}}
+    assert s.to_json(value) == b'{"zeta":2.5,"alpha":"1.10","mid":{"y":1,"x":2}}'
+    assert s.to_json(value, canonical=True) == b'{"alpha":"1.10","mid":{"x":2,"y":1},"zeta":2.5}'
+
+
+def test_non_finite():
+    with pytest.raises(PydanticSerializationError, match='`NaN` is not permitted in canonical JSON'):
+        to_json(float('nan'), canonical=True)
+    with pytest.raises(PydanticSerializationError, match='`-Infinity` is not permitted in canonical JSON'):
+        to_json([float('-inf')], canonical=True)
+
+    # `inf_nan_mode='null'` writes null instead
+    assert to_json(float('inf'), canonical=True, inf_nan_mode='null') == b'null'
+
+
+def test_duplicate_keys():
+    with pytest.raises(PydanticSerializationError, match='Duplicate key `1` is not permitted in canonical JSON'):
+        to_json({1: 'a', '1': 'b'}, canonical=True)
+    with pytest.raises(PydanticSerializationError, match='Duplicate key `a` is not permitted in canonical JSON'):
+        to_json({'x': [{'a': 1, b'a': 2}]}, canonical=True)
+
+
+@pytest.mark.parametrize('kwargs', [{'indent': 2}, {'ensure_ascii': True}])
+def test_invalid_combination(kwargs):
+    with pytest.raises(ValueError, match='`canonical` cannot be combined with `indent` or `ensure_ascii`'):
+        to_json({'a': 1}, canonical=True, **kwargs)
+
+
+def test_fp():
+    chunks = []
+
+    class Writer:
+        def write(self, chunk):
+            chunks.append(chunk)
+
+    to_json({'b': 1, 'a': 2}, canonical=True, fp=Writer())
+    assert b''.join(chunks) == b'{"a":2,"b":1}'
@@ -0,0 +1,118 @@
+import dataclasses
+from datetime import date
+from decimal import Decimal
+
+import pytest
+
+from pydantic_core import (
+    PydanticSerializationError,
+    SchemaSerializer,
+    SchemaValidator,
+    ValidationError,
+    core_schema,
+)
+
+
+def pair(schema, config=None):
+    return SchemaSerializer(schema, config), SchemaValidator(schema, config)
+
+
+class MyModel:
+    __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    def __eq__(self, other):
+        return type(other) is type(self) and self.__dict__ == other.__dict__
+
+
+@pytest.mark.parametrize(
+    'schema,value',
+    [
+        (core_schema.int_schema(), 1),
+        (core_schema.list_schema(core_schema.date_schema()), [date(2024, 1, 1)]),
+        (core_schema.dict_schema(core_schema.int_schema(), core_schema.decimal_schema()), {1: Decimal('1.5')}),
+        (core_schema.tuple_schema([core_schema.str_schema(), core_schema.bytes_schema()]), ('a', b'b')),
+    ],
+)
+def test_ok(schema, value):
+    s, v = pair(schema)
+    assert s.check_roundtrip(value, v) is None
+
+
+def test_plain_serializer_divergence():
+    schema = core_schema.dict_schema(
+        core_schema.str_schema(),
+        core_schema.list_schema(
+            core_schema.int_schema(
+                serialization=core_schema.plain_serializer_function_ser_schema(lambda x: x * 2)
+            )
+        ),
+    )
+    s, v = pair(schema)
+    assert s.check_roundtrip({'a': [0, 0], 'b': [0, 1]}, v) == ('b', 1)
+    assert s.check_roundtrip({'a': [0]}, v) is None
+
+
+def test_root_divergence():
+    s, v = pair(core_schema.str_schema(serialization=core_schema.plain_serializer_function_ser_schema(str.upper)))
+    assert s.check_roundtrip('a', v) == ()
+
+
+def test_length_divergence():
+    schema = core_schema.list_schema(
+        core_schema.int_schema(),
+        serialization=core_schema.plain_serializer_function_ser_schema(lambda x: x[:1]),
+    )
+    s, v = pair(schema)
+    assert s.check_roundtrip([1, 2], v) == (1,)
+
+
+def test_model():
+    schema = core_schema.model_schema(
+        MyModel,
+        core_schema.model_fields_schema(
+            {
+                'a': core_schema.model_field(
+                    core_schema.float_schema(
+                        serialization=core_schema.plain_serializer_function_ser_schema(round)
+                    )
+                ),
+                'b': core_schema.model_field(core_schema.str_schema()),
+            }
+        ),
+    )
+    s, v = pair(schema)
+    m = v.validate_python({'a': 1.5, 'b': 'x'})
+    assert s.check_roundtrip(m, v) == ('a',)
+    m = v.validate_python({'a': 1.0, 'b': 'x'})
+    assert s.check_roundtrip(m, v) is None
+
+
+def test_dataclass_alias():
+    @dataclasses.dataclass
+    class Foo:
+        a: int
+
+    schema = core_schema.dataclass_schema(
+        Foo,
+        core_schema.dataclass_args_schema(
+            'Foo', [core_schema.dataclass_field(name='a', schema=core_schema.int_schema(), serialization_alias='A')]
+        ),
+        ['a'],
+    )
+    s, v = pair(schema)
+    with pytest.raises(ValidationError, match='Field required'):
+        s.check_roundtrip(Foo(a=1), v, by_alias=True)
+    assert s.check_roundtrip(Foo(a=1), v, by_alias=False) is None
+
+
+def test_invalid_json():
+    s, v = pair(core_schema.int_schema(serialization=core_schema.plain_serializer_function_ser_schema(str)))
+    assert s.check_roundtrip(1, v) is None
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        s.check_roundtrip(1, v, strict=True)
+
+
+def test_serialization_warning():
+    s, v = pair(core_schema.int_schema())
+    with pytest.raises(PydanticSerializationError, match='Expected `int`'):
+        s.check_roundtrip('x', v)
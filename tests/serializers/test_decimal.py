@@ -61,3 +61,54 @@ def test_any_decimal_key():
 
     assert v.to_python(input_value, mode='json') == {'123.456': 1}
     assert v.to_json(input_value) == b'{"123.456":1}'
+
+
+def test_decimal_number():
+    v = SchemaSerializer(core_schema.decimal_schema(), {'ser_json_decimal': 'number'})
+    big = Decimal('123456789123456789123456789.123456789123456789123456789')
+    assert v.to_json(big) == b'123456789123456789123456789.123456789123456789123456789'
+    assert v.to_json(Decimal('1.10')) == b'1.10'
+    assert v.to_json(Decimal('1E+2')) == b'1E+2'
+    assert v.to_json(Decimal('-0')) == b'-0'
+    assert v.to_json(Decimal('-0.00')) == b'-0.00'
+    # no JSON-compatible python type holds the exact value, so JSON mode `to_python` still gives a string
+    assert v.to_python(big, mode='json') == str(big)
+    assert v.to_python({'a': Decimal('-0')}, mode='json') == {'a': '-0'}
+    # binary formats have no exact number type
+    assert v.to_msgpack(Decimal('1.5')) == SchemaSerializer(core_schema.str_schema()).to_msgpack('1.5')
+
+    # dict keys are always strings
+    v = SchemaSerializer(
+        core_schema.dict_schema(core_schema.decimal_schema(), core_schema.decimal_schema()),
+        {'ser_json_decimal': 'number'},
+    )
+    assert v.to_json({Decimal('1.5'): Decimal('2.5')}) == b'{"1.5":2.5}'
+
+
+@pytest.mark.parametrize(
+    'inf_nan_mode,expected',
+    [
+        ('null', b'[null,null,null]'),
+        ('constants', b'[NaN,Infinity,-Infinity]'),
+        ('strings', b'["NaN","Infinity","-Infinity"]'),
+    ],
+)
+def test_decimal_number_non_finite(inf_nan_mode, expected):
+    v = SchemaSerializer(
+        core_schema.list_schema(core_schema.decimal_schema()),
+        {'ser_json_decimal': 'number', 'ser_json_inf_nan': inf_nan_mode},
+    )
+    assert v.to_json([Decimal('NaN'), Decimal('Infinity'), Decimal('-Infinity')]) == expected
+
+
+def test_decimal_number_any():
+    v = SchemaSerializer(core_schema.any_schema(), {'ser_json_decimal': 'number'})
+    assert v.to_json({'a': [Decimal('3.14')]}) == b'{"a":[3.14]}'
+
+
+def test_decimal_number_float_format():
+    v = SchemaSerializer(
+        core_schema.decimal_schema(float_format='fixed', float_precision=2), {'ser_json_decimal': 'number'}
+    )
+    assert v.to_json(Decimal('3')) == b'3.00'
+    assert v.to_python(Decimal('3'), mode='json') == '3.00'
@@ -0,0 +1,142 @@
+import dataclasses
+import json
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaSerializer, core_schema
+
+
+class BasicModel:
+    def __init__(self, **kwargs):
+        for key, value in kwargs.items():
+            setattr(self, key, value)
+
+
+@pytest.mark.parametrize(
+    'predicate,value,excluded',
+    [
+        ('none', None, True),
+        ('none', 0, False),
+        ('empty', '', True),
+        ('empty', [], True),
+        ('empty', {}, True),
+        ('empty', b'', True),
+        ('empty', 'x', False),
+        ('empty', [None], False),
+        ('empty', None, False),
+        ('empty', 0, False),
+        ('falsy', 0, True),
+        ('falsy', '', True),
+        ('falsy', None, True),
+        ('falsy', 'x', False),
+        ({'equals': 'draft'}, 'draft', True),
+        ({'equals': 'draft'}, 'published', False),
+        ({'equals': 0}, 0.0, True),
+        ({'in': [None, 'n/a', -1]}, 'n/a', True),
+        ({'in': [None, 'n/a', -1]}, -1, True),
+        ({'in': [None, 'n/a', -1]}, 1, False),
+        ({'in': ('a', 'b')}, 'b', True),
+    ],
+)
+def test_field_predicates(predicate, value, excluded):
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            BasicModel,
+            core_schema.model_fields_schema(
+                {
+                    'a': core_schema.model_field(core_schema.any_schema(), serialization_exclude_if=predicate),
+                    'b': core_schema.model_field(core_schema.int_schema()),
+                }
+            ),
+        )
+    )
+    expected = {'b': 1} if excluded else {'a': value, 'b': 1}
+    m = BasicModel(a=value, b=1)
+    assert s.to_python(m) == expected
+    assert json.loads(s.to_json(m)) == json.loads(json.dumps(expected, default=bytes.decode))
+
+
+def test_dataclass():
+    @dataclasses.dataclass
+    class Foo:
+        a: list
+        b: str
+
+    s = SchemaSerializer(
+        core_schema.dataclass_schema(
+            Foo,
+            core_schema.dataclass_args_schema(
+                'Foo',
+                [
+                    core_schema.dataclass_field(
+                        name='a', schema=core_schema.any_schema(), serialization_exclude_if='empty'
+                    ),
+                    core_schema.dataclass_field(
+                        name='b', schema=core_schema.str_schema(), serialization_exclude_if={'equals': 'x'}
+                    ),
+                ],
+            ),
+            ['a', 'b'],
+        )
+    )
+    assert s.to_python(Foo(a=[], b='x')) == {}
+    assert s.to_json(Foo(a=[1], b='y')) == b'{"a":[1],"b":"y"}'
+
+
+def test_model_extras():
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            BasicModel,
+            core_schema.model_fields_schema(
+                {'a': core_schema.model_field(core_schema.any_schema())},
+                extra_behavior='allow',
+                extras_exclude_if='empty',
+            ),
+            extra_behavior='allow',
+        )
+    )
+    m = BasicModel(a=[], __pydantic_extra__={'b': [], 'c': '', 'd': [1]})
+    assert s.to_python(m) == {'a': [], 'd': [1]}
+    assert s.to_json(m) == b'{"a":[],"d":[1]}'
+
+
+def test_typed_dict_extras():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {'a': core_schema.typed_dict_field(core_schema.int_schema())},
+            extra_behavior='allow',
+            extras_exclude_if=lambda v: v == 42,
+        )
+    )
+    assert s.to_python({'a': 42, 'b': 42, 'c': 1}) == {'a': 42, 'c': 1}
+    assert s.to_json({'a': 42, 'b': 42, 'c': 1}) == b'{"a":42,"c":1}'
+
+
+def test_extras_exclude_if_requires_allow():
+    with pytest.raises(SchemaError, match='extras_exclude_if can only be used if extra_behavior=allow'):
+        SchemaSerializer(
+            core_schema.typed_dict_schema(
+                {'a': core_schema.typed_dict_field(core_schema.int_schema())}, extras_exclude_if='none'
+            )
+        )
+
+
+def test_dict_values():
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.str_schema(), values_exclude_if='falsy'))
+    assert s.to_python({'a': 0, 'b': 1, 'c': [], 'd': 'x'}) == {'b': 1, 'd': 'x'}
+    assert s.to_python({'a': 0, 'b': 1}, mode='json') == {'b': 1}
+    assert s.to_json({'a': 0, 'b': 1, 'c': None}) == b'{"b":1}'
+
+
+@pytest.mark.parametrize(
+    'predicate,message',
+    [
+        ('nope', 'Invalid exclude_if predicate: `nope`'),
+        ({'equals': 1, 'in': [1]}, 'Invalid exclude_if value'),
+        ({'contains': 1}, 'Invalid exclude_if value'),
+        (1, 'Invalid exclude_if value'),
+    ],
+)
+def test_invalid(predicate, message):
+    with pytest.raises(SchemaError, match=message):
+        SchemaSerializer(core_schema.dict_schema(values_exclude_if=predicate))
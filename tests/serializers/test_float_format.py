@@ -0,0 +1,109 @@
+from decimal import Decimal
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaSerializer, core_schema
+
+
+@pytest.mark.parametrize(
+    'float_format,precision,value,expected',
+    [
+        ('fixed', 2, 1.5, '1.50'),
+        ('fixed', 2, 0.125, '0.12'),
+        ('fixed', 0, 2.5, '2'),
+        ('fixed', None, 1.0, '1.000000'),
+        ('fixed', 3, -0.0005, '-0.001'),
+        ('scientific', 2, 1500.0, '1.50e+03'),
+        ('scientific', 3, -0.000123456, '-1.235e-04'),
+        ('scientific', None, 1.0, '1.000000e+00'),
+        ('shortest', 2, 0.1 + 0.2, '0.3'),
+        ('shortest', 2, 2.675, '2.67'),
+        ('shortest', 2, 1.5, '1.5'),
+        ('shortest', None, 0.1 + 0.2, '0.30000000000000004'),
+    ],
+)
+def test_float(float_format, precision, value, expected):
+    s = SchemaSerializer(core_schema.float_schema(float_format=float_format, float_precision=precision))
+    assert s.to_json(value) == expected.encode()
+    assert s.to_python(value, mode='json') == float(expected)
+    # python mode is unchanged
+    assert s.to_python(value) is value
+
+
+def test_config():
+    config = {'ser_json_float_format': 'fixed', 'ser_json_float_precision': 1}
+    s = SchemaSerializer(core_schema.list_schema(core_schema.float_schema()), config)
+    assert s.to_json([1.0, 2.25, 3]) == b'[1.0,2.2,3.0]'
+    assert s.to_python([1.0, 2.25], mode='json') == [1.0, 2.2]
+
+    # the schema takes priority over the config
+    s = SchemaSerializer(core_schema.float_schema(float_precision=3), config)
+    assert s.to_json(2.25) == b'2.250'
+    s = SchemaSerializer(core_schema.float_schema(float_format='shortest'), config)
+    assert s.to_json(2.25) == b'2.2'
+
+
+def test_any_follows_config():
+    s = SchemaSerializer(
+        core_schema.any_schema(), {'ser_json_float_format': 'scientific', 'ser_json_float_precision': 1}
+    )
+    assert s.to_json([1234.0, Decimal('1234'), {2.0: 1}]) == b'[1.2e+03,"1.2e+3",{"2.0e+00":1}]'
+    assert s.to_python([1234.0, Decimal('1234')], mode='json') == [1200.0, '1.2e+3']
+
+
+def test_dict_keys():
+    key_schema = core_schema.float_schema(float_format='fixed', float_precision=2)
+    s = SchemaSerializer(core_schema.dict_schema(key_schema, core_schema.int_schema()))
+    assert s.to_json({1.5: 1}) == b'{"1.50":1}'
+    assert s.to_python({1.5: 1}, mode='json') == {'1.50': 1}
+
+
+@pytest.mark.parametrize(
+    'float_format,precision,value,expected',
+    [
+        ('fixed', 2, Decimal('1.005'), '1.00'),
+        ('fixed', 2, Decimal('3'), '3.00'),
+        ('scientific', 2, Decimal('1234'), '1.23e+3'),
+        ('shortest', 2, Decimal('1.10'), '1.10'),
+        ('shortest', 2, Decimal('1.23456'), '1.23'),
+        ('shortest', 2, Decimal('NaN'), 'NaN'),
+        ('shortest', 30, Decimal('0.' + '1' * 40), '0.' + '1' * 30),
+        ('shortest', 3, Decimal('1' * 30 + '.12345'), '1' * 30 + '.123'),
+    ],
+)
+def test_decimal(float_format, precision, value, expected):
+    s = SchemaSerializer(core_schema.decimal_schema(float_format=float_format, float_precision=precision))
+    assert s.to_json(value) == f'"{expected}"'.encode()
+    assert s.to_python(value, mode='json') == expected
+    assert s.to_python(value) is value
+
+
+def test_non_finite():
+    s = SchemaSerializer(
+        core_schema.list_schema(core_schema.float_schema(float_format='fixed')), {'ser_json_inf_nan': 'strings'}
+    )
+    assert s.to_json([float('nan'), float('-inf')]) == b'["NaN","-Infinity"]'
+
+
+def test_binary_formats_and_canonical():
+    s = SchemaSerializer(core_schema.float_schema(float_format='fixed', float_precision=1))
+    assert s.to_msgpack(2.25) == SchemaSerializer(core_schema.float_schema()).to_msgpack(2.2)
+    assert s.to_json(2.0, canonical=True) == b'2'
+
+
+@pytest.mark.parametrize(
+    'schema,config',
+    [
+        (core_schema.float_schema(float_precision=-1), None),
+        (core_schema.decimal_schema(float_precision=-1), None),
+        (core_schema.any_schema(), {'ser_json_float_precision': -1}),
+    ],
+)
+def test_negative_precision(schema, config):
+    with pytest.raises(SchemaError, match='`float_precision` should be a non-negative integer, got -1'):
+        SchemaSerializer(schema, config)
+
+
+def test_invalid_format():
+    with pytest.raises(SchemaError, match='Invalid FloatFormatMode serialization mode: `nope`'):
+        SchemaSerializer(core_schema.float_schema(float_format='nope'))
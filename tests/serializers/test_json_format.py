@@ -0,0 +1,88 @@
+import io
+import json
+
+import pytest
+
+from pydantic_core import SchemaSerializer, core_schema, to_json
+
+VALUE = {'a': [1, 2.5, [3, 'x'], {'b': []}], 'c': 'é', 'd': [], 'e': [[1, 2], [None]]}
+
+
+@pytest.mark.parametrize(
+    'separators,indent',
+    [
+        ((',', ':'), None),
+        ((', ', ': '), None),
+        ((' , ', ' : '), None),
+        ((',', ': '), 2),
+        ((', ', ': '), 4),
+        ((';', '='), None),
+    ],
+)
+def test_separators_match_stdlib(separators, indent):
+    expected = json.dumps(VALUE, separators=separators, indent=indent, ensure_ascii=False).encode()
+    if indent is not None:
+        # like `json.dumps`, but without the trailing space of the item separator at line ends
+        expected = expected.replace(b' \n', b'\n')
+    assert to_json(VALUE, separators=separators, indent=indent) == expected
+
+
+def test_default_separators():
+    assert to_json(VALUE, indent=2, separators=(', ', ': ')) == to_json(VALUE, indent=2)
+    assert to_json(VALUE, separators=(',', ':')) == to_json(VALUE)
+
+
+def test_trailing_newline():
+    assert to_json([1], trailing_newline=True) == b'[1]\n'
+    assert to_json({'a': 1}, indent=2, trailing_newline=True) == b'{\n  "a": 1\n}\n'
+    assert to_json({'a': 1}, canonical=True, trailing_newline=True) == b'{"a":1}\n'
+
+    fp = io.StringIO()
+    to_json({'a': 1}, trailing_newline=True, fp=fp)
+    assert fp.getvalue() == '{"a":1}\n'
+
+
+def test_inline_scalar_arrays():
+    assert to_json(VALUE, indent=2, inline_scalar_arrays=True).decode() == (
+        '{\n'
+        '  "a": [\n'
+        '    1,\n'
+        '    2.5,\n'
+        '    [3, "x"],\n'
+        '    {\n'
+        '      "b": []\n'
+        '    }\n'
+        '  ],\n'
+        '  "c": "é",\n'
+        '  "d": [],\n'
+        '  "e": [\n'
+        '    [1, 2],\n'
+        '    [null]\n'
+        '  ]\n'
+        '}'
+    )
+    assert to_json({'a': [1, 2]}, indent=2, separators=(',', ': '), inline_scalar_arrays=True) == (
+        b'{\n  "a": [1,2]\n}'
+    )
+    # without `indent` arrays are always on one line
+    assert to_json(VALUE, inline_scalar_arrays=True) == to_json(VALUE)
+
+
+def test_ensure_ascii():
+    assert to_json(VALUE, ensure_ascii=True, separators=(', ', ': ')) == json.dumps(VALUE).encode()
+    assert to_json(['é', [1]], ensure_ascii=True, indent=2, inline_scalar_arrays=True) == (
+        b'[\n  "\\u00e9",\n  [1]\n]'
+    )
+
+
+def test_schema_serializer():
+    s = SchemaSerializer(core_schema.list_schema(core_schema.list_schema(core_schema.int_schema())))
+    assert s.to_json([[1, 2], [3]], indent=1, inline_scalar_arrays=True, trailing_newline=True) == (
+        b'[\n [1, 2],\n [3]\n]\n'
+    )
+    assert s.to_json([[1, 2], [3]], separators=('; ', '=')) == b'[[1; 2]; [3]]'
+
+
+def test_canonical_separators():
+    with pytest.raises(ValueError, match='`canonical` cannot be combined with `separators`'):
+        to_json({'a': 1}, canonical=True, separators=(', ', ': '))
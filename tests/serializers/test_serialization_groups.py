@@ -0,0 +1,119 @@
+import dataclasses
+import json
+
+import pytest
+
+from pydantic_core import SchemaSerializer, core_schema
+
+
+class BasicModel:
+    def __init__(self, **kwargs):
+        for key, value in kwargs.items():
+            setattr(self, key, value)
+
+
+def user_schema():
+    return core_schema.model_schema(
+        BasicModel,
+        core_schema.model_fields_schema(
+            {
+                'id': core_schema.model_field(core_schema.int_schema()),
+                'name': core_schema.model_field(core_schema.str_schema(), serialization_groups=['public', 'admin']),
+                'email': core_schema.model_field(core_schema.str_schema(), serialization_groups=['admin']),
+                'password_hash': core_schema.model_field(core_schema.str_schema(), serialization_groups=['internal']),
+            }
+        ),
+    )
+
+
+def user():
+    return BasicModel(id=1, name='sam', email='sam@example.com', password_hash='x')
+
+
+@pytest.mark.parametrize(
+    'groups,expected',
+    [
+        (None, {'id': 1, 'name': 'sam', 'email': 'sam@example.com', 'password_hash': 'x'}),
+        ({'public'}, {'id': 1, 'name': 'sam'}),
+        ({'admin'}, {'id': 1, 'name': 'sam', 'email': 'sam@example.com'}),
+        ({'public', 'internal'}, {'id': 1, 'name': 'sam', 'password_hash': 'x'}),
+        (set(), {'id': 1}),
+    ],
+)
+def test_model(groups, expected):
+    s = SchemaSerializer(user_schema())
+    assert s.to_python(user(), groups=groups) == expected
+    assert s.to_python(user(), groups=groups, mode='json') == expected
+    assert json.loads(s.to_json(user(), groups=groups)) == expected
+
+
+def test_nested():
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            BasicModel,
+            core_schema.model_fields_schema(
+                {
+                    'owner': core_schema.model_field(user_schema()),
+                    'members': core_schema.model_field(core_schema.list_schema(user_schema())),
+                    'audit_log': core_schema.model_field(core_schema.any_schema(), serialization_groups=['internal']),
+                }
+            ),
+        )
+    )
+    team = BasicModel(owner=user(), members=[user()], audit_log=['created'])
+    expected = {'owner': {'id': 1, 'name': 'sam'}, 'members': [{'id': 1, 'name': 'sam'}]}
+    assert s.to_python(team, groups={'public'}) == expected
+    assert json.loads(s.to_json(team, groups={'public'})) == expected
+
+
+def test_typed_dict():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema(), serialization_groups=['public']),
+                'b': core_schema.typed_dict_field(core_schema.int_schema(), serialization_groups=['internal']),
+            }
+        )
+    )
+    assert s.to_python({'a': 1, 'b': 2}, groups={'public'}) == {'a': 1}
+    assert s.to_json({'a': 1, 'b': 2}, groups={'internal'}) == b'{"b":2}'
+
+
+def test_dataclass():
+    @dataclasses.dataclass
+    class Foo:
+        a: int
+        b: int
+
+    s = SchemaSerializer(
+        core_schema.dataclass_schema(
+            Foo,
+            core_schema.dataclass_args_schema(
+                'Foo',
+                [
+                    core_schema.dataclass_field(name='a', schema=core_schema.int_schema()),
+                    core_schema.dataclass_field(
+                        name='b', schema=core_schema.int_schema(), serialization_groups=['internal']
+                    ),
+                ],
+            ),
+            ['a', 'b'],
+        )
+    )
+    assert s.to_python(Foo(a=1, b=2), groups={'public'}) == {'a': 1}
+    assert s.to_json(Foo(a=1, b=2), groups={'internal'}) == b'{"a":1,"b":2}'
+
+
+def test_wrap_serializer_keeps_groups():
+    def wrap(value, handler):
+        return {'wrapped': handler(value)}
+
+    schema = user_schema()
+    schema['serialization'] = core_schema.wrap_serializer_function_ser_schema(wrap)
+    s = SchemaSerializer(schema)
+    assert s.to_python(user(), groups={'public'}) == {'wrapped': {'id': 1, 'name': 'sam'}}
+
+
+def test_combined_with_exclude():
+    s = SchemaSerializer(user_schema())
+    assert s.to_python(user(), groups={'admin'}, exclude={'id'}) == {'name': 'sam', 'email': 'sam@example.com'}
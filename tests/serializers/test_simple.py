@@ -3,7 +3,7 @@ from enum import IntEnum
 
 import pytest
 
-from pydantic_core import CoreConfig, SchemaSerializer, core_schema
+from pydantic_core import CoreConfig, SchemaSerializer, SchemaValidator, core_schema, to_json, to_jsonable_python
 
 try:
     import numpy
@@ -169,3 +169,44 @@ def test_float_inf_and_nan_serializers(value, expected_json, config):
 
     # Serialized JSON value respects the ser_json_inf_nan setting
     assert s.to_json(value).decode() == expected_json
+
+
+@pytest.mark.parametrize(
+    'value,int_mode,expected_json',
+    [
+        (1, 'number', '1'),
+        (2**70, 'number', '1180591620717411303424'),
+        (1, 'string', '"1"'),
+        (-(2**70), 'string', '"-1180591620717411303424"'),
+        (2**53 - 1, 'safe', '9007199254740991'),
+        (-(2**53 - 1), 'safe', '-9007199254740991'),
+        (2**53, 'safe', '"9007199254740992"'),
+        (-(2**53), 'safe', '"-9007199254740992"'),
+        (2**70, 'safe', '"1180591620717411303424"'),
+        (IntSubClass(2**60), 'safe', '"1152921504606846976"'),
+    ],
+)
+def test_int_mode(value, int_mode, expected_json):
+    s = SchemaSerializer(core_schema.int_schema(), CoreConfig(ser_json_int=int_mode))
+    assert s.to_json(value).decode() == expected_json
+    assert s.to_python(value, mode='json') == json.loads(expected_json)
+    assert s.to_python(value) == value
+
+    # the same applies to ints found by inference
+    s = SchemaSerializer(core_schema.any_schema(), CoreConfig(ser_json_int=int_mode))
+    assert s.to_json([value]).decode() == f'[{expected_json}]'
+    assert s.to_python([value], mode='json') == [json.loads(expected_json)]
+
+
+def test_int_mode_function():
+    value = {'id': 2**60, 'count': 3, 'flag': True}
+    assert to_json(value, int_mode='safe') == b'{"id":"1152921504606846976","count":3,"flag":true}'
+    assert to_jsonable_python(value, int_mode='string') == {'id': '1152921504606846976', 'count': '3', 'flag': True}
+
+
+def test_int_mode_round_trip():
+    s = SchemaSerializer(core_schema.int_schema(), CoreConfig(ser_json_int='safe'))
+    # ints written as strings are accepted by the int validator in lax mode
+    assert SchemaValidator(core_schema.int_schema()).validate_json(s.to_json(2**60)) == 2**60
+    # binary formats aren't affected
+    assert s.to_msgpack(2**60) == SchemaSerializer(core_schema.int_schema()).to_msgpack(2**60)
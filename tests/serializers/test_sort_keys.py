@@ -0,0 +1,186 @@
+import dataclasses
+import json
+
+import pytest
+
+from pydantic_core import SchemaSerializer, core_schema, to_json, to_jsonable_python
+
+
+class BasicModel:
+    def __init__(self, **kwargs):
+        for key, value in kwargs.items():
+            setattr(self, key, value)
+
+
+@dataclasses.dataclass
+class Point:
+    y: int
+    x: int
+
+
+def model_serializer(config=None):
+    return SchemaSerializer(
+        core_schema.model_schema(
+            BasicModel,
+            core_schema.model_fields_schema(
+                {
+                    'zeta': core_schema.model_field(core_schema.int_schema()),
+                    'alpha': core_schema.model_field(
+                        core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema())
+                    ),
+                    'mid': core_schema.model_field(core_schema.any_schema()),
+                }
+            ),
+        ),
+        config,
+    )
+
+
+def model_value():
+    return BasicModel(zeta=1, alpha={'b': 1, 'a': 2}, mid={'y': {'d': 1, 'c': 2}, 'x': [{'f': 1, 'e': 2}]})
+
+
+def test_default_order():
+    s = model_serializer()
+    assert s.to_json(model_value()) == (
+        b'{"zeta":1,"alpha":{"b":1,"a":2},"mid":{"y":{"d":1,"c":2},"x":[{"f":1,"e":2}]}}'
+    )
+
+
+@pytest.mark.parametrize('sort_keys', [True, 'recursive'])
+def test_recursive(sort_keys):
+    s = model_serializer()
+    expected = {'alpha': {'a': 2, 'b': 1}, 'mid': {'x': [{'e': 2, 'f': 1}], 'y': {'c': 2, 'd': 1}}, 'zeta': 1}
+    assert s.to_json(model_value(), sort_keys=sort_keys) == json.dumps(expected, separators=(',', ':')).encode()
+
+    for mode in ('python', 'json'):
+        output = s.to_python(model_value(), mode=mode, sort_keys=sort_keys)
+        assert output == expected
+        assert list(output) == ['alpha', 'mid', 'zeta']
+        assert list(output['alpha']) == ['a', 'b']
+        assert list(output['mid']) == ['x', 'y']
+        assert list(output['mid']['y']) == ['c', 'd']
+        assert list(output['mid']['x'][0]) == ['e', 'f']
+
+
+def test_top_level():
+    s = model_serializer()
+    assert s.to_json(model_value(), sort_keys='top-level') == (
+        b'{"alpha":{"b":1,"a":2},"mid":{"y":{"d":1,"c":2},"x":[{"f":1,"e":2}]},"zeta":1}'
+    )
+    output = s.to_python(model_value(), sort_keys='top-level')
+    assert list(output) == ['alpha', 'mid', 'zeta']
+    assert list(output['alpha']) == ['b', 'a']
+    assert list(output['mid']) == ['y', 'x']
+
+
+def test_indent():
+    s = SchemaSerializer(core_schema.dict_schema())
+    assert s.to_json({'b': {'d': 1, 'c': 2}, 'a': []}, indent=2, sort_keys=True) == (
+        b'{\n  "a": [],\n  "b": {\n    "c": 2,\n    "d": 1\n  }\n}'
+    )
+
+
+def test_config():
+    s = model_serializer({'ser_json_sort_keys': 'top-level'})
+    assert s.to_json(model_value()).startswith(b'{"alpha":{"b":1,"a":2},')
+    # the argument takes priority over the config
+    assert s.to_json(model_value(), sort_keys=False).startswith(b'{"zeta":1,')
+    assert s.to_json(model_value(), sort_keys=True).startswith(b'{"alpha":{"a":2,"b":1},')
+
+    # `ser_json_sort_keys` only applies when serializing to JSON
+    assert list(s.to_python(model_value())) == ['zeta', 'alpha', 'mid']
+    assert list(s.to_python(model_value(), mode='json')) == ['alpha', 'mid', 'zeta']
+
+
+def test_extra_and_computed_fields():
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            BasicModel,
+            core_schema.model_fields_schema(
+                {'c': core_schema.model_field(core_schema.int_schema())},
+                extra_behavior='allow',
+                computed_fields=[core_schema.computed_field('b', core_schema.int_schema(), alias='a_alias')],
+            ),
+            extra_behavior='allow',
+        )
+    )
+
+    class Model(BasicModel):
+        @property
+        def b(self):
+            return 2
+
+    m = Model(c=1, __pydantic_extra__={'d': 4, 'a': 3})
+    assert s.to_json(m) == b'{"c":1,"d":4,"a":3,"b":2}'
+    assert s.to_json(m, sort_keys=True) == b'{"a":3,"b":2,"c":1,"d":4}'
+    assert s.to_json(m, by_alias=True, sort_keys=True) == b'{"a":3,"a_alias":2,"c":1,"d":4}'
+    assert list(s.to_python(m, sort_keys=True)) == ['a', 'b', 'c', 'd']
+
+
+def test_dataclass():
+    s = SchemaSerializer(
+        core_schema.dataclass_schema(
+            Point,
+            core_schema.dataclass_args_schema(
+                'Point',
+                [
+                    core_schema.dataclass_field(name='y', schema=core_schema.int_schema()),
+                    core_schema.dataclass_field(name='x', schema=core_schema.int_schema()),
+                ],
+            ),
+            ['y', 'x'],
+        )
+    )
+    assert s.to_json(Point(y=1, x=2), sort_keys=True) == b'{"x":2,"y":1}'
+    assert list(s.to_python(Point(y=1, x=2), sort_keys=True)) == ['x', 'y']
+
+
+def test_typed_dict_aliases():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema(), serialization_alias='z'),
+                'b': core_schema.typed_dict_field(core_schema.int_schema()),
+            }
+        )
+    )
+    # keys are sorted by their output name
+    assert s.to_json({'a': 1, 'b': 2}, by_alias=True, sort_keys=True) == b'{"b":2,"z":1}'
+    assert s.to_json({'a': 1, 'b': 2}, by_alias=False, sort_keys=True) == b'{"a":1,"b":2}'
+
+
+def test_non_str_keys():
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.int_schema(), core_schema.int_schema()))
+    assert list(s.to_python({3: 1, 1: 2, 2: 3}, sort_keys=True)) == [1, 2, 3]
+    # in JSON keys are sorted as strings
+    assert s.to_json({3: 1, 10: 2, 2: 3}, sort_keys=True) == b'{"10":2,"2":3,"3":1}'
+
+    s = SchemaSerializer(core_schema.any_schema())
+    # keys which can't be compared are ordered by type name, then `str()`
+    assert list(s.to_python({'a': 1, 10: 2, 'B': 3, 9: 4, None: 5}, sort_keys=True)) == [None, 10, 9, 'B', 'a']
+    assert s.to_json({1: 1, 'a': 2, '0': 3}, sort_keys=True) == b'{"0":3,"1":1,"a":2}'
+
+
+def test_msgpack_and_cbor_follow_config():
+    s = SchemaSerializer(core_schema.dict_schema(), {'ser_json_sort_keys': True})
+    unsorted = SchemaSerializer(core_schema.dict_schema())
+    assert s.to_msgpack({'b': 1, 'a': 2}) == unsorted.to_msgpack({'a': 2, 'b': 1})
+    assert s.to_cbor({'b': 1, 'a': 2}) == unsorted.to_cbor({'a': 2, 'b': 1})
+
+
+def test_to_json_function():
+    value = {'b': [{'d': 1, 'c': 2}], 'a': Point(y=1, x=2)}
+    assert to_json(value) == b'{"b":[{"d":1,"c":2}],"a":{"y":1,"x":2}}'
+    assert to_json(value, sort_keys=True) == b'{"a":{"x":2,"y":1},"b":[{"c":2,"d":1}]}'
+    assert to_json(value, sort_keys='top-level') == b'{"a":{"y":1,"x":2},"b":[{"d":1,"c":2}]}'
+
+    output = to_jsonable_python(value, sort_keys=True)
+    assert list(output) == ['a', 'b']
+    assert list(output['a']) == ['x', 'y']
+
+
+@pytest.mark.parametrize('sort_keys', ['foobar', 1, None])
+def test_invalid(sort_keys):
+    with pytest.raises(ValueError, match='Invalid sort_keys value'):
+        to_json({}, sort_keys=sort_keys)
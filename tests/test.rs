@@ -78,7 +78,19 @@ json_input = '{"a": "something"}'
             let json_input = locals.get_item("json_input").unwrap().unwrap();
             let binding = SchemaValidator::py_new(py, &schema, None)
                 .unwrap()
-                .validate_json(py, &json_input, None, None, None, None, false.into(), None, None)
+                .validate_json(
+                    py,
+                    &json_input,
+                    None,
+                    None,
+                    None,
+                    None,
+                    false.into(),
+                    None,
+                    None,
+                    false,
+                    None,
+                )
                 .unwrap();
             let validation_result: Bound<'_, PyAny> = binding.extract(py).unwrap();
             let repr = format!("{}", validation_result.repr().unwrap());
@@ -156,6 +168,14 @@ dump_json_input_2 = {'a': 'something'}
                     None,
                     false,
                     None,
+                    None,
+                    65536,
+                    false,
+                    None,
+                    None,
+                    false,
+                    false,
+                    None,
                 )
                 .unwrap();
             let repr = format!("{}", serialization_result.bind(py).repr().unwrap());
@@ -179,6 +199,14 @@ dump_json_input_2 = {'a': 'something'}
                     None,
                     false,
                     None,
+                    None,
+                    65536,
+                    false,
+                    None,
+                    None,
+                    false,
+                    false,
+                    None,
                 )
                 .unwrap();
             let repr = format!("{}", serialization_result.bind(py).repr().unwrap());
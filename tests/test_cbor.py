@@ -0,0 +1,263 @@
+import re
+import struct
+from datetime import datetime, timezone
+from decimal import Decimal
+
+import pytest
+
+from pydantic_core import SchemaSerializer, SchemaValidator, ValidationError, core_schema
+
+SCHEMA = core_schema.typed_dict_schema(
+    {
+        'id': core_schema.typed_dict_field(core_schema.int_schema()),
+        'data': core_schema.typed_dict_field(core_schema.bytes_schema()),
+        'tags': core_schema.typed_dict_field(core_schema.list_schema(core_schema.str_schema())),
+    }
+)
+DT = datetime(2013, 3, 21, 20, 4, tzinfo=timezone.utc)
+TAG_0_DT = b'\xc0\x742013-03-21T20:04:00Z'
+TAG_1_DT = b'\xc1\x1a\x51\x4b\x67\xb0'
+TAG_2_BIGNUM = b'\xc2\x49\x01' + b'\x00' * 8
+TAG_4_DECIMAL = b'\xc4\x82\x21\x19\x6a\xb3'
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (b'\xf6', None),
+        (b'\xf7', None),
+        (b'\xf5', True),
+        (b'\x17', 23),
+        (b'\x18\x18', 24),
+        (b'\x39\x01\x00', -257),
+        (b'\x1b' + b'\xff' * 8, 2**64 - 1),
+        (b'\x3b' + b'\xff' * 8, -(2**64)),
+        (b'\xf9\x3c\x00', 1.0),
+        (b'\xf9\x7c\x00', float('inf')),
+        (b'\xfb\x3f\xf8\x00\x00\x00\x00\x00\x00', 1.5),
+        (b'\x63foo', 'foo'),
+        (b'\x7f\x62fo\x61o\xff', 'foo'),
+        (b'\x42\x00\xff', b'\x00\xff'),
+        (b'\x82\x01\x61x', [1, 'x']),
+        (b'\x9f\x01\x02\xff', [1, 2]),
+        (b'\xa2\x61a\x01\x02\xf4', {'a': 1, 2: False}),
+        (b'\xbf\x61a\x01\xff', {'a': 1}),
+        (TAG_0_DT, DT),
+        (TAG_1_DT, DT),
+        (b'\xc1\xfb' + struct.pack('>d', 1363896240.5), DT.replace(microsecond=500000)),
+        (TAG_2_BIGNUM, 2**64),
+        (b'\xc3\x49\x01' + b'\x00' * 8, -1 - 2**64),
+        (TAG_4_DECIMAL, Decimal('273.15')),
+        # unknown tags and the self-described CBOR tag are ignored
+        (b'\xd8\x20\x63foo', 'foo'),
+        (b'\xd9\xd9\xf7\x01', 1),
+    ],
+)
+def test_any(input_value, expected):
+    v = SchemaValidator(core_schema.any_schema())
+    assert v.validate_cbor(input_value) == expected
+
+
+def test_typed_dict():
+    v = SchemaValidator(SCHEMA)
+    assert v.validate_cbor(b'\xa3\x62id\x01\x64data\x41\xff\x64tags\x81\x61a') == {
+        'id': 1,
+        'data': b'\xff',
+        'tags': ['a'],
+    }
+    assert v.validate_cbor(bytearray(b'\xa3\x62id\x611\x64data\x61x\x64tags\x80')) == {
+        'id': 1,
+        'data': b'x',
+        'tags': [],
+    }
+
+
+@pytest.mark.parametrize(
+    'schema,input_value,expected',
+    [
+        (core_schema.int_schema(), TAG_2_BIGNUM, 2**64),
+        (core_schema.float_schema(), TAG_2_BIGNUM, float(2**64)),
+        (core_schema.decimal_schema(), TAG_4_DECIMAL, Decimal('273.15')),
+        (core_schema.datetime_schema(), TAG_0_DT, DT),
+        (core_schema.datetime_schema(), TAG_1_DT, DT),
+    ],
+)
+def test_tags_strict(schema, input_value, expected):
+    v = SchemaValidator(schema)
+    assert v.validate_cbor(input_value, strict=True) == expected
+
+
+@pytest.mark.parametrize(
+    'schema,input_value,lax_expected,error_type',
+    [
+        (core_schema.int_schema(), TAG_1_DT, 1363896240, 'int_type'),
+        (core_schema.str_schema(), TAG_0_DT, '2013-03-21T20:04:00Z', 'string_type'),
+        (core_schema.str_schema(coerce_numbers_to_str=True), TAG_2_BIGNUM, str(2**64), 'string_type'),
+        (core_schema.float_schema(), TAG_4_DECIMAL, 273.15, 'float_type'),
+        (core_schema.date_schema(), b'\xc0\x742013-03-21T00:00:00Z', DT.date(), 'date_type'),
+    ],
+)
+def test_tags_mismatched(schema, input_value, lax_expected, error_type):
+    v = SchemaValidator(schema)
+    assert v.validate_cbor(input_value) == lax_expected
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_cbor(input_value, strict=True)
+    assert [e['type'] for e in exc_info.value.errors()] == [error_type]
+
+
+def test_strict():
+    v = SchemaValidator(SCHEMA)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_cbor(b'\xa3\x62id\x611\x64data\x61x\x64tags\x81\x41a', strict=True)
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'int_type', 'loc': ('id',), 'msg': 'Input should be a valid integer', 'input': '1'},
+        {'type': 'bytes_type', 'loc': ('data',), 'msg': 'Input should be a valid bytes', 'input': 'x'},
+        {'type': 'string_type', 'loc': ('tags', 0), 'msg': 'Input should be a valid string', 'input': b'a'},
+    ]
+
+
+@pytest.mark.parametrize(
+    'input_value,error',
+    [
+        (b'', 'EOF while parsing a value at byte 0'),
+        (b'\x82\x01', 'EOF while parsing a value at byte 2'),
+        (b'\x01\x02', 'trailing bytes after value at byte 1'),
+        (b'\x1c', 'invalid additional information at byte 0'),
+        (b'\xff', 'unexpected break at byte 0'),
+        (b'\xf8\x20', 'unsupported simple value at byte 0'),
+        (b'\x61\xff', 'invalid UTF-8 in text string at byte 0'),
+        (b'\x7f\x41a\xff', 'invalid chunk in indefinite-length string at byte 1'),
+        (b'\xc0\x01', 'invalid content for tag 0 at byte 0'),
+        (b'\xc4\x81\x01', 'invalid content for tag 4 at byte 0'),
+        (b'\x81' * 300, 'recursion limit exceeded at byte 200'),
+    ],
+)
+def test_invalid(input_value, error):
+    v = SchemaValidator(core_schema.any_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_cbor(input_value)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'cbor_invalid',
+            'loc': (),
+            'msg': f'Invalid CBOR: {error}',
+            'input': input_value,
+            'ctx': {'error': error},
+        }
+    ]
+
+
+@pytest.mark.parametrize(
+    'schema,input_value,error_type,error_input',
+    [
+        # tag 0 with a string which isn't a date/time
+        (core_schema.datetime_schema(), b'\xc0\x63abc', 'datetime_from_date_parsing', 'abc'),
+        # tag 1 with seconds after 9999
+        (core_schema.datetime_schema(), b'\xc1\x1b' + (2**62).to_bytes(8, 'big'), 'datetime_parsing', 2**62),
+        # tag 4 with an exponent too large for a decimal
+        (
+            core_schema.decimal_schema(),
+            b'\xc4\x82\x1b' + (2**63 - 1).to_bytes(8, 'big') + b'\x01',
+            'decimal_parsing',
+            [2**63 - 1, 1],
+        ),
+    ],
+)
+def test_tags_invalid_error_input(schema, input_value, error_type, error_input):
+    v = SchemaValidator(schema)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_cbor(input_value)
+    # the raw tag content is shown when it can't be converted
+    (error,) = exc_info.value.errors(include_url=False)
+    assert error['type'] == error_type
+    assert error['input'] == error_input
+
+
+def test_unhashable_keys_error_input():
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        # {[1]: 1, {}: 2}
+        v.validate_cbor(b'\xa2\x81\x01\x01\xa0\x02')
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'int_type', 'loc': (), 'msg': 'Input should be a valid integer', 'input': [([1], 1), ({}, 2)]}
+    ]
+
+
+def test_invalid_type():
+    v = SchemaValidator(core_schema.any_schema())
+    with pytest.raises(ValidationError, match=re.escape('CBOR input should be bytes or bytearray [type=cbor_type')):
+        v.validate_cbor('\x01')
+
+
+def test_key_loc():
+    v = SchemaValidator(core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema()))
+    with pytest.raises(ValidationError) as exc_info:
+        # {true: 'x', null: 1}
+        v.validate_cbor(b'\xa2\xf5\x61x\xf6\x01')
+    assert [e['loc'] for e in exc_info.value.errors(include_url=False)] == [
+        ('true', '[key]'),
+        ('true',),
+        ('null', '[key]'),
+    ]
+
+
+def test_model_fields_alias_path():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'x': core_schema.typed_dict_field(core_schema.int_schema(), validation_alias=['a', 1]),
+                'y': core_schema.typed_dict_field(core_schema.int_schema(), validation_alias=['b', -1]),
+            }
+        )
+    )
+    # `a` is a map with an int key, `b` is an array
+    assert v.validate_cbor(b'\xa2\x61a\xa1\x01\x02\x61b\x82\x03\x04') == {'x': 2, 'y': 4}
+
+
+def test_validation_info_mode():
+    def f(value, info):
+        return info.mode
+
+    v = SchemaValidator(core_schema.with_info_plain_validator_function(f))
+    assert v.validate_cbor(b'\xf6') == 'cbor'
+
+
+def test_to_cbor():
+    s = SchemaSerializer(SCHEMA)
+    # lengths aren't known up front, so maps and arrays are written with indefinite lengths
+    assert s.to_cbor({'id': 1, 'data': b'\xff', 'tags': ['a']}) == (
+        b'\xbf\x62id\x01\x64data\x41\xff\x64tags\x9f\x61a\xff\xff'
+    )
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.str_schema(), core_schema.list_schema()))
+    assert s.to_cbor({'a': [1, 2, 3], 'b': []}, exclude={'a': {1}, 'b': True}) == b'\xbf\x61a\x9f\x01\x03\xff\xff'
+
+
+@pytest.mark.parametrize(
+    'value,expected',
+    [
+        (-1, b'\x20'),
+        (2**64 - 1, b'\x1b' + b'\xff' * 8),
+        (-(2**64), b'\x3b' + b'\xff' * 8),
+        (2**64, TAG_2_BIGNUM),
+        (-1 - 2**64, b'\xc3\x49\x01' + b'\x00' * 8),
+    ],
+)
+def test_to_cbor_int(value, expected):
+    s = SchemaSerializer(core_schema.int_schema())
+    assert s.to_cbor(value) == expected
+    assert SchemaValidator(core_schema.int_schema()).validate_cbor(expected, strict=True) == value
+
+
+def test_round_trip():
+    schema = core_schema.typed_dict_schema(
+        {
+            'n': core_schema.typed_dict_field(core_schema.list_schema(core_schema.int_schema())),
+            'data': core_schema.typed_dict_field(core_schema.bytes_schema()),
+            'dt': core_schema.typed_dict_field(core_schema.datetime_schema()),
+            'd': core_schema.typed_dict_field(core_schema.decimal_schema()),
+        }
+    )
+    v = SchemaValidator(schema)
+    s = SchemaSerializer(schema)
+    value = {'n': [-5, 2**100, -(2**100)], 'data': bytes(range(256)), 'dt': DT, 'd': Decimal('1.5')}
+    assert v.validate_cbor(s.to_cbor(value)) == value
@@ -0,0 +1,76 @@
+import json
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+SCHEMA = core_schema.dict_schema(
+    core_schema.str_schema(),
+    core_schema.list_schema(
+        core_schema.typed_dict_schema({'price': core_schema.typed_dict_field(core_schema.int_schema())})
+    ),
+)
+
+
+def get_error(input_value, schema=SCHEMA):
+    with pytest.raises(ValidationError) as exc_info:
+        SchemaValidator(schema).validate_python(input_value)
+    return exc_info.value
+
+
+@pytest.mark.parametrize(
+    'key,loc_format,expected',
+    [
+        ('items', 'tuple', ('items', 1, 'price')),
+        ('items', 'json_pointer', '/items/1/price'),
+        ('items', 'jsonpath', '$.items[1].price'),
+        ('items', 'dotted', 'items.1.price'),
+        ('a/b~c', 'json_pointer', '/a~1b~0c/1/price'),
+        ('a.b', 'jsonpath', "$['a.b'][1].price"),
+        ("it's", 'jsonpath', "$['it\\'s'][1].price"),
+        ('a b', 'jsonpath', "$['a b'][1].price"),
+        ('1a', 'jsonpath', "$['1a'][1].price"),
+        ('', 'json_pointer', '//1/price'),
+        ('a.b', 'dotted', '`a.b`.1.price'),
+    ],
+)
+def test_loc_format(key, loc_format, expected):
+    exc = get_error({key: [{'price': 1}, {'price': 'x'}]})
+    assert exc.errors(loc_format=loc_format)[0]['loc'] == expected
+    assert json.loads(exc.json(loc_format=loc_format))[0]['loc'] == (
+        list(expected) if isinstance(expected, tuple) else expected
+    )
+
+
+def test_root_location():
+    exc = get_error('x', core_schema.int_schema())
+    assert exc.errors(loc_format='tuple')[0]['loc'] == ()
+    assert exc.errors(loc_format='json_pointer')[0]['loc'] == ''
+    assert exc.errors(loc_format='jsonpath')[0]['loc'] == '$'
+    assert exc.errors(loc_format='dotted')[0]['loc'] == ''
+
+
+def test_default_is_tuple():
+    exc = get_error({'items': [{'price': 'x'}]})
+    assert exc.errors()[0]['loc'] == ('items', 0, 'price')
+    assert json.loads(exc.json())[0]['loc'] == ['items', 0, 'price']
+
+
+def test_group_by_union_member():
+    schema = core_schema.typed_dict_schema(
+        {
+            'a': core_schema.typed_dict_field(
+                core_schema.union_schema([core_schema.int_schema(), core_schema.list_schema(core_schema.int_schema())])
+            )
+        }
+    )
+    exc = get_error({'a': ['x']}, schema)
+    (error,) = exc.errors(loc_format='json_pointer', group_by_union_member=True, include_url=False)
+    assert error['loc'] == '/a'
+    assert [[e['loc'] for e in m['errors']] for m in error['members']] == [[''], ['/0']]
+
+
+def test_invalid_loc_format():
+    exc = get_error('x', core_schema.int_schema())
+    with pytest.raises(ValueError, match="Invalid loc_format `nope`, should be 'tuple', 'json_pointer'"):
+        exc.errors(loc_format='nope')
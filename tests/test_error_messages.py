@@ -0,0 +1,112 @@
+import json
+
+import pytest
+
+from pydantic_core import PydanticCustomError, SchemaValidator, ValidationError, core_schema, register_error_messages
+
+register_error_messages(
+    'test-fr',
+    {
+        'missing': 'Champ obligatoire',
+        'greater_than': 'La valeur doit être supérieure à {gt}',
+        'string_too_short': 'Au moins {min_length} caractère{expected_plural}',
+        'my_error': 'Erreur personnalisée : {foo}',
+        'union_no_match': 'Aucun membre de l’union ne correspond',
+    },
+)
+
+
+def get_error(schema, input_value, method='validate_python'):
+    with pytest.raises(ValidationError) as exc_info:
+        getattr(SchemaValidator(schema), method)(input_value)
+    return exc_info.value
+
+
+SCHEMA = core_schema.typed_dict_schema(
+    {
+        'a': core_schema.typed_dict_field(core_schema.int_schema(gt=1)),
+        'b': core_schema.typed_dict_field(core_schema.str_schema(min_length=2)),
+        'c': core_schema.typed_dict_field(core_schema.bool_schema()),
+    }
+)
+
+
+def test_errors_locale():
+    exc = get_error(SCHEMA, {'a': 0, 'b': 'x'})
+    assert [e['msg'] for e in exc.errors(locale='test-fr')] == [
+        'La valeur doit être supérieure à 1',
+        'Au moins 2 caractères',
+        'Champ obligatoire',
+    ]
+    # the default is still English
+    assert [e['msg'] for e in exc.errors()] == [
+        'Input should be greater than 1',
+        'String should have at least 2 characters',
+        'Field required',
+    ]
+    # other details are unchanged
+    assert exc.errors(locale='test-fr', include_url=False)[0] == {
+        'type': 'greater_than',
+        'loc': ('a',),
+        'msg': 'La valeur doit être supérieure à 1',
+        'input': 0,
+        'ctx': {'gt': 1},
+    }
+
+
+def test_fallback_to_english():
+    exc = get_error(SCHEMA, {'a': 2, 'b': 'xx', 'c': 'nope'})
+    assert exc.errors(locale='test-fr')[0]['msg'] == 'Input should be a valid boolean, unable to interpret input'
+    assert exc.errors(locale='unknown')[0]['msg'] == 'Input should be a valid boolean, unable to interpret input'
+
+
+def test_json_locale():
+    exc = get_error(SCHEMA, '{"a": 0, "b": "xx", "c": true}', 'validate_json')
+    assert [e['msg'] for e in json.loads(exc.json(locale='test-fr'))] == ['La valeur doit être supérieure à 1']
+    assert [e['msg'] for e in json.loads(exc.json())] == ['Input should be greater than 1']
+
+
+def test_custom_error():
+    def f(input_value, info):
+        raise PydanticCustomError('my_error', 'Custom error: {foo}', {'foo': 'bar'})
+
+    exc = get_error(core_schema.with_info_plain_validator_function(f), 1)
+    assert exc.errors(locale='test-fr')[0]['msg'] == 'Erreur personnalisée : bar'
+    assert exc.errors()[0]['msg'] == 'Custom error: bar'
+
+
+def test_group_by_union_member(pydantic_version):
+    schema = core_schema.union_schema([core_schema.int_schema(gt=1), core_schema.str_schema(min_length=2)])
+    exc = get_error(schema, 0)
+    (error,) = exc.errors(locale='test-fr', group_by_union_member=True)
+    assert {k: v for k, v in error.items() if k != 'members'} == {
+        'type': 'union_no_match',
+        'loc': (),
+        'msg': 'Aucun membre de l’union ne correspond',
+        'input': 0,
+        'url': f'https://errors.pydantic.dev/{pydantic_version}/v/union_no_match',
+    }
+    assert [m['errors'][0]['msg'] for m in error['members']] == [
+        'La valeur doit être supérieure à 1',
+        'Input should be a valid string',
+    ]
+    (error,) = exc.errors(group_by_union_member=True, include_input=False, include_url=False)
+    assert {k: v for k, v in error.items() if k != 'members'} == {
+        'type': 'union_no_match',
+        'loc': (),
+        'msg': 'Input did not match any union member',
+    }
+
+
+def test_register_updates_locale():
+    register_error_messages('test-de', {'missing': 'Feld fehlt'})
+    register_error_messages('test-de', {'int_parsing': 'Ganzzahl erwartet'})
+    exc = get_error(core_schema.typed_dict_schema({'a': core_schema.typed_dict_field(core_schema.int_schema())}), {})
+    assert exc.errors(locale='test-de')[0]['msg'] == 'Feld fehlt'
+    exc = get_error(core_schema.int_schema(), 'x')
+    assert exc.errors(locale='test-de')[0]['msg'] == 'Ganzzahl erwartet'
+
+
+def test_register_invalid():
+    with pytest.raises(TypeError):
+        register_error_messages('test-x', {'missing': 1})
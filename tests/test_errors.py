@@ -257,14 +257,38 @@ all_errors = [
     ('no_such_attribute', "Object has no attribute 'wrong_name'", {'attribute': 'wrong_name'}),
     ('json_invalid', 'Invalid JSON: foobar', {'error': 'foobar'}),
     ('json_type', 'JSON input should be string, bytes or bytearray', None),
+    ('msgpack_invalid', 'Invalid MessagePack: foobar', {'error': 'foobar'}),
+    ('msgpack_type', 'MessagePack input should be bytes or bytearray', None),
+    ('cbor_invalid', 'Invalid CBOR: foobar', {'error': 'foobar'}),
+    ('cbor_type', 'CBOR input should be bytes or bytearray', None),
     (
         'needs_python_object',
         'Cannot check `isinstance` when validating from json, use a JsonOrPython validator instead',
         {'method_name': 'isinstance'},
     ),
     ('recursion_loop', 'Recursion error - cyclic reference detected', None),
+    ('too_many_errors', 'Validation stopped after 10 errors', {'max_errors': 10}),
+    ('too_many_errors', 'Validation stopped after 1 error', {'max_errors': 1}),
+    ('ambiguous_key', "Key differs only by case from 'DB__PORT'", {'other_key': 'DB__PORT'}),
     ('model_type', 'Input should be a valid dictionary or instance of Foobar', {'class_name': 'Foobar'}),
     ('model_attributes_type', 'Input should be a valid dictionary or object to extract fields from', None),
+    (
+        'field_comparison',
+        "Input should be less than field 'end'",
+        {'comparison': 'less than', 'other_field': 'end'},
+    ),
+    (
+        'field_not_comparable',
+        "Input could not be compared to field 'end': unsupported operand",
+        {'other_field': 'end', 'error': 'unsupported operand'},
+    ),
+    ('fields_one_of', "Exactly one of the fields 'a', 'b' should be provided", {'fields': "'a', 'b'"}),
+    (
+        'fields_mutually_exclusive',
+        "Field cannot be provided together with field 'a'",
+        {'other_field': 'a'},
+    ),
+    ('field_requires', "Field required when field 'a' is provided", {'other_field': 'a'}),
     ('dataclass_exact_type', 'Input should be an instance of Foobar', {'class_name': 'Foobar'}),
     ('dataclass_type', 'Input should be a dictionary or an instance of Foobar', {'class_name': 'Foobar'}),
     (
@@ -301,6 +325,11 @@ all_errors = [
     ('string_sub_type', 'Input should be a string, not an instance of a subclass of str', None),
     ('string_unicode', 'Input should be a valid string, unable to parse raw data as a unicode string', None),
     ('string_pattern_mismatch', "String should match pattern 'foo'", {'pattern': 'foo'}),
+    (
+        'string_pattern_error',
+        "Pattern 'foo' could not be run against the string: backtrack limit exceeded",
+        {'pattern': 'foo', 'error': 'backtrack limit exceeded'},
+    ),
     ('string_too_short', 'String should have at least 42 characters', {'min_length': 42}),
     ('string_too_short', 'String should have at least 1 character', {'min_length': 1}),
     ('string_too_long', 'String should have at most 42 characters', {'max_length': 42}),
@@ -375,6 +404,7 @@ all_errors = [
         {'discriminator': 'bar', 'tag': 'foo', 'expected_tags': 'baz'},
     ),
     ('union_tag_not_found', 'Unable to extract tag using discriminator foo', {'discriminator': 'foo'}),
+    ('union_no_match', 'Input did not match any union member', None),
     ('arguments_type', 'Arguments must be a tuple, list or a dictionary', None),
     ('missing_argument', 'Missing required argument', None),
     ('unexpected_keyword_argument', 'Unexpected keyword argument', None),
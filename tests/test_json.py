@@ -1,3 +1,4 @@
+import io
 import json
 import platform
 import re
@@ -236,6 +237,61 @@ def test_to_json_fallback():
     assert to_json(Foobar(), fallback=fallback_func) == b'"fallback:Foobar"'
 
 
+class ChunkRecorder:
+    def __init__(self):
+        self.chunks = []
+
+    def write(self, chunk):
+        self.chunks.append(chunk)
+
+
+def test_to_json_fp():
+    fp = io.BytesIO()
+    assert to_json({'a': [1, 2], 'b': 'à'}, fp=fp) is None
+    assert fp.getvalue() == '{"a":[1,2],"b":"à"}'.encode()
+
+    fp = io.BytesIO()
+    to_json([1, 2], indent=2, fp=fp)
+    assert fp.getvalue() == b'[\n  1,\n  2\n]'
+
+
+def test_to_json_fp_chunks():
+    value = [f'item-{i}' for i in range(100)]
+    fp = ChunkRecorder()
+    to_json(value, fp=fp, buffer_size=64)
+    assert len(fp.chunks) > 10
+    assert all(isinstance(chunk, bytes) for chunk in fp.chunks)
+    assert b''.join(fp.chunks) == to_json(value)
+
+
+def test_to_json_fp_text():
+    # multi-byte characters are never split between chunks written to a text file
+    value = ['€' * 10, 'à', '😀' * 3]
+    fp = io.StringIO()
+    to_json(value, fp=fp, buffer_size=1)
+    assert fp.getvalue() == to_json(value).decode()
+
+
+def test_to_json_fp_schema_serializer():
+    s = SchemaSerializer(core_schema.list_schema(core_schema.int_schema()))
+    fp = io.BytesIO()
+    assert s.to_json([1, 2, 3], fp=fp, buffer_size=2) is None
+    assert fp.getvalue() == b'[1,2,3]'
+
+
+def test_to_json_fp_write_error():
+    class BadWriter:
+        def write(self, chunk):
+            raise RuntimeError('disk full')
+
+    with pytest.raises(RuntimeError, match='disk full'):
+        to_json([1, 2, 3], fp=BadWriter(), buffer_size=1)
+
+    # errors from the final write are raised too
+    with pytest.raises(RuntimeError, match='disk full'):
+        to_json([1, 2, 3], fp=BadWriter())
+
+
 def test_to_jsonable_python():
     assert to_jsonable_python([1, 2]) == [1, 2]
     assert to_jsonable_python({1, 2}) == IsList(1, 2, check_order=False)
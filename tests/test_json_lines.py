@@ -0,0 +1,73 @@
+import pytest
+
+from pydantic_core import SchemaSerializer, SchemaValidator, ValidationError, core_schema
+
+SCHEMA = core_schema.typed_dict_schema(
+    {
+        'a': core_schema.typed_dict_field(core_schema.int_schema()),
+        'b': core_schema.typed_dict_field(core_schema.str_schema()),
+    }
+)
+
+
+@pytest.mark.parametrize(
+    'input_value',
+    [
+        b'{"a": 1, "b": "x"}\n{"a": "2", "b": "y"}\n',
+        '{"a": 1, "b": "x"}\n{"a": "2", "b": "y"}',
+        bytearray(b'{"a": 1, "b": "x"}\r\n\r\n  \n{"a": "2", "b": "y"}\r\n'),
+    ],
+)
+def test_validate_json_lines(input_value):
+    v = SchemaValidator(SCHEMA)
+    assert v.validate_json_lines(input_value) == [{'a': 1, 'b': 'x'}, {'a': 2, 'b': 'y'}]
+
+
+def test_validate_json_lines_empty():
+    v = SchemaValidator(SCHEMA)
+    assert v.validate_json_lines(b'') == []
+    assert v.validate_json_lines(b'\n\n') == []
+
+
+def test_validate_json_lines_errors():
+    v = SchemaValidator(SCHEMA)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json_lines(b'{"a": 1, "b": "x"}\n{"a": "x", "b": 1}\n\n{"a": 1,\n{"a": 1}')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': (2, 'a'),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        },
+        {'type': 'string_type', 'loc': (2, 'b'), 'msg': 'Input should be a valid string', 'input': 1},
+        {
+            'type': 'json_invalid',
+            'loc': (4,),
+            'msg': 'Invalid JSON: EOF while parsing a value at line 4 column 8',
+            'input': '{"a": 1,',
+            'ctx': {'error': 'EOF while parsing a value at line 4 column 8'},
+        },
+        {'type': 'missing', 'loc': (5, 'b'), 'msg': 'Field required', 'input': {'a': 1}},
+    ]
+
+
+def test_validate_json_lines_invalid_type():
+    v = SchemaValidator(SCHEMA)
+    with pytest.raises(ValidationError, match=r'JSON input should be string, bytes or bytearray \[type=json_type'):
+        v.validate_json_lines(123)
+
+
+def test_to_json_lines():
+    s = SchemaSerializer(SCHEMA)
+    assert s.to_json_lines([{'a': 1, 'b': 'x'}, {'a': 2, 'b': 'é'}]) == b'{"a":1,"b":"x"}\n{"a":2,"b":"\xc3\xa9"}\n'
+    assert s.to_json_lines(iter([{'a': 1, 'b': 'é'}]), ensure_ascii=True) == b'{"a":1,"b":"\\u00e9"}\n'
+    assert s.to_json_lines([{'a': 1, 'b': 'x'}], exclude={'b'}) == b'{"a":1}\n'
+    assert s.to_json_lines([]) == b''
+
+
+def test_to_json_lines_round_trip():
+    v = SchemaValidator(SCHEMA)
+    s = SchemaSerializer(SCHEMA)
+    items = [{'a': i, 'b': str(i) * i} for i in range(5)]
+    assert v.validate_json_lines(s.to_json_lines(items)) == items
@@ -0,0 +1,130 @@
+import json
+import pickle
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+SCHEMA = core_schema.typed_dict_schema(
+    {
+        'name': core_schema.typed_dict_field(core_schema.str_schema()),
+        'items': core_schema.typed_dict_field(
+            core_schema.list_schema(
+                core_schema.typed_dict_schema({'price': core_schema.typed_dict_field(core_schema.int_schema())})
+            )
+        ),
+    }
+)
+
+
+def get_error(input_json, schema=SCHEMA, **kwargs):
+    with pytest.raises(ValidationError) as exc_info:
+        SchemaValidator(schema).validate_json(input_json, track_positions=True, **kwargs)
+    return exc_info.value
+
+
+def positions(exc):
+    return [(e['loc'], e['line'], e['column'], e['offset']) for e in exc.errors()]
+
+
+def test_nested():
+    input_json = '{\n  "name": "x",\n  "items": [\n    {"price": 1},\n    {"price": "cheap"}\n  ]\n}'
+    exc = get_error(input_json)
+    assert positions(exc) == [(('items', 1, 'price'), 5, 15, 62)]
+    assert input_json[62:69] == '"cheap"'
+
+
+def test_missing_key_points_at_object():
+    exc = get_error('{"name": "x", "items": [{}, {"price": 1}]}')
+    assert positions(exc) == [(('items', 0, 'price'), 1, 25, 24)]
+
+
+def test_multiple_errors():
+    exc = get_error('{"name": 1, "items": [{"price": []}]}')
+    assert positions(exc) == [(('name',), 1, 10, 9), (('items', 0, 'price'), 1, 33, 32)]
+
+
+def test_many_errors_on_many_lines():
+    schema = core_schema.dict_schema(core_schema.int_schema(), core_schema.list_schema(core_schema.int_schema()))
+    input_json = '{\n' + ',\n'.join(f'"{i}": [1, "x{i}"]' for i in range(50)) + '\n}'
+    exc = get_error(input_json, schema)
+    assert positions(exc) == [
+        ((str(i), 1), i + 2, len(f'"{i}": [1, ') + 1, input_json.index(f'"x{i}"')) for i in range(50)
+    ]
+
+
+def test_union_labels_skipped():
+    schema = core_schema.list_schema(core_schema.union_schema([core_schema.int_schema(), core_schema.bool_schema()]))
+    exc = get_error('[1, "x"]', schema)
+    assert positions(exc) == [((1, 'int'), 1, 5, 4), ((1, 'bool'), 1, 5, 4)]
+
+
+def test_column_counts_characters():
+    exc = get_error('{"name": "été", "items": [{"price": "x"}]}')
+    (error,) = exc.errors()
+    assert (error['line'], error['column'], error['offset']) == (1, 37, 38)
+
+
+def test_root():
+    exc = get_error('  "x"', core_schema.int_schema())
+    assert positions(exc) == [((), 1, 3, 2)]
+
+
+def test_json_output():
+    exc = get_error('{"name": "x", "items": [{"price": "x"}]}')
+    (error,) = json.loads(exc.json(include_url=False))
+    assert error == {
+        'type': 'int_parsing',
+        'loc': ['items', 0, 'price'],
+        'line': 1,
+        'column': 35,
+        'offset': 34,
+        'msg': 'Input should be a valid integer, unable to parse string as an integer',
+        'input': 'x',
+    }
+
+
+def test_default_no_positions():
+    with pytest.raises(ValidationError) as exc_info:
+        SchemaValidator(SCHEMA).validate_json('{"name": 1, "items": []}')
+    assert 'line' not in exc_info.value.errors()[0]
+    assert 'line' not in json.loads(exc_info.value.json())[0]
+
+
+def test_validate_python_no_positions():
+    with pytest.raises(ValidationError) as exc_info:
+        SchemaValidator(SCHEMA).validate_python({'name': 1, 'items': []})
+    assert 'line' not in exc_info.value.errors()[0]
+
+
+def test_invalid_json():
+    exc = get_error('{"name": "x",\n "items": [1,]}')
+    (error,) = exc.errors()
+    assert error['type'] == 'json_invalid'
+    assert (error['line'], error['column'], error['offset']) == (2, 14, 27)
+
+
+def test_deeply_nested():
+    schema = core_schema.int_schema()
+    for _ in range(150):
+        schema = core_schema.list_schema(schema)
+    exc = get_error('[' * 150 + '"x"' + ']' * 150, schema)
+    assert positions(exc) == [((0,) * 150, 1, 151, 150)]
+
+
+def test_nesting_beyond_recursion_limit():
+    exc = get_error('[' * 1_000_000, core_schema.any_schema())
+    (error,) = exc.errors()
+    assert error['type'] == 'json_invalid'
+    assert (error['line'], error['column'], error['offset']) == (1, 202, 201)
+
+
+def test_duplicate_keys_last_wins():
+    # the last value is validated, so that's where the error is
+    exc = get_error('{"name": "x", "items": [{"price": 1, "price": "x"}]}')
+    assert positions(exc) == [(('items', 0, 'price'), 1, 47, 46)]
+
+
+def test_pickle():
+    exc = get_error('{"name": 1, "items": []}')
+    assert positions(pickle.loads(pickle.dumps(exc))) == positions(exc)
@@ -0,0 +1,235 @@
+import io
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+def chunks(data: bytes, size: int):
+    for i in range(0, len(data), size):
+        yield data[i : i + size]
+
+
+@pytest.mark.parametrize(
+    'reader',
+    [
+        b'[1, 2, "3"]',
+        '[1, 2, "3"]',
+        io.BytesIO(b'[1, 2, "3"]'),
+        io.StringIO('[1, 2, "3"]'),
+        iter([b'[1, 2', b', "3"]']),
+        chunks(b' [1 ,2, "3"] \n', 1),
+        [b'[1, 2, ', bytearray(b'"3"]')],
+    ],
+)
+def test_list(reader):
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    assert v.validate_json_stream(reader) == [1, 2, 3]
+
+
+@pytest.mark.parametrize('chunk_size', [1, 2, 3, 7, 1000])
+def test_chunk_size(chunk_size):
+    v = SchemaValidator(core_schema.list_schema(core_schema.dict_schema(core_schema.str_schema())))
+    data = b'[{"a": "x,]"}, {"b": "\\"]"}, {"c": "[{"}]'
+    assert v.validate_json_stream(io.BytesIO(data), chunk_size=chunk_size) == [
+        {'a': 'x,]'},
+        {'b': '"]'},
+        {'c': '[{'},
+    ]
+
+
+def test_not_list_root():
+    v = SchemaValidator(core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema()))
+    assert v.validate_json_stream(io.BytesIO(b'{"a": 1, "b": "2"}'), chunk_size=3) == {'a': 1, 'b': 2}
+
+
+@pytest.mark.parametrize(
+    'data',
+    [
+        b'[1, "x", 3, {"a": [1]}]',
+        b'{"a": 1}',
+        b'[1, 2,',
+        b'[1,\n2,\n3,]',
+        b'[1 2]',
+        b'[1{}]',
+        b'["x"1]',
+        b'[1[2]]',
+        b'[true"x"]',
+        b'[1,\n2] x',
+        b'[1,\n tru]',
+        b'["x", 1',
+        b'',
+    ],
+)
+@pytest.mark.parametrize('chunk_size', [1, 3, 1000])
+def test_errors_match_validate_json(data, chunk_size):
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json(data)
+    expected = [{k: e[k] for k in ('type', 'loc', 'msg')} for e in exc_info.value.errors(include_url=False)]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json_stream(io.BytesIO(data), chunk_size=chunk_size)
+    assert [{k: e[k] for k in ('type', 'loc', 'msg')} for e in exc_info.value.errors(include_url=False)] == expected
+
+
+def test_missing_separator_message():
+    v = SchemaValidator(core_schema.list_schema(core_schema.any_schema()))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json_stream(io.BytesIO(b'[1{}]'))
+    assert exc_info.value.errors(include_url=False)[0]['msg'] == (
+        'Invalid JSON: expected `,` or `]` at line 1 column 3'
+    )
+
+
+def test_max_length():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(), max_length=2))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json_stream(io.BytesIO(b'[1, 2, 3]'))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'too_long',
+            'loc': (),
+            'msg': 'List should have at most 2 items after validation, not more',
+            'input': exc_info.value.errors()[0]['input'],
+            'ctx': {'field_type': 'List', 'max_length': 2, 'actual_length': None},
+        }
+    ]
+
+
+def test_min_length():
+    v = SchemaValidator(core_schema.list_schema(min_length=2))
+    with pytest.raises(ValidationError, match='List should have at least 2 items after validation, not 1'):
+        v.validate_json_stream(io.BytesIO(b'[{"a": 1}]'))
+
+
+def test_invalid_json_takes_precedence():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json_stream(iter([b'["a", "b", ', b'"c"']))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'json_invalid',
+            'loc': (),
+            'msg': 'Invalid JSON: EOF while parsing a list at line 1 column 14',
+            'input': exc_info.value.errors()[0]['input'],
+            'ctx': {'error': 'EOF while parsing a list at line 1 column 14'},
+        }
+    ]
+
+
+def test_reads_incrementally():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    reads = []
+
+    class Reader:
+        def __init__(self, data: bytes):
+            self.data = io.BytesIO(data)
+
+        def read(self, size: int) -> bytes:
+            reads.append(size)
+            return self.data.read(size)
+
+    assert v.validate_json_stream(Reader(b'[' + b'1,' * 99 + b'1]'), chunk_size=16) == [1] * 100
+    assert reads == [16] * 14
+
+
+def test_invalid_source():
+    v = SchemaValidator(core_schema.list_schema())
+    with pytest.raises(TypeError, match='Expected bytes, bytearray, str, a file-like object with a `read\\(\\)` method'):
+        v.validate_json_stream(123)
+    with pytest.raises(TypeError, match='Expected chunks of bytes, bytearray or str, got int'):
+        v.validate_json_stream(iter([b'[1', 2]))
+    with pytest.raises(ValueError, match='`chunk_size` must be greater than zero'):
+        v.validate_json_stream(io.BytesIO(b'[]'), chunk_size=0)
+
+
+def test_iter_validate_json():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    it = v.iter_validate_json(io.BytesIO(b'[1, "2", 3]'), chunk_size=2)
+    assert it.index == 0
+    assert next(it) == 1
+    assert it.index == 1
+    assert list(it) == [2, 3]
+    assert it.index == 3
+    assert list(v.iter_validate_json(b'[]')) == []
+
+
+def test_iter_validate_json_no_item_schema():
+    v = SchemaValidator(core_schema.list_schema())
+    assert list(v.iter_validate_json(iter([b'[1, {"a"', b': []}]']))) == [1, {'a': []}]
+
+
+def test_iter_validate_json_on_error_raise():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    it = v.iter_validate_json(b'[1, "x", 3]')
+    assert next(it) == 1
+    with pytest.raises(ValidationError) as exc_info:
+        next(it)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': (1,),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+    assert list(it) == []
+
+
+def test_iter_validate_json_on_error_skip():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    assert list(v.iter_validate_json(b'[1, "x", 3, "y"]', on_error='skip')) == [1, 3]
+
+
+def test_iter_validate_json_max_length_counts_valid_items():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(), max_length=3))
+    assert list(v.iter_validate_json(b'[1,"a","b",3]', on_error='skip')) == [1, 3]
+
+
+def test_iter_validate_json_on_error_collect():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    output = []
+    with pytest.raises(ValidationError) as exc_info:
+        for item in v.iter_validate_json(b'[1, "x", 3, [4]]', on_error='collect'):
+            output.append(item)
+    assert output == [1, 3]
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': (1,),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        },
+        {'type': 'int_type', 'loc': (3,), 'msg': 'Input should be a valid integer', 'input': [4]},
+    ]
+
+
+def test_iter_validate_json_length():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(), min_length=2, max_length=3))
+    it = v.iter_validate_json(b'[1, 2, 3, 4]')
+    assert [next(it), next(it), next(it)] == [1, 2, 3]
+    with pytest.raises(ValidationError, match=r'List should have at most 3 items after validation, not more'):
+        next(it)
+
+    with pytest.raises(ValidationError, match=r'List should have at least 2 items after validation, not 1'):
+        list(v.iter_validate_json(b'[1, "x"]', on_error='skip'))
+
+
+def test_iter_validate_json_invalid():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    it = v.iter_validate_json(b'[1, 2 x]', on_error='skip')
+    assert [next(it), next(it)] == [1, 2]
+    with pytest.raises(ValidationError, match=r'Invalid JSON: expected `,` or `\]` at line 1 column 7'):
+        next(it)
+
+    with pytest.raises(ValidationError, match=r'Input should be a valid array \[type=list_type'):
+        v.iter_validate_json(b'{"a": 1}')
+
+
+def test_iter_validate_json_bad_arguments():
+    with pytest.raises(TypeError, match='`iter_validate_json` requires a `list` schema'):
+        SchemaValidator(core_schema.int_schema()).iter_validate_json(b'[1]')
+    v = SchemaValidator(core_schema.list_schema())
+    with pytest.raises(ValueError, match='Invalid on_error: `ignore`, expected `raise`, `skip` or `collect`'):
+        v.iter_validate_json(b'[1]', on_error='ignore')
@@ -0,0 +1,160 @@
+import dataclasses
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+def get_errors(schema, input_value, method='validate_python', **kwargs):
+    with pytest.raises(ValidationError) as exc_info:
+        getattr(SchemaValidator(schema), method)(input_value, **kwargs)
+    return exc_info.value.errors(include_url=False, include_input=False)
+
+
+def locs(errors):
+    return [e['loc'] for e in errors]
+
+
+def test_list():
+    errors = get_errors(core_schema.list_schema(core_schema.int_schema()), ['x'] * 100_000, max_errors=3)
+    assert errors == [
+        {'type': 'int_parsing', 'loc': (0,), 'msg': 'Input should be a valid integer, unable to parse string as an integer'},
+        {'type': 'int_parsing', 'loc': (1,), 'msg': 'Input should be a valid integer, unable to parse string as an integer'},
+        {'type': 'int_parsing', 'loc': (2,), 'msg': 'Input should be a valid integer, unable to parse string as an integer'},
+        {'type': 'too_many_errors', 'loc': (), 'msg': 'Validation stopped after 3 errors', 'ctx': {'max_errors': 3}},
+    ]
+
+
+def test_default_collects_all():
+    errors = get_errors(core_schema.list_schema(core_schema.int_schema()), ['x'] * 100)
+    assert len(errors) == 100
+
+
+def test_nested():
+    schema = core_schema.list_schema(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.list_schema(core_schema.int_schema())),
+            }
+        )
+    )
+    input_value = [{'a': 1, 'b': [1, 'x']}, {'a': 'x', 'b': ['x', 'x', 'x']}, {'a': 'x', 'b': []}]
+    assert locs(get_errors(schema, input_value, max_errors=3)) == [(0, 'b', 1), (1, 'a'), (1, 'b', 0), ()]
+    assert locs(get_errors(schema, input_value, max_errors=1)) == [(0, 'b', 1), ()]
+    assert locs(get_errors(schema, input_value, max_errors=5)) == [
+        (0, 'b', 1),
+        (1, 'a'),
+        (1, 'b', 0),
+        (1, 'b', 1),
+        (1, 'b', 2),
+        (),
+    ]
+    assert len(get_errors(schema, input_value, max_errors=6)) == 6
+    assert len(get_errors(schema, input_value, max_errors=100)) == 6
+
+
+def test_limit_not_reached():
+    schema = core_schema.typed_dict_schema(
+        {
+            'a': core_schema.typed_dict_field(core_schema.int_schema()),
+            'b': core_schema.typed_dict_field(core_schema.int_schema()),
+        }
+    )
+    # the summary is only added when errors beyond the limit were dropped
+    assert locs(get_errors(schema, {'a': 'x', 'b': 'x'}, max_errors=2)) == [('a',), ('b',)]
+    assert locs(get_errors(schema, {'a': 'x', 'b': 1}, max_errors=1)) == [('a',)]
+    assert locs(get_errors(schema, {'a': 'x', 'b': 'x'}, max_errors=1)) == [('a',), ()]
+
+    list_schema = core_schema.list_schema(core_schema.int_schema())
+    assert locs(get_errors(list_schema, ['a', 'b', 'c'], max_errors=3)) == [(0,), (1,), (2,)]
+
+
+def test_union_member_errors_ignored():
+    schema = core_schema.list_schema(
+        core_schema.union_schema(
+            [
+                core_schema.list_schema(core_schema.int_schema()),
+                core_schema.list_schema(core_schema.str_schema()),
+            ]
+        )
+    )
+    v = SchemaValidator(schema)
+    assert v.validate_python([['a', 'b', 'c'], [1, 2]], max_errors=1) == [['a', 'b', 'c'], [1, 2]]
+    errors = get_errors(schema, [['a', 'b'], [None], [None]], max_errors=2)
+    assert locs(errors) == [(1, 'list[int]', 0), (1, 'list[str]', 0), ()]
+
+
+@pytest.mark.parametrize(
+    'schema,input_value,expected',
+    [
+        (core_schema.set_schema(core_schema.int_schema()), ['a', 'b', 'c'], [(0,), (1,), ()]),
+        (core_schema.tuple_variable_schema(core_schema.int_schema()), ('a', 'b', 'c'), [(0,), (1,), ()]),
+        (
+            core_schema.tuple_schema([core_schema.int_schema(), core_schema.int_schema(), core_schema.int_schema()]),
+            ('a', 'b', 'c'),
+            [(0,), (1,), ()],
+        ),
+        (
+            core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema()),
+            {'a': 'x', 'b': 'x', 'c': 'x'},
+            [('a',), ('b',), ()],
+        ),
+    ],
+)
+def test_collections(schema, input_value, expected):
+    assert locs(get_errors(schema, input_value, max_errors=2)) == expected
+
+
+def test_extra_keys_not_reported():
+    schema = core_schema.typed_dict_schema(
+        {
+            'a': core_schema.typed_dict_field(core_schema.int_schema()),
+            'b': core_schema.typed_dict_field(core_schema.int_schema()),
+            'c': core_schema.typed_dict_field(core_schema.int_schema()),
+        },
+        extra_behavior='forbid',
+    )
+    assert locs(get_errors(schema, {'a': 'x', 'b': 'x', 'c': 1}, max_errors=1)) == [('a',), ()]
+
+
+def test_dataclass():
+    @dataclasses.dataclass
+    class Foo:
+        a: int
+        b: int
+
+    schema = core_schema.dataclass_schema(
+        Foo,
+        core_schema.dataclass_args_schema(
+            'Foo',
+            [
+                core_schema.dataclass_field(name='a', schema=core_schema.int_schema()),
+                core_schema.dataclass_field(name='b', schema=core_schema.int_schema()),
+            ],
+        ),
+        ['a', 'b'],
+    )
+    assert locs(get_errors(schema, {'a': 'x', 'b': 'x'}, max_errors=1)) == [('a',), ()]
+
+
+def test_json():
+    schema = core_schema.list_schema(core_schema.int_schema())
+    assert locs(get_errors(schema, '["x", "x", "x"]', 'validate_json', max_errors=2)) == [(0,), (1,), ()]
+
+
+def test_strings():
+    schema = core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema())
+    input_value = {'a': 'x', 'b': 'x', 'c': 'x'}
+    assert locs(get_errors(schema, input_value, 'validate_strings', max_errors=2)) == [('a',), ('b',), ()]
+
+
+@pytest.mark.parametrize('max_errors', [0, -1])
+@pytest.mark.parametrize(
+    'method,input_value',
+    [('validate_python', ['x']), ('validate_json', '["x"]'), ('validate_strings', ['x'])],
+)
+def test_invalid_max_errors(max_errors, method, input_value):
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    with pytest.raises(ValueError, match=f'`max_errors` should be at least 1, got {max_errors}'):
+        getattr(v, method)(input_value, max_errors=max_errors)
@@ -0,0 +1,222 @@
+import re
+from datetime import datetime, timezone
+
+import pytest
+
+from pydantic_core import PydanticSerializationError, SchemaSerializer, SchemaValidator, ValidationError, core_schema
+
+SCHEMA = core_schema.typed_dict_schema(
+    {
+        'id': core_schema.typed_dict_field(core_schema.int_schema()),
+        'data': core_schema.typed_dict_field(core_schema.bytes_schema()),
+        'tags': core_schema.typed_dict_field(core_schema.list_schema(core_schema.str_schema())),
+    }
+)
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (b'\xc0', None),
+        (b'\xc3', True),
+        (b'\x7f', 127),
+        (b'\xe0', -32),
+        (b'\xcd\x01\x00', 256),
+        (b'\xd3\xff\xff\xff\xff\xff\xff\xff\xfe', -2),
+        (b'\xcf\xff\xff\xff\xff\xff\xff\xff\xff', 2**64 - 1),
+        (b'\xcb\x3f\xf8\x00\x00\x00\x00\x00\x00', 1.5),
+        (b'\xa3foo', 'foo'),
+        (b'\xc4\x02\x00\xff', b'\x00\xff'),
+        (b'\x92\x01\xa1x', [1, 'x']),
+        (b'\x82\xa1a\x01\x02\xc2', {'a': 1, 2: False}),
+        (b'\xd6\xff\x00\x00\x00\x00', datetime(1970, 1, 1, tzinfo=timezone.utc)),
+        (b'\xd4\x05\x07', (5, b'\x07')),
+    ],
+)
+def test_any(input_value, expected):
+    v = SchemaValidator(core_schema.any_schema())
+    assert v.validate_msgpack(input_value) == expected
+
+
+def test_typed_dict():
+    v = SchemaValidator(SCHEMA)
+    assert v.validate_msgpack(b'\x83\xa2id\x01\xa4data\xc4\x01\xff\xa4tags\x91\xa1a') == {
+        'id': 1,
+        'data': b'\xff',
+        'tags': ['a'],
+    }
+    assert v.validate_msgpack(bytearray(b'\x83\xa2id\xa11\xa4data\xa1x\xa4tags\x90')) == {
+        'id': 1,
+        'data': b'x',
+        'tags': [],
+    }
+
+
+def test_strict():
+    v = SchemaValidator(SCHEMA)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_msgpack(b'\x83\xa2id\xa11\xa4data\xa1x\xa4tags\x91\xc4\x01a', strict=True)
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'int_type', 'loc': ('id',), 'msg': 'Input should be a valid integer', 'input': '1'},
+        {'type': 'bytes_type', 'loc': ('data',), 'msg': 'Input should be a valid bytes', 'input': 'x'},
+        {'type': 'string_type', 'loc': ('tags', 0), 'msg': 'Input should be a valid string', 'input': b'a'},
+    ]
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (b'\xd6\xff\x65\x53\xf1\x00', datetime(2023, 11, 14, 22, 13, 20, tzinfo=timezone.utc)),
+        (
+            # 64-bit format, 30 bits of nanoseconds and 34 bits of seconds
+            b'\xd7\xff' + ((123_456_000 << 34) | 1_700_000_000).to_bytes(8, 'big'),
+            datetime(2023, 11, 14, 22, 13, 20, 123456, tzinfo=timezone.utc),
+        ),
+        (
+            b'\xc7\x0c\xff' + (1000).to_bytes(4, 'big') + (-1).to_bytes(8, 'big', signed=True),
+            datetime(1969, 12, 31, 23, 59, 59, 1, tzinfo=timezone.utc),
+        ),
+        (b'\xb42023-11-14T22:13:20Z', datetime(2023, 11, 14, 22, 13, 20, tzinfo=timezone.utc)),
+    ],
+)
+def test_datetime(input_value, expected):
+    v = SchemaValidator(core_schema.datetime_schema(strict=True))
+    assert v.validate_msgpack(input_value) == expected
+
+
+@pytest.mark.parametrize(
+    'input_value,error',
+    [
+        (b'', 'EOF while parsing a value at byte 0'),
+        (b'\x92\x01', 'EOF while parsing a value at byte 2'),
+        (b'\x01\x02', 'trailing bytes after value at byte 1'),
+        (b'\xc1', 'reserved marker 0xc1 at byte 0'),
+        (b'\x91\xa1\xff', 'invalid UTF-8 in string at byte 1'),
+        (b'\xd5\xff\x00\x00', 'invalid timestamp length 2 at byte 0'),
+        (b'\x91' * 300, 'recursion limit exceeded at byte 200'),
+    ],
+)
+def test_invalid(input_value, error):
+    v = SchemaValidator(core_schema.any_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_msgpack(input_value)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'msgpack_invalid',
+            'loc': (),
+            'msg': f'Invalid MessagePack: {error}',
+            'input': input_value,
+            'ctx': {'error': error},
+        }
+    ]
+
+
+def test_invalid_type():
+    v = SchemaValidator(core_schema.any_schema())
+    with pytest.raises(
+        ValidationError, match=re.escape('MessagePack input should be bytes or bytearray [type=msgpack_type')
+    ):
+        v.validate_msgpack('\x01')
+
+
+def test_model_fields_alias_path():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'x': core_schema.typed_dict_field(core_schema.int_schema(), validation_alias=['a', 1]),
+                'y': core_schema.typed_dict_field(core_schema.int_schema(), validation_alias=['b', -1]),
+            }
+        )
+    )
+    # `a` is a map with an int key, `b` is an array
+    assert v.validate_msgpack(b'\x82\xa1a\x81\x01\x02\xa1b\x92\x03\x04') == {'x': 2, 'y': 4}
+
+
+def test_duplicate_keys_last_wins():
+    v = SchemaValidator(core_schema.typed_dict_schema({'a': core_schema.typed_dict_field(core_schema.int_schema())}))
+    assert v.validate_msgpack(b'\x82\xa1a\x01\xa1a\x02') == {'a': 2}
+
+
+def test_key_loc():
+    v = SchemaValidator(core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema()))
+    with pytest.raises(ValidationError) as exc_info:
+        # {true: 'x', [1, 'a']: 1}
+        v.validate_msgpack(b'\x82\xc3\xa1x\x92\x01\xa1a\x01')
+    assert [e['loc'] for e in exc_info.value.errors(include_url=False)] == [
+        ('true', '[key]'),
+        ('true',),
+        ('[1,"a"]', '[key]'),
+    ]
+
+
+def test_timestamp_out_of_range():
+    v = SchemaValidator(core_schema.datetime_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_msgpack(b'\xc7\x0c\xff' + (0).to_bytes(4, 'big') + (2**62).to_bytes(8, 'big'))
+    # the raw seconds and nanoseconds are shown as they don't fit in a datetime
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'datetime_parsing',
+            'loc': (),
+            'msg': 'Input should be a valid datetime, dates after 9999 are not supported as unix timestamps',
+            'input': (2**62, 0),
+            'ctx': {'error': 'dates after 9999 are not supported as unix timestamps'},
+        }
+    ]
+
+
+@pytest.mark.parametrize(
+    'input_value,error_input',
+    [
+        # {[1, 'a']: 1}
+        (b'\x81\x92\x01\xa1a\x01', {(1, 'a'): 1}),
+        # {{}: 1, [{}]: 2}
+        (b'\x82\x80\x01\x91\x80\x02', [({}, 1), ([{}], 2)]),
+    ],
+)
+def test_unhashable_keys_error_input(input_value, error_input):
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_msgpack(input_value)
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'int_type', 'loc': (), 'msg': 'Input should be a valid integer', 'input': error_input}
+    ]
+
+
+def test_validation_info_mode():
+    def f(value, info):
+        return info.mode
+
+    v = SchemaValidator(core_schema.with_info_plain_validator_function(f))
+    assert v.validate_msgpack(b'\xc0') == 'msgpack'
+
+
+def test_to_msgpack():
+    s = SchemaSerializer(SCHEMA)
+    assert s.to_msgpack({'id': 1, 'data': b'\xff', 'tags': ['a']}) == (
+        b'\x83\xa2id\x01\xa4data\xc4\x01\xff\xa4tags\x91\xa1a'
+    )
+    assert s.to_msgpack({'id': 2**64 - 1, 'data': b'', 'tags': []}, exclude={'data'}) == (
+        b'\x82\xa2id\xcf\xff\xff\xff\xff\xff\xff\xff\xff\xa4tags\x90'
+    )
+
+
+def test_to_msgpack_filtered_lengths():
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.str_schema(), core_schema.list_schema()))
+    # container lengths are written up front, so filtered items must not be counted
+    assert s.to_msgpack({'a': [1, 2, 3], 'b': []}, exclude={'a': {1}, 'b': True}) == b'\x81\xa1a\x92\x01\x03'
+
+
+def test_to_msgpack_int_out_of_range():
+    s = SchemaSerializer(core_schema.int_schema())
+    with pytest.raises(
+        PydanticSerializationError, match=r'Error serializing to MessagePack: integer \d+ is out of range'
+    ):
+        s.to_msgpack(2**64)
+
+
+def test_round_trip():
+    v = SchemaValidator(SCHEMA)
+    s = SchemaSerializer(SCHEMA)
+    value = {'id': -5, 'data': bytes(range(256)), 'tags': ['é', '']}
+    assert v.validate_msgpack(s.to_msgpack(value)) == value
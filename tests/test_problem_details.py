@@ -0,0 +1,83 @@
+import json
+
+from pydantic_core import PydanticCustomError, SchemaValidator, ValidationError, core_schema
+
+SCHEMA = core_schema.typed_dict_schema(
+    {
+        'age': core_schema.typed_dict_field(core_schema.int_schema(gt=0)),
+        'tags': core_schema.typed_dict_field(core_schema.list_schema(core_schema.str_schema())),
+    }
+)
+
+
+def get_error(input_value, schema=SCHEMA):
+    try:
+        SchemaValidator(schema, config=core_schema.CoreConfig(title='Person')).validate_python(input_value)
+    except ValidationError as e:
+        return e
+    raise AssertionError('validation should have failed')
+
+
+def test_problem_details():
+    exc = get_error({'age': 0, 'tags': ['a', 1]})
+    details = exc.to_problem_details(type_uri_prefix='https://example.com/problems/', instance='/people/1')
+    assert details == {
+        'type': 'https://example.com/problems/validation_error',
+        'title': 'Validation Error',
+        'status': 422,
+        'detail': '2 validation errors for Person',
+        'instance': '/people/1',
+        'errors': [
+            {
+                'type': 'greater_than',
+                'pointer': '/age',
+                'msg': 'Input should be greater than 0',
+                'ctx': {'gt': 0},
+                'url': 'https://example.com/problems/greater_than',
+            },
+            {
+                'type': 'string_type',
+                'pointer': '/tags/1',
+                'msg': 'Input should be a valid string',
+                'url': 'https://example.com/problems/string_type',
+            },
+        ],
+    }
+
+
+def test_defaults():
+    exc = get_error({'tags': []})
+    details = exc.to_problem_details()
+    assert details['type'] == 'about:blank'
+    assert details['status'] == 422
+    assert details['detail'] == '1 validation error for Person'
+    assert 'instance' not in details
+    (error,) = details['errors']
+    assert error['pointer'] == '/age'
+    assert error['url'].startswith('https://errors.pydantic.dev/')
+    assert error['url'].endswith('/v/missing')
+
+
+def test_status():
+    exc = get_error({'age': 1})
+    assert exc.to_problem_details(status=400)['status'] == 400
+
+
+def test_json_safe_context():
+    def f(input_value):
+        raise ValueError('bad value')
+
+    exc = get_error({'age': 1, 'tags': ['x']}, core_schema.no_info_plain_validator_function(f))
+    details = exc.to_problem_details()
+    assert details['errors'][0]['ctx'] == {'error': 'bad value'}
+    assert json.loads(json.dumps(details)) == details
+
+
+def test_custom_error_has_no_url():
+    def f(input_value):
+        raise PydanticCustomError('my_error', 'My error {x}', {'x': 1})
+
+    exc = get_error(1, core_schema.no_info_plain_validator_function(f))
+    assert exc.to_problem_details(type_uri_prefix='https://example.com/')['errors'] == [
+        {'type': 'my_error', 'pointer': '', 'msg': 'My error 1', 'ctx': {'x': 1}}
+    ]
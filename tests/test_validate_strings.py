@@ -132,3 +132,198 @@ def test_validate_strings_forbid_extra_fn_override():
 
     with pytest.raises(ValidationError, match='Extra inputs are not permitted'):
         v.validate_strings({'f': '1', 'extra_field': '123'}, extra='forbid')
+
+
+SETTINGS_SCHEMA = core_schema.typed_dict_schema(
+    {
+        'debug': core_schema.typed_dict_field(core_schema.bool_schema()),
+        'db': core_schema.typed_dict_field(
+            core_schema.typed_dict_schema(
+                {
+                    'host': core_schema.typed_dict_field(core_schema.str_schema()),
+                    'port': core_schema.typed_dict_field(core_schema.int_schema()),
+                }
+            )
+        ),
+    }
+)
+
+
+def test_nested_delimiter():
+    v = SchemaValidator(SETTINGS_SCHEMA)
+    assert v.validate_strings(
+        {'debug': 'true', 'db__host': 'localhost', 'db__port': '5432'}, nested_delimiter='__'
+    ) == {'debug': True, 'db': {'host': 'localhost', 'port': 5432}}
+
+
+def test_case_insensitive():
+    v = SchemaValidator(SETTINGS_SCHEMA)
+    assert v.validate_strings(
+        {'DEBUG': 'false', 'DB__HOST': 'localhost', 'Db__Port': '5432'}, nested_delimiter='__', case_sensitive=False
+    ) == {'debug': False, 'db': {'host': 'localhost', 'port': 5432}}
+
+
+def test_case_insensitive_mixed_case_fields():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'apiKey': core_schema.typed_dict_field(core_schema.str_schema()),
+                'db': core_schema.typed_dict_field(
+                    core_schema.typed_dict_schema(
+                        {'port': core_schema.typed_dict_field(core_schema.int_schema(), validation_alias='dbPort')}
+                    ),
+                    validation_alias=['Database'],
+                ),
+            }
+        )
+    )
+    assert v.validate_strings(
+        {'APIKEY': 'secret', 'DATABASE__DBPORT': '5432'}, nested_delimiter='__', case_sensitive=False
+    ) == {'apiKey': 'secret', 'db': {'port': 5432}}
+    # without case_sensitive=False, keys must match exactly
+    with pytest.raises(ValidationError):
+        v.validate_strings({'APIKEY': 'secret', 'DATABASE__DBPORT': '5432'}, nested_delimiter='__')
+
+
+def test_case_insensitive_ambiguous_keys():
+    v = SchemaValidator(SETTINGS_SCHEMA)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_strings(
+            {'debug': 'true', 'DB__PORT': '1', 'db__port': '2', 'db__host': 'x'},
+            nested_delimiter='__',
+            case_sensitive=False,
+        )
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'ambiguous_key',
+            'loc': ('db__port',),
+            'msg': "Key differs only by case from 'DB__PORT'",
+            'input': '2',
+            'ctx': {'other_key': 'DB__PORT'},
+        }
+    ]
+
+
+def test_nested_delimiter_error_locs():
+    v = SchemaValidator(SETTINGS_SCHEMA)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_strings({'DEBUG': 'maybe', 'DB__PORT': 'x'}, nested_delimiter='__', case_sensitive=False)
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [
+        ('bool_parsing', ('DEBUG',)),
+        # missing fields have no flat key, so their location is joined with the delimiter
+        ('missing', ('db__host',)),
+        ('int_parsing', ('DB__PORT',)),
+    ]
+
+
+def test_nested_delimiter_dict_values():
+    v = SchemaValidator(
+        core_schema.dict_schema(core_schema.str_schema(), core_schema.dict_schema(values_schema=core_schema.int_schema()))
+    )
+    assert v.validate_strings({'a__x': '1', 'a__y': '2', 'b__z': '3'}, nested_delimiter='__') == {
+        'a': {'x': 1, 'y': 2},
+        'b': {'z': 3},
+    }
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_strings({'a__x': '1', 'a__y': 'y'}, nested_delimiter='__')
+    assert [e['loc'] for e in exc_info.value.errors()] == [('a__y',)]
+
+
+@pytest.mark.parametrize(
+    'input_value,errors',
+    [
+        ({'db': 'x', 'db__port': '1'}, [('dict_type', ('db',), 'x')]),
+        ({'db__port': '1', 'db': 'x'}, [('dict_type', ('db',), 'x')]),
+        ({'debug': 1}, [('string_type', ('debug',), 1)]),
+        ('debug', [('dict_type', (), 'debug')]),
+    ],
+)
+def test_nested_delimiter_invalid_input(input_value, errors):
+    v = SchemaValidator(SETTINGS_SCHEMA)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_strings(input_value, nested_delimiter='__')
+    assert [(e['type'], e['loc'], e['input']) for e in exc_info.value.errors()] == errors
+
+
+def test_nested_delimiter_empty():
+    v = SchemaValidator(SETTINGS_SCHEMA)
+    with pytest.raises(ValueError, match='`nested_delimiter` must not be empty'):
+        v.validate_strings({}, nested_delimiter='')
+
+
+@pytest.mark.parametrize(
+    'schema,input_value,expected',
+    [
+        (core_schema.list_schema(core_schema.int_schema()), '[1, 2, 3]', [1, 2, 3]),
+        (core_schema.list_schema(core_schema.str_schema()), '["a", 1, null]', ['a', '1', 'null']),
+        (core_schema.list_schema(core_schema.list_schema(core_schema.int_schema())), '[[1], [2, 3]]', [[1], [2, 3]]),
+        (core_schema.list_schema(), '[1, "a"]', ['1', 'a']),
+        (core_schema.set_schema(core_schema.int_schema()), '[1, 2, 1]', {1, 2}),
+        (core_schema.frozenset_schema(core_schema.int_schema()), '[1]', frozenset({1})),
+        (
+            core_schema.dict_schema(core_schema.str_schema(), core_schema.date_schema()),
+            '{"a": "2020-01-01"}',
+            {'a': date(2020, 1, 1)},
+        ),
+    ],
+)
+def test_str_collection_parsing_json(schema, input_value, expected):
+    v = SchemaValidator(schema, config=core_schema.CoreConfig(str_collection_parsing='json'))
+    assert v.validate_strings(input_value) == expected
+
+
+@pytest.mark.parametrize(
+    'schema,input_value,expected',
+    [
+        (core_schema.list_schema(core_schema.int_schema()), '1, 2,3', [1, 2, 3]),
+        (core_schema.list_schema(core_schema.str_schema()), 'a,b,c', ['a', 'b', 'c']),
+        (core_schema.list_schema(core_schema.str_schema()), '', []),
+        (core_schema.set_schema(core_schema.str_schema()), 'a,b,a', {'a', 'b'}),
+        (core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema()), 'a=1, b = 2', {'a': 1, 'b': 2}),
+    ],
+)
+def test_str_collection_parsing_csv(schema, input_value, expected):
+    v = SchemaValidator(schema, config=core_schema.CoreConfig(str_collection_parsing='csv'))
+    assert v.validate_strings(input_value) == expected
+
+
+def test_str_collection_parsing_errors():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'x': core_schema.typed_dict_field(core_schema.list_schema(core_schema.int_schema()))},
+            config=core_schema.CoreConfig(str_collection_parsing='json'),
+        )
+    )
+    assert v.validate_strings({'x': '[1]'}) == {'x': [1]}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_strings({'x': '[1, "a"]'})
+    assert [(e['type'], e['loc'], e['input']) for e in exc_info.value.errors()] == [('int_parsing', ('x', 1), 'a')]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_strings({'x': '[1,'})
+    assert [(e['type'], e['loc'], e['input']) for e in exc_info.value.errors()] == [('json_invalid', ('x',), '[1,')]
+
+    # valid JSON of the wrong type isn't parsed
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_strings({'x': '{}'})
+    assert [(e['type'], e['loc'], e['input']) for e in exc_info.value.errors()] == [('list_type', ('x',), '{}')]
+
+
+def test_str_collection_parsing_csv_dict_invalid():
+    v = SchemaValidator(core_schema.dict_schema(), config=core_schema.CoreConfig(str_collection_parsing='csv'))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_strings('a=1,b')
+    assert [(e['type'], e['input']) for e in exc_info.value.errors()] == [('dict_type', 'a=1,b')]
+
+
+def test_str_collection_parsing_off():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    with pytest.raises(ValidationError, match=re.escape('Input should be a valid array [type=list_type')):
+        v.validate_strings('[1, 2]')
+    # parsing only applies to string inputs
+    v = SchemaValidator(
+        core_schema.list_schema(core_schema.str_schema()),
+        config=core_schema.CoreConfig(str_collection_parsing='csv'),
+    )
+    with pytest.raises(ValidationError, match=re.escape('Input should be a valid array [type=list_type')):
+        v.validate_json('"a,b"')
@@ -0,0 +1,332 @@
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
+
+
+def typed_dict_validator(*constraints: core_schema.FieldConstraint, **fields: core_schema.CoreSchema):
+    return SchemaValidator(
+        core_schema.typed_dict_schema(
+            {name: core_schema.typed_dict_field(schema, required=False) for name, schema in fields.items()},
+            field_constraints=list(constraints),
+        )
+    )
+
+
+def model_fields_validator(*constraints: core_schema.FieldConstraint, **fields: core_schema.CoreSchema):
+    return SchemaValidator(
+        core_schema.model_fields_schema(
+            {
+                name: core_schema.model_field(core_schema.with_default_schema(schema, default=None))
+                for name, schema in fields.items()
+            },
+            field_constraints=list(constraints),
+        )
+    )
+
+
+@pytest.mark.parametrize(
+    'constraint_type,start,end,valid,comparison',
+    [
+        ('lt', 1, 2, True, 'less than'),
+        ('lt', 2, 2, False, 'less than'),
+        ('le', 2, 2, True, 'less than or equal to'),
+        ('le', 3, 2, False, 'less than or equal to'),
+        ('gt', 3, 2, True, 'greater than'),
+        ('gt', 2, 2, False, 'greater than'),
+        ('ge', 2, 2, True, 'greater than or equal to'),
+        ('ge', 1, 2, False, 'greater than or equal to'),
+        ('eq', 2, 2, True, 'equal to'),
+        ('eq', 1, 2, False, 'equal to'),
+        ('ne', 1, 2, True, 'not equal to'),
+        ('ne', 2, 2, False, 'not equal to'),
+    ],
+)
+def test_comparison(constraint_type, start, end, valid, comparison):
+    v = typed_dict_validator(
+        core_schema.field_constraint(constraint_type, ['start', 'end']),
+        start=core_schema.int_schema(),
+        end=core_schema.int_schema(),
+    )
+    if valid:
+        assert v.validate_python({'start': start, 'end': end}) == {'start': start, 'end': end}
+    else:
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_python({'start': start, 'end': end})
+        assert exc_info.value.errors(include_url=False) == [
+            {
+                'type': 'field_comparison',
+                'loc': ('start',),
+                'msg': f"Input should be {comparison} field 'end'",
+                'input': start,
+                'ctx': {'comparison': comparison, 'other_field': 'end'},
+            }
+        ]
+
+
+def test_comparison_skipped_when_missing():
+    v = typed_dict_validator(
+        core_schema.field_constraint('lt', ['start', 'end']),
+        start=core_schema.int_schema(),
+        end=core_schema.nullable_schema(core_schema.int_schema()),
+    )
+    assert v.validate_python({'start': 5}) == {'start': 5}
+    assert v.validate_python({'start': 5, 'end': None}) == {'start': 5, 'end': None}
+
+
+def test_comparison_json():
+    v = typed_dict_validator(
+        core_schema.field_constraint('lt', ['start', 'end']),
+        start=core_schema.date_schema(),
+        end=core_schema.date_schema(),
+    )
+    assert v.validate_json('{"start": "2020-01-01", "end": "2020-01-02"}')
+    with pytest.raises(ValidationError, match=r"start\n  Input should be less than field 'end'"):
+        v.validate_json('{"start": "2020-01-03", "end": "2020-01-02"}')
+
+
+def test_one_of():
+    v = typed_dict_validator(
+        core_schema.field_constraint('one_of', ['email', 'phone']),
+        email=core_schema.str_schema(),
+        phone=core_schema.str_schema(),
+    )
+    assert v.validate_python({'email': 'a@b.c'}) == {'email': 'a@b.c'}
+    assert v.validate_python({'phone': '123'}) == {'phone': '123'}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'fields_one_of',
+            'loc': (),
+            'msg': "Exactly one of the fields 'email', 'phone' should be provided",
+            'input': {},
+            'ctx': {'fields': "'email', 'phone'"},
+        }
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'email': 'a@b.c', 'phone': '123'})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'fields_mutually_exclusive',
+            'loc': ('phone',),
+            'msg': "Field cannot be provided together with field 'email'",
+            'input': '123',
+            'ctx': {'other_field': 'email'},
+        }
+    ]
+
+
+def test_mutually_exclusive():
+    v = typed_dict_validator(
+        core_schema.field_constraint('mutually_exclusive', ['a', 'b', 'c']),
+        a=core_schema.int_schema(),
+        b=core_schema.int_schema(),
+        c=core_schema.int_schema(),
+    )
+    assert v.validate_python({}) == {}
+    assert v.validate_python({'b': 2}) == {'b': 2}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 1, 'b': 2, 'c': 3})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'fields_mutually_exclusive',
+            'loc': ('b',),
+            'msg': "Field cannot be provided together with field 'a'",
+            'input': 2,
+            'ctx': {'other_field': 'a'},
+        },
+        {
+            'type': 'fields_mutually_exclusive',
+            'loc': ('c',),
+            'msg': "Field cannot be provided together with field 'a'",
+            'input': 3,
+            'ctx': {'other_field': 'a'},
+        },
+    ]
+
+
+def test_requires():
+    v = typed_dict_validator(
+        core_schema.field_constraint('requires', ['password', 'username', 'realm']),
+        password=core_schema.str_schema(),
+        username=core_schema.str_schema(),
+        realm=core_schema.str_schema(),
+    )
+    assert v.validate_python({}) == {}
+    assert v.validate_python({'username': 'x'}) == {'username': 'x'}
+    assert v.validate_python({'password': 'p', 'username': 'u', 'realm': 'r'})
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'password': 'p', 'username': 'u'})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'field_requires',
+            'loc': ('realm',),
+            'msg': "Field required when field 'password' is provided",
+            'input': {'password': 'p', 'username': 'u'},
+            'ctx': {'other_field': 'password'},
+        }
+    ]
+
+
+@pytest.mark.parametrize(
+    'schema_factory,field_factory',
+    [
+        (core_schema.typed_dict_schema, core_schema.typed_dict_field),
+        (core_schema.model_fields_schema, core_schema.model_field),
+    ],
+)
+def test_aliases(schema_factory, field_factory):
+    v = SchemaValidator(
+        schema_factory(
+            {
+                'start': field_factory(core_schema.int_schema(), validation_alias='startDate'),
+                'end': field_factory(core_schema.int_schema(), validation_alias='endDate'),
+                'realm': field_factory(
+                    core_schema.with_default_schema(core_schema.str_schema(), default=None),
+                    validation_alias=['meta', 'realm'],
+                ),
+            },
+            field_constraints=[
+                core_schema.field_constraint('lt', ['start', 'end']),
+                core_schema.field_constraint('requires', ['start', 'realm']),
+            ],
+        )
+    )
+    # errors are located like the fields' own errors, at the alias the value was found at
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'startDate': 3, 'endDate': 2})
+    assert [e['loc'] for e in exc_info.value.errors()] == [('startDate',), ('meta', 'realm')]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'start': 3, 'endDate': 2}, by_name=True)
+    assert [e['loc'] for e in exc_info.value.errors()] == [('start',), ('meta', 'realm')]
+
+
+def test_aliases_loc_by_name():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'start': core_schema.typed_dict_field(core_schema.int_schema(), validation_alias='startDate'),
+                'end': core_schema.typed_dict_field(core_schema.int_schema(), validation_alias='endDate'),
+            },
+            field_constraints=[core_schema.field_constraint('lt', ['start', 'end'])],
+            config=core_schema.CoreConfig(loc_by_alias=False),
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'startDate': 3, 'endDate': 2})
+    assert [e['loc'] for e in exc_info.value.errors()] == [('start',)]
+
+
+def test_not_checked_with_field_errors():
+    v = typed_dict_validator(
+        core_schema.field_constraint('lt', ['start', 'end']),
+        core_schema.field_constraint('one_of', ['start', 'end']),
+        start=core_schema.int_schema(),
+        end=core_schema.int_schema(),
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'start': 5, 'end': 'x'})
+    assert [e['type'] for e in exc_info.value.errors()] == ['int_parsing']
+
+
+def test_model_fields():
+    v = model_fields_validator(
+        core_schema.field_constraint('lt', ['start', 'end']),
+        core_schema.field_constraint('mutually_exclusive', ['start', 'label']),
+        start=core_schema.int_schema(),
+        end=core_schema.int_schema(),
+        label=core_schema.str_schema(),
+    )
+    assert v.validate_python({'start': 1, 'end': 2}) == ({'start': 1, 'end': 2, 'label': None}, None, {'start', 'end'})
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'start': 3, 'end': 2, 'label': 'x'})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'field_comparison',
+            'loc': ('start',),
+            'msg': "Input should be less than field 'end'",
+            'input': 3,
+            'ctx': {'comparison': 'less than', 'other_field': 'end'},
+        },
+        {
+            'type': 'fields_mutually_exclusive',
+            'loc': ('label',),
+            'msg': "Field cannot be provided together with field 'start'",
+            'input': 'x',
+            'ctx': {'other_field': 'start'},
+        },
+    ]
+
+
+def test_model_fields_assignment():
+    v = model_fields_validator(
+        core_schema.field_constraint('lt', ['start', 'end']),
+        start=core_schema.int_schema(),
+        end=core_schema.int_schema(),
+    )
+    data, _, _ = v.validate_python({'start': 1, 'end': 2})
+    assert v.validate_assignment(data, 'end', 5) == ({'start': 1, 'end': 5}, None, {'end'})
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_assignment(data, 'end', 0)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'field_comparison',
+            'loc': ('start',),
+            'msg': "Input should be less than field 'end'",
+            'input': 1,
+            'ctx': {'comparison': 'less than', 'other_field': 'end'},
+        }
+    ]
+
+
+@pytest.mark.parametrize(
+    'constraint,message',
+    [
+        ({'type': 'lt', 'fields': ['a', 'x']}, "Field constraint 'lt': unknown field 'x'"),
+        ({'type': 'lt', 'fields': ['a', 'b', 'a']}, "Field constraint 'lt' requires exactly two fields"),
+        ({'type': 'one_of', 'fields': ['a']}, "Field constraint 'one_of' requires at least two fields"),
+        ({'type': 'foobar', 'fields': ['a', 'b']}, "Invalid field constraint type: 'foobar'"),
+    ],
+)
+def test_invalid_constraint(constraint, message):
+    with pytest.raises(SchemaError, match=message):
+        typed_dict_validator(constraint, a=core_schema.int_schema(), b=core_schema.int_schema())
+
+
+@pytest.mark.parametrize('validator_factory', [typed_dict_validator, model_fields_validator])
+def test_comparison_not_supported(validator_factory):
+    v = validator_factory(
+        core_schema.field_constraint('lt', ['start', 'end']),
+        start=core_schema.union_schema([core_schema.int_schema(strict=True), core_schema.str_schema()]),
+        end=core_schema.int_schema(),
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'start': 'x', 'end': 2})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'field_not_comparable',
+            'loc': ('start',),
+            'msg': "Input could not be compared to field 'end': '<' not supported between instances of 'str' and 'int'",
+            'input': 'x',
+            'ctx': {'other_field': 'end', 'error': "'<' not supported between instances of 'str' and 'int'"},
+        }
+    ]
+
+
+def test_comparison_not_supported_assignment():
+    v = model_fields_validator(
+        core_schema.field_constraint('lt', ['start', 'end']),
+        start=core_schema.union_schema([core_schema.int_schema(strict=True), core_schema.str_schema()]),
+        end=core_schema.int_schema(),
+    )
+    data, _, _ = v.validate_python({'start': 1, 'end': 2})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_assignment(data, 'start', 'x')
+    assert [e['type'] for e in exc_info.value.errors()] == ['field_not_comparable']
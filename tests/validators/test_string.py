@@ -171,7 +171,7 @@ def test_str_constrained_config():
         v.validate_python('test long')
 
 
-@pytest.mark.parametrize('engine', [None, 'rust-regex', 'python-re'])
+@pytest.mark.parametrize('engine', [None, 'rust-regex', 'fancy-regex', 'python-re'])
 def test_invalid_regex(engine):
     if platform.python_implementation() == 'PyPy' and sys.version_info[:2] == (3, 11):
         # pypy 3.11 type formatting
@@ -190,6 +190,11 @@ def test_invalid_regex(engine):
         assert exc_info.value.args[0] == (
             'Error building "str" validator:\n  SchemaError: regex parse error:\n    (abc\n    ^\nerror: unclosed group'
         )
+    elif engine == 'fancy-regex':
+        assert exc_info.value.args[0] == (
+            'Error building "str" validator:\n'
+            '  SchemaError: Parsing error at position 4: Opening parenthesis without closing parenthesis'
+        )
     elif engine == 'python-re':
         prefix = 'PatternError' if sys.version_info >= (3, 13) else 'error'
         assert exc_info.value.args[0] == (
@@ -197,7 +202,7 @@ def test_invalid_regex(engine):
         )
 
 
-@pytest.mark.parametrize('engine', [None, 'rust-regex', 'python-re'])
+@pytest.mark.parametrize('engine', [None, 'rust-regex', 'fancy-regex', 'python-re'])
 def test_regex_error(engine):
     v = SchemaValidator(core_schema.str_schema(pattern='11', regex_engine=engine))
     with pytest.raises(ValidationError) as exc_info:
@@ -374,15 +379,16 @@ def test_backtracking_regex_rust_unsupported(mode) -> None:
     )
 
 
+@pytest.mark.parametrize('engine', ('fancy-regex', 'python-re'))
 @pytest.mark.parametrize('mode', ('schema', 'config'))
-def test_backtracking_regex_python(mode) -> None:
+def test_backtracking_regex(mode, engine) -> None:
     pattern = r'r(#*)".*?"\1'
 
     if mode == 'schema':
-        v = SchemaValidator(core_schema.str_schema(pattern=pattern, regex_engine='python-re'))
+        v = SchemaValidator(core_schema.str_schema(pattern=pattern, regex_engine=engine))
     elif mode == 'config':
         v = SchemaValidator(
-            schema=core_schema.str_schema(pattern=pattern), config=core_schema.CoreConfig(regex_engine='python-re')
+            schema=core_schema.str_schema(pattern=pattern), config=core_schema.CoreConfig(regex_engine=engine)
         )
     assert v.validate_python('r""') == 'r""'
     assert v.validate_python('r#""#') == 'r#""#'
@@ -391,6 +397,43 @@ def test_backtracking_regex_python(mode) -> None:
         v.validate_python('r#"#')
 
 
+def test_lookahead_regex_fancy() -> None:
+    v = SchemaValidator(core_schema.str_schema(pattern=r'^(?=.*\d)(?=.*[a-z]).{8,}$', regex_engine='fancy-regex'))
+    assert v.validate_python('abcdefg1') == 'abcdefg1'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('abcdefgh')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'string_pattern_mismatch',
+            'loc': (),
+            'msg': "String should match pattern '^(?=.*\\d)(?=.*[a-z]).{8,}$'",
+            'input': 'abcdefgh',
+            'ctx': {'pattern': '^(?=.*\\d)(?=.*[a-z]).{8,}$'},
+        }
+    ]
+
+
+def test_backtrack_limit_regex_fancy() -> None:
+    v = SchemaValidator(core_schema.str_schema(pattern=r'^(a|a)*\1b', regex_engine='fancy-regex'))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('a' * 30)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'string_pattern_error',
+            'loc': (),
+            'msg': (
+                "Pattern '^(a|a)*\\1b' could not be run against the string: "
+                'Error executing regex: Max limit for backtracking count exceeded'
+            ),
+            'input': 'a' * 30,
+            'ctx': {
+                'pattern': '^(a|a)*\\1b',
+                'error': 'Error executing regex: Max limit for backtracking count exceeded',
+            },
+        }
+    ]
+
+
 @pytest.mark.parametrize('number', (42, 443, 10242))
 def test_coerce_numbers_to_str_schema(number: int):
     v = SchemaValidator(core_schema.str_schema(coerce_numbers_to_str=True))
@@ -422,7 +465,7 @@ def test_coerce_numbers_to_str_schema_with_strict_mode(number: int):
         v.validate_json(str(number))
 
 
-@pytest.mark.parametrize('engine', [None, 'rust-regex', 'python-re'])
+@pytest.mark.parametrize('engine', [None, 'rust-regex', 'fancy-regex', 'python-re'])
 def test_compiled_regex(engine) -> None:
     v = SchemaValidator(core_schema.str_schema(pattern=re.compile('abc', re.IGNORECASE), regex_engine=engine))
     assert v.validate_python('abc') == 'abc'
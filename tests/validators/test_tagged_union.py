@@ -606,3 +606,54 @@ def test_custom_error_type():
             'input': {'foo': 'other', 'bar': 'Bar'},
         }
     ]
+
+
+def test_errors_group_by_union_member():
+    v = SchemaValidator(
+        core_schema.tagged_union_schema(
+            discriminator='foo',
+            choices={
+                'apple': core_schema.typed_dict_schema(
+                    fields={
+                        'foo': core_schema.typed_dict_field(schema=core_schema.str_schema()),
+                        'bar': core_schema.typed_dict_field(schema=core_schema.int_schema()),
+                    }
+                ),
+                'banana': core_schema.typed_dict_schema(
+                    fields={'foo': core_schema.typed_dict_field(schema=core_schema.str_schema())}
+                ),
+            },
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'foo': 'apple', 'bar': 'x'})
+    # insert_assert(exc_info.value.errors(include_url=False, group_by_union_member=True))
+    assert exc_info.value.errors(include_url=False, group_by_union_member=True) == [
+        {
+            'type': 'union_no_match',
+            'loc': (),
+            'msg': 'Input did not match any union member',
+            'input': {'foo': 'apple', 'bar': 'x'},
+            'members': [
+                {
+                    'member': 'apple',
+                    'closest': True,
+                    'errors': [
+                        {
+                            'type': 'int_parsing',
+                            'loc': ('bar',),
+                            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+                            'input': 'x',
+                        }
+                    ],
+                }
+            ],
+        }
+    ]
+
+    # errors raised before a member is chosen aren't grouped
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'foo': 'other'})
+    assert exc_info.value.errors(include_url=False, group_by_union_member=True) == exc_info.value.errors(
+        include_url=False
+    )
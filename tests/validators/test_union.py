@@ -1427,3 +1427,257 @@ def test_smart_union_wrap_validator_should_not_change_nested_model_field_counts(
     assert isinstance(m, RootModel)
     assert isinstance(m.ab, ModelA)
     assert m.ab.sub.x == 'y'
+
+
+def test_errors_group_by_union_member():
+    a_schema = core_schema.typed_dict_schema(
+        {
+            'a': core_schema.typed_dict_field(core_schema.int_schema()),
+            'b': core_schema.typed_dict_field(core_schema.str_schema()),
+        }
+    )
+    b_schema = core_schema.typed_dict_schema({'x': core_schema.typed_dict_field(core_schema.int_schema())})
+    v = SchemaValidator(
+        core_schema.list_schema(core_schema.union_schema([(a_schema, 'A'), (b_schema, 'B'), core_schema.int_schema()]))
+    )
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, {'a': 1, 'b': 2}, 'wrong'])
+
+    # the flat errors are unchanged
+    assert [e['loc'] for e in exc_info.value.errors()] == [
+        (1, 'A', 'b'),
+        (1, 'B', 'x'),
+        (1, 'int'),
+        (2, 'A'),
+        (2, 'B'),
+        (2, 'int'),
+    ]
+
+    # insert_assert(exc_info.value.errors(include_url=False, group_by_union_member=True))
+    assert exc_info.value.errors(include_url=False, group_by_union_member=True) == [
+        {
+            'type': 'union_no_match',
+            'loc': (1,),
+            'msg': 'Input did not match any union member',
+            'input': {'a': 1, 'b': 2},
+            'members': [
+                {
+                    'member': 'A',
+                    'closest': True,
+                    'errors': [
+                        {'type': 'string_type', 'loc': ('b',), 'msg': 'Input should be a valid string', 'input': 2}
+                    ],
+                },
+                {
+                    'member': 'B',
+                    'closest': False,
+                    'errors': [{'type': 'missing', 'loc': ('x',), 'msg': 'Field required', 'input': {'a': 1, 'b': 2}}],
+                },
+                {
+                    'member': 'int',
+                    'closest': False,
+                    'errors': [
+                        {
+                            'type': 'int_type',
+                            'loc': (),
+                            'msg': 'Input should be a valid integer',
+                            'input': {'a': 1, 'b': 2},
+                        }
+                    ],
+                },
+            ],
+        },
+        {
+            'type': 'union_no_match',
+            'loc': (2,),
+            'msg': 'Input did not match any union member',
+            'input': 'wrong',
+            'members': [
+                {
+                    'member': 'A',
+                    'closest': True,
+                    'errors': [
+                        {'type': 'dict_type', 'loc': (), 'msg': 'Input should be a valid dictionary', 'input': 'wrong'}
+                    ],
+                },
+                {
+                    'member': 'B',
+                    'closest': False,
+                    'errors': [
+                        {'type': 'dict_type', 'loc': (), 'msg': 'Input should be a valid dictionary', 'input': 'wrong'}
+                    ],
+                },
+                {
+                    'member': 'int',
+                    'closest': False,
+                    'errors': [
+                        {
+                            'type': 'int_parsing',
+                            'loc': (),
+                            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+                            'input': 'wrong',
+                        }
+                    ],
+                },
+            ],
+        },
+    ]
+
+
+def test_errors_group_by_union_member_closest_model():
+    class ModelA:
+        pass
+
+    class ModelB:
+        pass
+
+    model_a_schema = core_schema.model_schema(
+        ModelA,
+        core_schema.model_fields_schema(
+            fields={
+                'x': core_schema.model_field(core_schema.int_schema()),
+                'y': core_schema.model_field(core_schema.int_schema()),
+            }
+        ),
+    )
+    model_b_schema = core_schema.model_schema(
+        ModelB,
+        core_schema.model_fields_schema(
+            fields={
+                'x': core_schema.model_field(core_schema.int_schema()),
+                'y': core_schema.model_field(core_schema.str_schema()),
+                'z': core_schema.model_field(core_schema.int_schema()),
+            }
+        ),
+    )
+    v = SchemaValidator(core_schema.union_schema([core_schema.int_schema(), model_a_schema, model_b_schema]))
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'x': 1, 'y': 'a'})
+
+    errors = exc_info.value.errors(include_url=False, group_by_union_member=True)
+    assert len(errors) == 1
+    assert errors[0]['loc'] == ()
+    # `int` raises fewest errors but rejects the input outright, both models accept it and ModelA raises fewer errors
+    assert [(m['member'], m['closest'], [e['loc'] for e in m['errors']]) for m in errors[0]['members']] == [
+        ('int', False, [()]),
+        ('ModelA', True, [('y',)]),
+        ('ModelB', False, [('z',)]),
+    ]
+
+
+def test_smart_union_failed_nested_member_fields_not_counted():
+    """Fields set by a member of a nested union which failed mustn't count towards the outer union's choice."""
+
+    class M1:
+        pass
+
+    class M2:
+        pass
+
+    class Inner:
+        pass
+
+    inner_schema = core_schema.model_schema(
+        Inner,
+        core_schema.model_fields_schema(
+            fields={
+                'p': core_schema.model_field(core_schema.int_schema()),
+                'q': core_schema.model_field(core_schema.int_schema()),
+                'r': core_schema.model_field(core_schema.int_schema()),
+            }
+        ),
+    )
+    m1_schema = core_schema.model_schema(
+        M1,
+        core_schema.model_fields_schema(
+            fields={
+                'f': core_schema.model_field(
+                    core_schema.union_schema([inner_schema, core_schema.any_schema()], mode='left_to_right')
+                ),
+            }
+        ),
+    )
+    m2_schema = core_schema.model_schema(
+        M2,
+        core_schema.model_fields_schema(
+            fields={
+                'f': core_schema.model_field(core_schema.any_schema()),
+                'g': core_schema.model_field(core_schema.int_schema()),
+            }
+        ),
+    )
+    v = SchemaValidator(core_schema.union_schema([m2_schema, m1_schema]))
+
+    m = v.validate_python({'f': {'p': 1, 'q': 2}, 'g': 0})
+    assert isinstance(m, M2)
+    assert m.f == {'p': 1, 'q': 2}
+    assert m.g == 0
+
+
+def test_errors_group_by_union_member_nested():
+    inner = core_schema.union_schema([core_schema.int_schema(), core_schema.bool_schema()])
+    v = SchemaValidator(
+        core_schema.union_schema([core_schema.list_schema(inner), core_schema.str_schema()], mode='left_to_right')
+    )
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, 'x'])
+
+    # insert_assert(exc_info.value.errors(include_url=False, include_input=False, group_by_union_member=True))
+    assert exc_info.value.errors(include_url=False, include_input=False, group_by_union_member=True) == [
+        {
+            'type': 'union_no_match',
+            'loc': (),
+            'msg': 'Input did not match any union member',
+            'members': [
+                {
+                    'member': 'list[union[int,bool]]',
+                    # the list was accepted and only its items failed, so it's closer than `str`
+                    'closest': True,
+                    'errors': [
+                        {
+                            'type': 'union_no_match',
+                            'loc': (1,),
+                            'msg': 'Input did not match any union member',
+                            'members': [
+                                {
+                                    'member': 'int',
+                                    'closest': True,
+                                    'errors': [
+                                        {
+                                            'type': 'int_parsing',
+                                            'loc': (),
+                                            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+                                        }
+                                    ],
+                                },
+                                {
+                                    'member': 'bool',
+                                    'closest': False,
+                                    'errors': [
+                                        {
+                                            'type': 'bool_parsing',
+                                            'loc': (),
+                                            'msg': 'Input should be a valid boolean, unable to interpret input',
+                                        }
+                                    ],
+                                },
+                            ],
+                        }
+                    ],
+                },
+                {
+                    'member': 'str',
+                    'closest': False,
+                    'errors': [{'type': 'string_type', 'loc': (), 'msg': 'Input should be a valid string'}],
+                },
+            ],
+        }
+    ]
+
+    # each group shows the input given to its own union
+    (error,) = exc_info.value.errors(group_by_union_member=True)
+    assert error['input'] == [1, 'x']
+    assert error['members'][0]['errors'][0]['input'] == 'x'